@@ -4,6 +4,7 @@ from .types import DType
 from . import random
 from . import simulation
 from . import distribution
+from . import analysis
 
 try:
     __version__ = version("diffusionx")
@@ -14,6 +15,7 @@ __all__ = [
     "__version__",
     "random",
     "distribution",
+    "analysis",
     "DType",
     "simulation",
 ]
@@ -6,6 +6,12 @@ import numpy
 import numpy.typing
 import typing
 __all__ = [
+    "BmEnsembleIterator",
+    "Process",
+    "StableGenerator",
+    "UnitArray",
+    "add_processes",
+    "analytic_moment",
     "asymmetric_cauchy_central_moment",
     "asymmetric_cauchy_eatamsd",
     "asymmetric_cauchy_fpt",
@@ -46,6 +52,7 @@ __all__ = [
     "bb_occupation_time_raw_moment",
     "bb_raw_moment",
     "bb_simulate",
+    "bb_simulate_endpoints",
     "bb_tamsd",
     "be_central_moment",
     "be_eatamsd",
@@ -62,23 +69,56 @@ __all__ = [
     "be_raw_moment",
     "be_simulate",
     "be_tamsd",
+    "benchmark_simulation",
     "bm_central_moment",
+    "bm_crossing_events",
     "bm_eatamsd",
+    "bm_eatamsd_samples",
+    "bm_ensemble_iter",
+    "bm_extreme_stats",
+    "bm_first_crossing_path",
     "bm_fpt",
+    "bm_fpt_and_occupation",
     "bm_fpt_central_moment",
+    "bm_fpt_mean_analytic",
+    "bm_fpt_moving",
+    "bm_fpt_pdf",
     "bm_fpt_raw_moment",
+    "bm_fpt_raw_moment_corrected",
     "bm_frac_central_moment",
     "bm_frac_raw_moment",
+    "bm_local_time_profile",
     "bm_mean",
+    "bm_moment_about",
     "bm_msd",
     "bm_occupation_time",
     "bm_occupation_time_central_moment",
+    "bm_occupation_time_curve",
     "bm_occupation_time_raw_moment",
+    "bm_occupation_time_windows",
+    "bm_propagator",
     "bm_raw_moment",
+    "bm_raw_moment_ci",
+    "bm_raw_moment_diagnostic",
+    "bm_running_max_simulate",
     "bm_simulate",
+    "bm_simulate_absorbing",
+    "bm_simulate_batch_flat",
+    "bm_simulate_continue",
+    "bm_simulate_dict",
+    "bm_simulate_double_barrier",
+    "bm_simulate_ensemble_dict",
+    "bm_simulate_ensemble_to_parquet",
+    "bm_simulate_until",
+    "bm_simulate_with_increments",
+    "bm_survival_curve",
     "bm_tamsd",
+    "bm_tamsd_curve",
+    "bm_terminal",
+    "bm_terminals",
     "bool_rand",
     "bool_rands",
+    "bool_rands_packed",
     "cauchy_central_moment",
     "cauchy_eatamsd",
     "cauchy_fpt",
@@ -104,9 +144,14 @@ __all__ = [
     "ctrw_occupation_time_central_moment",
     "ctrw_occupation_time_raw_moment",
     "ctrw_raw_moment",
+    "ctrw_raw_moment_running",
     "ctrw_simulate_duration",
     "ctrw_simulate_step",
+    "ctrw_weibull_simulate",
+    "dt_convergence",
     "eatamsd",
+    "ensemble_functional",
+    "estimate_diffusion_coefficient",
     "exp_rand",
     "exp_rands",
     "fbm_central_moment",
@@ -124,6 +169,12 @@ __all__ = [
     "fbm_raw_moment",
     "fbm_simulate",
     "fbm_tamsd",
+    "fbm_vacf",
+    "fgn_simulate",
+    "fit_ou",
+    "fit_stable",
+    "fractional_diffusion_msd",
+    "fractional_diffusion_simulate",
     "gamma_central_moment",
     "gamma_eatamsd",
     "gamma_fpt",
@@ -152,6 +203,7 @@ __all__ = [
     "gb_occupation_time_central_moment",
     "gb_occupation_time_raw_moment",
     "gb_raw_moment",
+    "gb_running_max_simulate",
     "gb_simulate",
     "gb_tamsd",
     "generalized_langevin_central_moment",
@@ -169,6 +221,14 @@ __all__ = [
     "generalized_langevin_raw_moment",
     "generalized_langevin_simulate",
     "generalized_langevin_tamsd",
+    "get_max_points",
+    "get_rng_backend",
+    "half_cauchy_rand",
+    "half_cauchy_rands",
+    "has_parquet",
+    "increment_normality_test",
+    "integrate_path",
+    "integrated_cir_subordinator_simulate",
     "inv_subordinator_central_moment",
     "inv_subordinator_fpt",
     "inv_subordinator_fpt_central_moment",
@@ -180,6 +240,8 @@ __all__ = [
     "inv_subordinator_occupation_time_raw_moment",
     "inv_subordinator_raw_moment",
     "inv_subordinator_simulate",
+    "inverse_gaussian_rand",
+    "inverse_gaussian_rands",
     "langevin_central_moment",
     "langevin_eatamsd",
     "langevin_fpt",
@@ -194,7 +256,10 @@ __all__ = [
     "langevin_occupation_time_raw_moment",
     "langevin_raw_moment",
     "langevin_simulate",
+    "langevin_simulate_tabulated",
+    "langevin_simulate_with_work",
     "langevin_tamsd",
+    "langevin_vectorized_moment",
     "levy_central_moment",
     "levy_eatamsd",
     "levy_fpt",
@@ -206,7 +271,9 @@ __all__ = [
     "levy_occupation_time_central_moment",
     "levy_occupation_time_raw_moment",
     "levy_raw_moment",
+    "levy_raw_moment_grid",
     "levy_simulate",
+    "levy_simulate_sweep",
     "levy_tamsd",
     "levy_walk_central_moment",
     "levy_walk_fpt",
@@ -216,6 +283,9 @@ __all__ = [
     "levy_walk_msd",
     "levy_walk_raw_moment",
     "levy_walk_simulate",
+    "local_scaling_exponent",
+    "log_returns",
+    "make_process",
     "mean",
     "meander_central_moment",
     "meander_eatamsd",
@@ -232,10 +302,17 @@ __all__ = [
     "meander_raw_moment",
     "meander_simulate",
     "meander_tamsd",
+    "mittag_leffler_rand",
+    "mittag_leffler_rands",
     "moment",
+    "moment_diagnostic",
     "msd",
+    "nig_raw_moment",
+    "nig_simulate",
     "normal_rand",
     "normal_rands",
+    "normal_rands_into",
+    "num_grid_points",
     "ou_central_moment",
     "ou_eatamsd",
     "ou_fpt",
@@ -249,8 +326,12 @@ __all__ = [
     "ou_occupation_time_central_moment",
     "ou_occupation_time_raw_moment",
     "ou_raw_moment",
+    "ou_raw_moment_crn",
+    "ou_raw_moment_extrapolated",
     "ou_simulate",
+    "ou_simulate_integrated",
     "ou_tamsd",
+    "ou_terminals",
     "poisson_central_moment",
     "poisson_fpt",
     "poisson_fpt_central_moment",
@@ -267,12 +348,34 @@ __all__ = [
     "poisson_raw_moment",
     "poisson_simulate_duration",
     "poisson_simulate_step",
+    "process_central_moment",
+    "process_fpt",
+    "process_fpt_central_moment",
+    "process_fpt_raw_moment",
+    "process_occupation_time",
+    "process_occupation_time_central_moment",
+    "process_occupation_time_raw_moment",
+    "process_raw_moment",
+    "raw_moment_from_array",
+    "reflected_fbm_simulate",
+    "resample_step_path",
+    "rng_get_state",
+    "rng_set_state",
+    "seed_from_entropy",
+    "set_max_points",
+    "set_rng_backend",
+    "set_seed",
+    "simple_returns",
+    "simulate_reduce",
     "skew_stable_rand",
     "skew_stable_rands",
+    "stable_cdf",
+    "stable_pdf",
     "stable_rand",
     "stable_rands",
     "subordinated_langevin_central_moment",
     "subordinated_langevin_eatamsd",
+    "subordinated_langevin_eb_parameter",
     "subordinated_langevin_fpt",
     "subordinated_langevin_fpt_central_moment",
     "subordinated_langevin_fpt_raw_moment",
@@ -293,20 +396,116 @@ __all__ = [
     "subordinator_occupation_time",
     "subordinator_occupation_time_central_moment",
     "subordinator_occupation_time_raw_moment",
+    "subordinator_pair_simulate",
     "subordinator_simulate",
     "tamsd",
+    "tamsd_convergence",
+    "tamsd_from_array",
     "uniform_rand_float",
     "uniform_rand_int",
     "uniform_rands_float",
+    "uniform_rands_float_into",
     "uniform_rands_int",
+    "weibull_rand",
+    "weibull_rands",
 ]
 
+@typing.final
+class BmEnsembleIterator:
+    r"""
+    Iterator over `particles` independent Brownian motion paths, created by `bm_ensemble_iter`.
+
+    Each path is simulated lazily when requested, so the iterator can drive a progress bar.
+    """
+    def __iter__(self) -> BmEnsembleIterator: ...
+    def __next__(self) -> typing.Optional[tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]]: ...
+    def __len__(self) -> builtins.int:
+        r"""
+        Number of paths not yet yielded.
+        """
+
+@typing.final
+class Process:
+    r"""
+    A built-in process created by `make_process`.
+
+    It exposes `simulate(duration, time_step)`, so it can be passed to the generic
+    estimators `moment`, `mean`, `msd`, `tamsd` and `eatamsd`. The `process_*` functions
+    compute its moments, first passage times and occupation times.
+    """
+    @property
+    def name(self) -> builtins.str:
+        r"""
+        Name of the process.
+        """
+    @property
+    def params(self) -> builtins.dict[builtins.str, builtins.float]:
+        r"""
+        Parameters of the process, including defaults.
+        """
+    def simulate(self, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+        r"""
+        Simulate the process.
+        """
+    def __repr__(self) -> builtins.str: ...
+
+@typing.final
+class StableGenerator:
+    r"""
+    Stable random number generator with the `alpha`/`beta`-dependent constants computed once.
+
+    `StableGenerator(alpha, beta, sigma, mu, parameterization)` samples the same law as
+    `stable_rands` with these arguments, but repeated `rands` calls skip the setup.
+    """
+    def __new__(cls, alpha: builtins.float, beta: builtins.float, sigma: builtins.float = 1.0, mu: builtins.float = 0.0, parameterization: builtins.str = 'S1') -> StableGenerator: ...
+    def rands(self, n: builtins.int, /, seed: typing.Optional[builtins.int] = None) -> numpy.typing.NDArray[numpy.float64]:
+        r"""
+        Draw `n` stable random numbers, reproducibly when `seed` is given.
+        """
+    def __repr__(self) -> builtins.str: ...
+
+@typing.final
+class UnitArray:
+    r"""
+    A NumPy array tagged with a unit string.
+    """
+    @property
+    def values(self) -> numpy.typing.NDArray[numpy.float64]:
+        r"""
+        The underlying array.
+        """
+    @property
+    def units(self) -> builtins.str:
+        r"""
+        The unit of the values.
+        """
+    def __len__(self) -> builtins.int: ...
+    def __repr__(self) -> builtins.str: ...
+
+def add_processes(simulate_fn_a: typing.Any, simulate_fn_b: typing.Any, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate `X(t) = A(t) + B(t)` for two independent processes on the same grid.
+
+    Both objects must provide `simulate(duration, time_step)`; the paths are simulated
+    independently and their positions summed. The times are those of `simulate_fn_a`.
+    """
+
+def analytic_moment(process: builtins.str, params: typing.Optional[typing.Mapping[builtins.str, builtins.float]], duration: builtins.float, order: builtins.int) -> typing.Optional[builtins.float]:
+    r"""
+    Closed-form raw moment `E[X(duration)^order]`, or `None` if no formula is known.
+
+    Supported processes, with the parameter names of `make_process`:
+    `bm` and `drifted_bm` (`X = x0 + drift * t + sqrt(2D) W`), `ou` started at
+    `start_position`, `ou_stationary` started from its stationary law, `geometric_bm` and
+    `poisson` (`lambda_`). Negative orders are only supported for `geometric_bm`.
+    """
+
 def asymmetric_cauchy_central_moment(start_position: builtins.float, beta: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
     r"""
     Get the central moment of asymmetric Cauchy process.
     """
 
-def asymmetric_cauchy_eatamsd(start_position: builtins.float, beta: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def asymmetric_cauchy_eatamsd(start_position: builtins.float, beta: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the ensemble average of the time-averaged mean square displacement of asymmetric Cauchy process.
     """
@@ -356,12 +555,12 @@ def asymmetric_cauchy_raw_moment(start_position: builtins.float, beta: builtins.
     Get the raw moment of asymmetric Cauchy process.
     """
 
-def asymmetric_cauchy_simulate(start_position: builtins.float, beta: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def asymmetric_cauchy_simulate(start_position: builtins.float, beta: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate asymmetric Cauchy process.
     """
 
-def asymmetric_cauchy_tamsd(start_position: builtins.float, beta: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def asymmetric_cauchy_tamsd(start_position: builtins.float, beta: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean square displacement of asymmetric Cauchy process.
     """
@@ -371,7 +570,7 @@ def asymmetric_levy_central_moment(start_position: builtins.float, alpha: builti
     Get the central moment of asymmetric Levy process.
     """
 
-def asymmetric_levy_eatamsd(start_position: builtins.float, alpha: builtins.float, beta: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def asymmetric_levy_eatamsd(start_position: builtins.float, alpha: builtins.float, beta: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the effective time-averaged mean squared displacement of AsymmetricLevy process.
     """
@@ -421,12 +620,12 @@ def asymmetric_levy_raw_moment(start_position: builtins.float, alpha: builtins.f
     Get the raw moment of asymmetric Levy process.
     """
 
-def asymmetric_levy_simulate(start_position: builtins.float, alpha: builtins.float, beta: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def asymmetric_levy_simulate(start_position: builtins.float, alpha: builtins.float, beta: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate AsymmetricLevy process.
     """
 
-def asymmetric_levy_tamsd(start_position: builtins.float, alpha: builtins.float, beta: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def asymmetric_levy_tamsd(start_position: builtins.float, alpha: builtins.float, beta: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean squared displacement of AsymmetricLevy process.
     """
@@ -436,7 +635,7 @@ def bb_central_moment(duration: builtins.float, time_step: builtins.float, order
     Get the central moment of Brownian bridge.
     """
 
-def bb_eatamsd(duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def bb_eatamsd(duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the ensemble average of the time-averaged mean square displacement of Brownian bridge.
     """
@@ -496,12 +695,17 @@ def bb_raw_moment(duration: builtins.float, time_step: builtins.float, order: bu
     Get the raw moment of Brownian bridge.
     """
 
-def bb_simulate(duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def bb_simulate(duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate Brownian bridge.
     """
 
-def bb_tamsd(duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def bb_simulate_endpoints(start_value: builtins.float, end_value: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate Brownian bridge pinned at `start_value` at `t = 0` and `end_value` at `t = duration`.
+    """
+
+def bb_tamsd(duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean square displacement of Brownian bridge.
     """
@@ -511,7 +715,7 @@ def be_central_moment(duration: builtins.float, time_step: builtins.float, order
     Get the central moment of Brownian excursion.
     """
 
-def be_eatamsd(duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def be_eatamsd(duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the ensemble average of the time-averaged mean square displacement of Brownian excursion.
     """
@@ -571,41 +775,145 @@ def be_raw_moment(duration: builtins.float, time_step: builtins.float, order: bu
     Get the raw moment of Brownian excursion.
     """
 
-def be_simulate(duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def be_simulate(duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate Brownian excursion.
     """
 
-def be_tamsd(duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def be_tamsd(duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean square displacement of Brownian excursion.
     """
 
+def benchmark_simulation(process_name: builtins.str, params: typing.Optional[typing.Mapping[builtins.str, builtins.float]], duration: builtins.float, time_step: builtins.float, particles: builtins.int, repeats: builtins.int) -> builtins.dict:
+    r"""
+    Time `repeats` runs of a built-in process's second raw moment at `duration`.
+
+    The process is created as by `make_process(process_name, params)`. Each run simulates
+    `particles` paths in parallel, keeping only their endpoints (the simulation phase), and
+    then sums their squares (the reduction phase). Returns a dict with the wall-clock
+    `mean`, `std` (population), `min` and `max` of a run in seconds, the overall
+    `simulation_fraction` and `reduction_fraction`, `repeats`, and the last `moment`.
+    """
+
 def bm_central_moment(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
     r"""
     Get the central moment of Brownian motion.
     """
 
-def bm_eatamsd(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def bm_crossing_events(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, level: builtins.float, t0: builtins.float = 0.0) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.int8]]:
+    r"""
+    Times and directions of the crossings of `level` along one Brownian path.
+
+    A point is above the level when `x >= level`; a crossing is recorded whenever consecutive
+    grid points lie on different sides, with direction `+1` upwards and `-1` downwards, so
+    the directions alternate. The crossing time is linearly interpolated between the two
+    grid points and shifted by `t0`.
+    """
+
+def bm_eatamsd(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the ensemble average of the time-averaged mean square displacement of Brownian motion.
     """
 
+def bm_eatamsd_samples(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Get the time-averaged mean square displacement of each of `particles` Brownian motion paths.
+
+    This is the per-particle distribution averaged by `bm_eatamsd`, e.g. for the
+    ergodicity breaking parameter.
+    """
+
+def bm_ensemble_iter(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, particles: builtins.int) -> BmEnsembleIterator:
+    r"""
+    Iterate over `particles` independent Brownian motion paths, yielding `(times, positions)`.
+    """
+
+def bm_extreme_stats(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, particles: builtins.int, return_arrays: builtins.bool = False) -> builtins.dict:
+    r"""
+    Ensemble statistics of the global maximum of Brownian motion and the time it occurs.
+
+    Returns a dict with `mean_max` and `mean_argmax_time`; with `return_arrays`, also the
+    per-path `max` and `argmax_time` arrays. Ties keep the earliest grid time. By the
+    arcsine law, `argmax_time / duration` has density `1 / (pi sqrt(u (1 - u)))`.
+    """
+
+def bm_first_crossing_path(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, domain: tuple[builtins.float, builtins.float], particles: builtins.int) -> typing.Optional[tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64], builtins.float]]:
+    r"""
+    Simulate `particles` Brownian motion paths and return the one that leaves `domain` first.
+
+    The result is the `(times, positions, fpt)` of the path with the smallest first exit
+    time on the simulation grid, or `None` if no path leaves the open interval `domain`
+    within `duration`. Only the current best path is kept while the others are discarded.
+    """
+
 def bm_fpt(start_position: builtins.float, diffusion_coefficient: builtins.float, time_step: builtins.float, domain: tuple[builtins.float, builtins.float], max_duration: builtins.float) -> typing.Optional[builtins.float]:
     r"""
     Get the first passage time of Brownian motion.
     """
 
+def bm_fpt_and_occupation(start_position: builtins.float, diffusion_coefficient: builtins.float, time_step: builtins.float, target_domain: tuple[builtins.float, builtins.float], trap_domain: tuple[builtins.float, builtins.float], max_duration: builtins.float, particles: builtins.int) -> typing.Optional[tuple[builtins.float, builtins.float]]:
+    r"""
+    Mean first passage time out of `target_domain` together with the mean time spent in
+    `trap_domain` before that passage, both taken from the same paths.
+
+    Each path is simulated step by step until it leaves the open `target_domain`; a step
+    adds to the trap occupation when its left point lies in the closed `trap_domain`, as in
+    `bm_occupation_time_curve`. Returns `None` if some path has not left `target_domain`
+    by `max_duration`.
+    """
+
 def bm_fpt_central_moment(start_position: builtins.float, diffusion_coefficient: builtins.float, domain: tuple[builtins.float, builtins.float], order: builtins.int, particles: builtins.int, time_step: builtins.float, max_duration: builtins.float) -> typing.Optional[builtins.float]:
     r"""
     Get the central moment of the first passage time of Brownian motion.
     """
 
+def bm_fpt_mean_analytic(start_position: builtins.float, diffusion_coefficient: builtins.float, drift: builtins.float, boundary: builtins.float) -> builtins.float:
+    r"""
+    Mean first passage time of drifted Brownian motion to the level `boundary`, see
+    `bm_fpt_pdf`.
+
+    Equals `a / v` when the drift points towards the boundary and `inf` otherwise, since the
+    boundary is then missed with positive probability (or, for zero drift, hit with an
+    infinite mean).
+    """
+
+def bm_fpt_moving(start_position: builtins.float, diffusion_coefficient: builtins.float, time_step: builtins.float, lower_func: typing.Any, upper_func: typing.Any, max_duration: builtins.float) -> typing.Optional[builtins.float]:
+    r"""
+    Get the first passage time of Brownian motion through time-dependent boundaries.
+
+    `lower_func` and `upper_func` map `t` to the boundaries and are evaluated at every
+    step; exceptions raised by them are propagated.
+    """
+
+def bm_fpt_pdf(start_position: builtins.float, diffusion_coefficient: builtins.float, drift: builtins.float, boundary: builtins.float, t: numpy.typing.NDArray[numpy.float64]) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    First passage density of drifted Brownian motion to the level `boundary`.
+
+    For `X(t) = start_position + drift * t + sqrt(2D) W(t)`, distance
+    `a = |boundary - start_position|` and drift `v` towards the boundary, the density is
+    the inverse Gaussian `a / sqrt(4 pi D t^3) exp(-(a - v t)^2 / (4 D t))`, and 0 for
+    `t <= 0`. If the drift points away from the boundary the density is defective: it
+    integrates to the hitting probability `exp(-a |v| / D)`.
+    """
+
 def bm_fpt_raw_moment(start_position: builtins.float, diffusion_coefficient: builtins.float, domain: tuple[builtins.float, builtins.float], order: builtins.int, particles: builtins.int, time_step: builtins.float, max_duration: builtins.float) -> typing.Optional[builtins.float]:
     r"""
     Get the raw moment of the first passage time of Brownian motion.
     """
 
+def bm_fpt_raw_moment_corrected(start_position: builtins.float, diffusion_coefficient: builtins.float, domain: tuple[builtins.float, builtins.float], order: builtins.int, particles: builtins.int, max_duration: builtins.float, time_step: builtins.float) -> typing.Optional[builtins.float]:
+    r"""
+    Get the raw moment of the first passage time of Brownian motion with the leading
+    time-step bias removed.
+
+    A path sampled every `dt` misses excursions across the boundary between grid points,
+    so the discrete first passage time is late by `O(sqrt(dt))`. The estimator is run at
+    `time_step` and `time_step / 4`; quartering the step halves `sqrt(dt)`, so
+    `2 M(time_step / 4) - M(time_step)` cancels the leading term. The two runs use
+    independent paths, so the result has a larger variance than either of them.
+    """
+
 def bm_frac_central_moment(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.float, particles: builtins.int) -> builtins.float:
     r"""
     Get the fractional central moment of Brownian motion.
@@ -616,11 +924,26 @@ def bm_frac_raw_moment(start_position: builtins.float, diffusion_coefficient: bu
     Get the raw moment of Brownian motion.
     """
 
+def bm_local_time_profile(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, particles: builtins.int, edges: numpy.typing.NDArray[numpy.float64]) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Get the ensemble-averaged occupation time of Brownian motion in each bin defined by `edges`.
+
+    `edges` must be a strictly increasing sequence of at least two finite values.
+    """
+
 def bm_mean(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, particles: builtins.int, time_step: builtins.float) -> builtins.float:
     r"""
     Get the mean of Brownian motion.
     """
 
+def bm_moment_about(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int, center: builtins.float) -> builtins.float:
+    r"""
+    Get the moment `E[(X(duration) - center)^order]` of Brownian motion about a fixed `center`.
+
+    `center = 0` gives the raw moment and `center = start_position` the displacement
+    moment; unlike `bm_central_moment`, the center is not estimated from the ensemble.
+    """
+
 def bm_msd(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, particles: builtins.int, time_step: builtins.float) -> builtins.float:
     r"""
     Get the msd of Brownian motion.
@@ -629,6 +952,9 @@ def bm_msd(start_position: builtins.float, diffusion_coefficient: builtins.float
 def bm_occupation_time(start_position: builtins.float, diffusion_coefficient: builtins.float, time_step: builtins.float, domain: tuple[builtins.float, builtins.float], duration: builtins.float) -> builtins.float:
     r"""
     Get the occupation time of Brownian motion.
+
+    Each step counts fully when its left point lies in the closed `domain`, the same rule
+    as `bm_occupation_time_curve`, whose last value this is.
     """
 
 def bm_occupation_time_central_moment(start_position: builtins.float, diffusion_coefficient: builtins.float, domain: tuple[builtins.float, builtins.float], order: builtins.int, particles: builtins.int, time_step: builtins.float, duration: builtins.float) -> builtins.float:
@@ -636,36 +962,215 @@ def bm_occupation_time_central_moment(start_position: builtins.float, diffusion_
     Get the central moment of the occupation time of Brownian motion.
     """
 
+def bm_occupation_time_curve(start_position: builtins.float, diffusion_coefficient: builtins.float, domain: tuple[builtins.float, builtins.float], duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Get the cumulative occupation time `∫_0^t 1{X(s) ∈ domain} ds` along one Brownian path.
+
+    Each step counts fully when its left point lies in the closed `domain`, so the curve is
+    nondecreasing, starts at 0 and ends at the total occupation time of the path.
+    """
+
 def bm_occupation_time_raw_moment(start_position: builtins.float, diffusion_coefficient: builtins.float, domain: tuple[builtins.float, builtins.float], order: builtins.int, particles: builtins.int, time_step: builtins.float, duration: builtins.float) -> builtins.float:
     r"""
     Get the raw moment of the occupation time of Brownian motion.
     """
 
+def bm_occupation_time_windows(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, particles: builtins.int, centers: numpy.typing.NDArray[numpy.float64], half_width: builtins.float) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Get the ensemble-averaged occupation time of Brownian motion in each window
+    `[c - half_width, c + half_width]` for `c` in `centers`.
+
+    Every simulated path is tallied into all windows, which may overlap.
+    """
+
+def bm_propagator(start_position: builtins.float, diffusion_coefficient: builtins.float, snapshot_times: typing.Sequence[builtins.float], time_step: builtins.float, particles: builtins.int, bins: builtins.int, range: tuple[builtins.float, builtins.float]) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Get the empirical propagator `P(x, t)` of Brownian motion at several snapshot times.
+
+    The ensemble is simulated once up to the largest snapshot time, and each snapshot uses
+    the position at the grid point nearest to it. Returns the `bins + 1` equally spaced
+    edges over `range` and a `(len(snapshot_times), bins)` array of densities, normalized
+    by the number of particles, so mass falling outside `range` is lost.
+    """
+
 def bm_raw_moment(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
     r"""
     Get the raw moment of Brownian motion.
     """
 
-def bm_simulate(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def bm_raw_moment_ci(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int, confidence: builtins.float) -> tuple[builtins.float, builtins.float, builtins.float]:
+    r"""
+    Get the raw moment of Brownian motion with a CLT confidence interval.
+
+    Returns `(estimate, lower, upper)`, where the interval is
+    `estimate ± z * s / sqrt(particles)` with `s` the sample standard deviation.
+    """
+
+def bm_raw_moment_diagnostic(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int, skip_nonfinite: builtins.bool = False) -> tuple[builtins.float, builtins.int]:
+    r"""
+    Get the raw moment of Brownian motion together with the number of non-finite contributions.
+
+    With `skip_nonfinite`, particles whose contribution is `inf` or `NaN` are excluded from the
+    average; they are counted either way.
+    """
+
+def bm_running_max_simulate(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate Brownian motion together with its running maximum.
+    """
+
+def bm_simulate(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, return_units: builtins.bool = False, endpoint: builtins.str = 'extend', t0: builtins.float = 0.0, validate_output: builtins.bool = False, thin: builtins.int = 1, diffusion_unit: builtins.str = 'm^2/s') -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]] | tuple[UnitArray, UnitArray]:
     r"""
     Simulate Brownian motion.
+
+    If `time_step` does not divide `duration`, `endpoint="extend"` ends with a shorter step
+    landing exactly at `duration`, and `endpoint="clip"` stops at the last full step.
+
+    The returned times start at `t0` instead of `0`; the path itself is unchanged.
+    With `thin > 1` only every `thin`-th point, plus the last one, is returned; the path is
+    still simulated at `time_step`.
+
+    The arrays are always C-contiguous `float64`; `validate_output` checks this before
+    returning, as a debugging aid.
+
+    With `return_units`, the times and positions are returned as `UnitArray`s. The units
+    follow from `diffusion_unit`, the unit `L^2/T` of `diffusion_coefficient`: the times are
+    in `T` and the positions in `L`, e.g. seconds and metres for the default `"m^2/s"`.
+    """
+
+def bm_simulate_absorbing(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, domain: tuple[builtins.float, builtins.float]) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate Brownian motion absorbed at the boundary of `domain`.
+
+    The path is truncated at the first point outside the open interval `domain`,
+    which is included; it runs the whole `duration` if no crossing occurs.
     """
 
-def bm_tamsd(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def bm_simulate_batch_flat(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, particles: builtins.int) -> tuple[numpy.typing.NDArray[numpy.float64], builtins.int]:
+    r"""
+    Simulate `particles` Brownian motion paths into one flat, particle-major array.
+
+    Returns the array of length `particles * points` and `points`, the number of grid
+    points per path, so `reshape(particles, points)` is a view without copies. All
+    increments are drawn in one vectorized pass and then summed per path in parallel.
+    """
+
+def bm_simulate_continue(last_position: builtins.float, diffusion_coefficient: builtins.float, additional_duration: builtins.float, time_step: builtins.float, t0: builtins.float, seed: typing.Optional[builtins.int] = None) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Continue a Brownian motion path from `last_position` at time `t0` for `additional_duration`.
+
+    The returned path starts with the point `(t0, last_position)`, so drop it when appending
+    to the previous segment. Its increments are independent of that segment, so the joined
+    path is again Brownian motion; carry the RNG state with `rng_get_state` and
+    `rng_set_state`, or pass `seed`, to make the continuation reproducible.
+    """
+
+def bm_simulate_dict(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> builtins.dict:
+    r"""
+    Simulate Brownian motion and return `{"time": ..., "position": ...}`, ready for
+    `pandas.DataFrame`. `t0` and `thin` are as in `bm_simulate`.
+    """
+
+def bm_simulate_double_barrier(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, lower: builtins.float, upper: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64], builtins.int]:
+    r"""
+    Simulate Brownian motion absorbed at `lower` or `upper` and report which barrier was hit.
+
+    The path is truncated as in `bm_simulate_absorbing` with `domain = (lower, upper)`.
+    The outcome is `-1` if the path was absorbed at `lower`, `1` at `upper`, and `0` if it
+    survived to `duration`.
+    """
+
+def bm_simulate_ensemble_dict(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, particles: builtins.int, t0: builtins.float = 0.0, thin: builtins.int = 1) -> builtins.dict:
+    r"""
+    Simulate an ensemble of Brownian motion paths in long format.
+
+    Returns `{"time": ..., "particle": ..., "position": ...}` with one row per particle and
+    grid point, ordered by particle and then time, so `pandas.DataFrame(result)` has
+    `particles * points` rows. `t0` and `thin` apply to every path as in `bm_simulate`.
+    """
+
+def bm_simulate_ensemble_to_parquet(path: builtins.str, start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, particles: builtins.int) -> builtins.int:
+    r"""
+    Simulate an ensemble of Brownian motion paths and stream them to a Parquet file.
+
+    Rows are `(particle_id, time, position)`. Paths are flushed in row groups, so peak memory
+    does not grow with `particles`. Returns the number of rows written.
+    """
+
+def bm_simulate_until(start_position: builtins.float, diffusion_coefficient: builtins.float, time_step: builtins.float, max_duration: builtins.float, predicate: typing.Any) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64], builtins.bool]:
+    r"""
+    Simulate Brownian motion until `predicate(t, x)` is truthy or `max_duration` is reached.
+
+    The predicate is called after every step, not at the start. Returns the path up to and
+    including the stopping point and whether the predicate stopped it; exceptions raised by
+    the predicate are propagated.
+    """
+
+def bm_simulate_with_increments(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, seed: typing.Optional[builtins.int] = None) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate Brownian motion and return the Gaussian increments that produced the path.
+
+    `positions[i + 1] = positions[i] + increments[i]`, so `increments` has one entry fewer
+    than `times`. A given `seed` makes the output reproducible, independent of the number
+    of threads.
+    """
+
+def bm_survival_curve(start_position: builtins.float, diffusion_coefficient: builtins.float, domain: tuple[builtins.float, builtins.float], duration: builtins.float, time_step: builtins.float, particles: builtins.int) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Get the empirical survival probability `S(t) = P(T > t)` of Brownian motion in `domain`.
+
+    `T` is the first grid time at which a path is outside the open interval `domain`;
+    `S` is evaluated on the simulation grid, so `S(0) = 1`.
+    """
+
+def bm_tamsd(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean square displacement of Brownian motion.
     """
 
+def bm_tamsd_curve(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, deltas: typing.Sequence[builtins.float], time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Get the time-averaged mean square displacement of Brownian motion for every lag in `deltas`.
+
+    A single path is simulated and reused for all lags, which are evaluated by quadrature
+    over the linearly interpolated path.
+    """
+
+def bm_terminal(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float) -> builtins.float:
+    r"""
+    Get the terminal value `X(duration)` of Brownian motion.
+
+    The value is sampled exactly as `start_position + sqrt(2 D duration) * N(0, 1)`,
+    so `time_step` is only validated.
+    """
+
+def bm_terminals(start_position: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, particles: builtins.int, qmc: builtins.bool = False) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Get the terminal values `X(duration)` of `particles` independent Brownian motions.
+
+    The values are sampled exactly, see `bm_terminal`. With `qmc`, the Gaussian draws come
+    from a scrambled Sobol sequence, which lowers the error of smooth functionals of them.
+    """
+
 def bool_rand(p: builtins.float = 0.5) -> builtins.bool: ...
 
-def bool_rands(n: builtins.int, /, p: builtins.float = 0.5) -> typing.Annotated[numpy.typing.NDArray[numpy.bool], typing.Literal["N"]]: ...
+def bool_rands(n: builtins.int, /, p: builtins.float = 0.5, seed: typing.Optional[builtins.int] = None) -> typing.Annotated[numpy.typing.NDArray[numpy.bool], typing.Literal["N"]]: ...
+
+def bool_rands_packed(n: builtins.int, /, p: builtins.float = 0.5, seed: typing.Optional[builtins.int] = None) -> tuple[numpy.typing.NDArray[numpy.uint8], builtins.int]:
+    r"""
+    Bernoulli(`p`) draws packed eight to a byte, returned with their count `n`.
+
+    Bit `i % 8` (least significant first) of byte `i / 8` is the `i`-th draw, so
+    `numpy.unpackbits(bits, count=n, bitorder="little")` recovers them; with the same `seed`
+    they equal `bool_rands(n, p, seed)`.
+    """
 
 def cauchy_central_moment(start_position: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
     r"""
     Get the central moment of Cauchy process.
     """
 
-def cauchy_eatamsd(start_position: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def cauchy_eatamsd(start_position: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the ensemble average of the time-averaged mean square displacement of Cauchy process.
     """
@@ -715,12 +1220,12 @@ def cauchy_raw_moment(start_position: builtins.float, duration: builtins.float,
     Get the raw moment of Cauchy process.
     """
 
-def cauchy_simulate(start_position: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def cauchy_simulate(start_position: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate Cauchy process.
     """
 
-def cauchy_tamsd(start_position: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def cauchy_tamsd(start_position: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean square displacement of Cauchy process.
     """
@@ -785,6 +1290,14 @@ def ctrw_raw_moment(alpha: builtins.float, beta: builtins.float, start_position:
     Get the raw moment of CTRW.
     """
 
+def ctrw_raw_moment_running(alpha: builtins.float, beta: builtins.float, start_position: builtins.float, duration: builtins.float, order: builtins.int, particles: builtins.int, checkpoints: typing.Sequence[builtins.int]) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Get the raw moment of CTRW estimated from the first `c` paths, for each `c` in `checkpoints`.
+
+    `particles` paths are simulated once, so the entries show how the Monte Carlo estimate
+    converges; the last one uses all paths if it equals `particles`.
+    """
+
 def ctrw_simulate_duration(alpha: builtins.float, beta: builtins.float, start_position: builtins.float, duration: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate CTRW.
@@ -795,18 +1308,70 @@ def ctrw_simulate_step(alpha: builtins.float, beta: builtins.float, start_positi
     Simulate CTRW.
     """
 
-def eatamsd(simulate_fn: typing.Any, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float: ...
+def ctrw_weibull_simulate(shape: builtins.float, scale: builtins.float, jump_beta: builtins.float, start_position: builtins.float, duration: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate a CTRW with Weibull waiting times of shape `shape` and scale `scale`.
+
+    Jumps are symmetric stable `S_jump_beta(1, 0, 0)`, so `jump_beta = 2` gives `N(0, 2)` jumps.
+    As with `ctrw_simulate_duration`, the path holds one point per jump plus a final point at
+    `duration`. The times start at `t0`, and `thin` keeps every `thin`-th point and the last.
+    """
+
+def dt_convergence(simulate_fn: typing.Any, duration: builtins.float, statistic: builtins.str, particles: builtins.int, dt_list: typing.Sequence[builtins.float]) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Statistic at `duration` for each time step in `dt_list`, to study convergence as `dt -> 0`.
+
+    `statistic` is one of `mean`, `msd` and `raw_moment_2`. The global random stream of
+    `diffusionx.random` is reset to the same state before each time step, so every time step
+    hands the same set of seeds to its particles. This couples the runs only for simulators
+    that draw each path from that stream, such as the Levy flight of `levy_simulate`;
+    the upstream simulators (Brownian motion, OU, ...) use their own generators, so for them the
+    runs stay independent and the differences include Monte Carlo noise of order
+    `std / sqrt(particles)`.
+    """
+
+def eatamsd(simulate_fn: typing.Any, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float: ...
 
-def exp_rand(scale: builtins.float = 1.0) -> builtins.float: ...
+def ensemble_functional(simulate_fn: typing.Any, path_functional: typing.Any, duration: builtins.float, time_step: builtins.float, particles: builtins.int) -> builtins.float:
+    r"""
+    Ensemble average of a Python path functional, `E[path_functional(t, x)]`.
 
-def exp_rands(n: builtins.int, /, scale: builtins.float = 1.0) -> numpy.typing.NDArray[numpy.float64]: ...
+    `simulate_fn` must provide `simulate(duration, time_step)` as for `moment`, and
+    `path_functional(times, positions)` maps one path to a float, and an exception it raises
+    is propagated unchanged. The paths are requested in batches from a thread pool, but each
+    `simulate` call re-acquires the GIL, so the simulation is not truly parallel unless
+    `simulate` releases the GIL itself. `path_functional` also runs under the GIL one path at
+    a time.
+    """
+
+def estimate_diffusion_coefficient(positions: numpy.typing.NDArray[numpy.float64], time_step: builtins.float, deltas: typing.Sequence[builtins.float]) -> builtins.float:
+    r"""
+    Diffusion coefficient of a single trajectory, from the slope of its TAMSD.
+
+    Computes the TAMSD at each lag in `deltas`, rounded to multiples of `time_step` as in
+    `tamsd_from_array`, and fits `TAMSD(delta) = 2 D delta` by least squares through the
+    origin. The fit assumes normal diffusion.
+    """
+
+def exp_rand(scale: builtins.float = 1.0) -> builtins.float:
+    r"""
+    Exponential random number with mean `scale`.
+
+    Sampled with the ziggurat method of `rand_distr::Exp1` (Doornik's ZIGNOR variant), so
+    no logarithm is evaluated except in the rare tail case.
+    """
+
+def exp_rands(n: builtins.int, /, scale: builtins.float = 1.0, seed: typing.Optional[builtins.int] = None) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Exponential random numbers, see `exp_rand`.
+    """
 
 def fbm_central_moment(start_position: builtins.float, hurst_exponent: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
     r"""
     Get the central moment of FBm.
     """
 
-def fbm_eatamsd(start_position: builtins.float, hurst_exponent: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def fbm_eatamsd(start_position: builtins.float, hurst_exponent: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the effective time-averaged mean squared displacement of FBm.
     """
@@ -866,22 +1431,80 @@ def fbm_raw_moment(start_position: builtins.float, hurst_exponent: builtins.floa
     Get the raw moment of FBm.
     """
 
-def fbm_simulate(start_position: builtins.float, hurst_exponent: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def fbm_simulate(start_position: builtins.float, hurst_exponent: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate FBm.
     """
 
-def fbm_tamsd(start_position: builtins.float, hurst_exponent: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def fbm_tamsd(start_position: builtins.float, hurst_exponent: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean squared displacement of FBm.
     """
 
+def fbm_vacf(start_position: builtins.float, hurst_exponent: builtins.float, duration: builtins.float, time_step: builtins.float, particles: builtins.int, max_lag: builtins.int) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Get the ensemble-averaged velocity autocorrelation function of FBm.
+
+    Velocities are the discrete increments divided by the time step; returns the lags in time
+    units and `C(tau) = E[v(t) v(t + tau)]` for `0..=max_lag` steps.
+    """
+
+def fgn_simulate(hurst_exponent: builtins.float, n: builtins.int, time_step: builtins.float, seed: typing.Optional[builtins.int] = None, thin: builtins.int = 1) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Simulate `n` samples of fractional Gaussian noise, the increments of FBm over steps of
+    `time_step`.
+
+    The samples are drawn exactly by circulant embedding (Davies–Harte), with covariance
+    `time_step^(2H) (|k + 1|^(2H) - 2 |k|^(2H) + |k - 1|^(2H)) / 2` at lag `k`, so no path
+    has to be differenced. A given `seed` makes the output reproducible.
+
+    The noise has no time axis, so there is no `t0`. With `thin > 1` the output is the
+    increments of the thinned FBm grid, i.e. the sums of `thin` consecutive samples (the
+    last sum may be shorter), which matches `thin` of the path simulators.
+    """
+
+def fit_ou(data: numpy.typing.NDArray[numpy.float64], time_step: builtins.float) -> tuple[builtins.float, builtins.float, builtins.float]:
+    r"""
+    Maximum-likelihood fit of `(theta, sigma, mu)` of the OU process
+    `dX = theta (mu - X) dt + sigma dW` to a path sampled every `time_step`.
+
+    The exact transition is the Gaussian AR(1) `X[k+1] = a + b X[k] + eps` with
+    `b = exp(-theta time_step)`, `a = mu (1 - b)` and `Var eps = sigma^2 (1 - b^2) / (2 theta)`,
+    so the conditional likelihood is maximized by least squares of `X[k+1]` on `X[k]`.
+    A fitted `b > 1` gives `theta < 0`, i.e. data that do not look stationary, and issues
+    a `RuntimeWarning`.
+    """
+
+def fit_stable(data: numpy.typing.NDArray[numpy.float64]) -> tuple[builtins.float, builtins.float, builtins.float, builtins.float]:
+    r"""
+    Fit the parameters `(alpha, beta, sigma, mu)` of a stable distribution to `data`.
+
+    Uses McCulloch's (1986) quantile estimator, which maps the 5%, 25%, 50%, 75% and 95%
+    sample quantiles to the parameters through interpolation tables. `mu` is returned in
+    the S1 parameterization used by `stable_rands`, which is ill-conditioned when `alpha` is
+    close to 1 and `beta != 0`. Below the tabulated range the data are treated as Gaussian,
+    with `alpha = 2` and `beta = 0`.
+    """
+
+def fractional_diffusion_msd(alpha: builtins.float, diffusion_coefficient: builtins.float, duration: builtins.float, time_step: builtins.float, particles: builtins.int) -> builtins.float:
+    r"""
+    Get the mean squared displacement of fractional-time diffusion.
+    """
+
+def fractional_diffusion_simulate(alpha: builtins.float, diffusion_coefficient: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate fractional-time diffusion, i.e. Brownian motion evaluated at the inverse
+    alpha-stable subordinator `E(t)`.
+
+    Its mean squared displacement is `2 D t^alpha / Gamma(1 + alpha)`.
+    """
+
 def gamma_central_moment(shape: builtins.float, rate: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
     r"""
     Get the central moment of Gamma.
     """
 
-def gamma_eatamsd(shape: builtins.float, rate: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def gamma_eatamsd(shape: builtins.float, rate: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the effective time-averaged mean squared displacement of Gamma.
     """
@@ -939,14 +1562,16 @@ def gamma_occupation_time_raw_moment(shape: builtins.float, rate: builtins.float
 def gamma_raw_moment(shape: builtins.float, rate: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
     r"""
     Get the raw moment of Gamma.
+
+    A negative `order` gives the inverse moment `E[X^order]`.
     """
 
-def gamma_simulate(shape: builtins.float, rate: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def gamma_simulate(shape: builtins.float, rate: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate Gamma.
     """
 
-def gamma_tamsd(shape: builtins.float, rate: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def gamma_tamsd(shape: builtins.float, rate: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean squared displacement of Gamma.
     """
@@ -956,7 +1581,7 @@ def gb_central_moment(start_position: builtins.float, mu: builtins.float, sigma:
     Get the central moment of Geometric Brownian Motion.
     """
 
-def gb_eatamsd(start_position: builtins.float, mu: builtins.float, sigma: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def gb_eatamsd(start_position: builtins.float, mu: builtins.float, sigma: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the effective time-averaged mean squared displacement of Geometric Brownian Motion.
     """
@@ -1011,17 +1636,39 @@ def gb_occupation_time_raw_moment(start_position: builtins.float, mu: builtins.f
     Get the raw moment of the occupation time of Geometric Brownian Motion.
     """
 
-def gb_raw_moment(start_position: builtins.float, mu: builtins.float, sigma: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
+def gb_raw_moment(start_position: builtins.float, mu: builtins.float, sigma: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int, control_variate: builtins.bool = False, qmc: builtins.bool = False) -> builtins.float:
     r"""
     Get the raw moment of Geometric Brownian Motion.
+
+    A negative `order` gives the inverse moment `E[X^order]`, which needs a positive
+    `start_position`.
+
+    With `control_variate`, the terminal value is used as a control variate against its
+    mean under the simulated Euler scheme, `start_position * prod(1 + mu * dt_k)` over the
+    steps of the grid. This mean is exact for the scheme, so the estimator targets the same
+    discretized moment as the plain one, with a smaller variance.
+
+    With `qmc`, the terminal values are sampled exactly from the log-normal law using a
+    scrambled Sobol sequence instead of simulated paths, so `time_step` is only validated.
+    Quasi-Monte Carlo only helps for smooth functionals of few Gaussian draws such as this one.
+    """
+
+def gb_running_max_simulate(start_position: builtins.float, mu: builtins.float, sigma: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate Geometric Brownian Motion together with its running maximum.
     """
 
-def gb_simulate(start_position: builtins.float, mu: builtins.float, sigma: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def gb_simulate(start_position: builtins.float, mu: builtins.float, sigma: builtins.float, duration: builtins.float, time_step: builtins.float, endpoint: builtins.str = 'extend', exact: builtins.bool = False, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate Geometric Brownian Motion.
+
+    See `bm_simulate` for `endpoint`. With `exact`, every step applies the exact log-normal
+    transition `S exp((mu - sigma^2 / 2) dt + sigma sqrt(dt) Z)`, which keeps the path
+    strictly positive and has no discretization error for any `time_step`; it is the
+    recommended choice. Otherwise the upstream Euler scheme is used.
     """
 
-def gb_tamsd(start_position: builtins.float, mu: builtins.float, sigma: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def gb_tamsd(start_position: builtins.float, mu: builtins.float, sigma: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean squared displacement of Geometric Brownian Motion.
     """
@@ -1031,7 +1678,7 @@ def generalized_langevin_central_moment(drift_func: typing.Any, diffusion_func:
     Get the central moment of GeneralizedLangevin process.
     """
 
-def generalized_langevin_eatamsd(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def generalized_langevin_eatamsd(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the effective time-averaged mean squared displacement of GeneralizedLangevin process.
     """
@@ -1091,16 +1738,65 @@ def generalized_langevin_raw_moment(drift_func: typing.Any, diffusion_func: typi
     Get the raw moment of GeneralizedLangevin process.
     """
 
-def generalized_langevin_simulate(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def generalized_langevin_simulate(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Py function wrapper for GeneralizedLangevin simulation
     """
 
-def generalized_langevin_tamsd(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def generalized_langevin_tamsd(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean squared displacement of GeneralizedLangevin process.
     """
 
+def get_max_points() -> builtins.int:
+    r"""
+    Get the maximum number of grid points a single simulated path may have.
+    """
+
+def get_rng_backend() -> builtins.str:
+    r"""
+    Name of the generator behind the global random stream, see `set_rng_backend`.
+    """
+
+def half_cauchy_rand(scale: builtins.float = 1.0) -> builtins.float:
+    r"""
+    Half-Cauchy random number `|C|` for a Cauchy variable `C` with scale `scale`, sampled
+    exactly as `scale * tan(pi U / 2)` with `U` uniform on `[0, 1)`; its median is `scale`.
+    """
+
+def half_cauchy_rands(n: builtins.int, /, scale: builtins.float = 1.0, seed: typing.Optional[builtins.int] = None) -> numpy.typing.NDArray[numpy.float64]: ...
+
+def has_parquet() -> builtins.bool:
+    r"""
+    Whether the extension was built with the `parquet` feature.
+    """
+
+def increment_normality_test(positions: numpy.typing.NDArray[numpy.float64], time_step: builtins.float, diffusion_coefficient: builtins.float) -> tuple[builtins.float, builtins.float]:
+    r"""
+    Anderson–Darling test that the increments of a path are `N(0, 2 D time_step)`.
+
+    The increments are divided by `sqrt(2 D time_step)` and compared with the standard
+    normal distribution, so the path must be sampled on a uniform grid. The p-value uses the
+    approximation of Marsaglia & Marsaglia (2004); a small value rejects Gaussian increments
+    with this diffusion coefficient. Returns `(statistic, pvalue)`.
+    """
+
+def integrate_path(positions: numpy.typing.NDArray[numpy.float64], time_step: builtins.float) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Running time integral `Y(t_i) = ∫_0^{t_i} X(s) ds` of a path sampled every `time_step`.
+
+    The trapezoidal rule is used and `Y(0) = 0`, so the result has the length of `positions`.
+    """
+
+def integrated_cir_subordinator_simulate(kappa: builtins.float, theta: builtins.float, xi: builtins.float, v0: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate the integrated CIR time change `T(t) = int_0^t v(s) ds`, where
+    `dv = kappa (theta - v) dt + xi sqrt(v) dW` with `v(0) = v0`.
+
+    The variance is integrated with the full-truncation Euler scheme and the trapezoidal
+    rule on `max(v, 0)`, so `T` is nondecreasing. At stationarity `T` grows at rate `theta`.
+    """
+
 def inv_subordinator_central_moment(alpha: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
     r"""
     Get the central moment of inverse subordinator process.
@@ -1151,17 +1847,27 @@ def inv_subordinator_raw_moment(alpha: builtins.float, duration: builtins.float,
     Get the raw moment of inverse subordinator process.
     """
 
-def inv_subordinator_simulate(alpha: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def inv_subordinator_simulate(alpha: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate inverse subordinator process.
     """
 
-def langevin_central_moment(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, order: builtins.int, particles: builtins.int, time_step: builtins.float) -> builtins.float:
+def inverse_gaussian_rand(mu: builtins.float, lambda_: builtins.float) -> builtins.float:
+    r"""
+    Inverse Gaussian random number with mean `mu` and shape `lambda_`.
+
+    It is the first passage time of a Brownian motion with drift `v > 0` and diffusion
+    coefficient `D` to a level at distance `L`, with `mu = L / v` and `lambda_ = L^2 / (2D)`.
+    """
+
+def inverse_gaussian_rands(n: builtins.int, /, mu: builtins.float, lambda_: builtins.float, seed: typing.Optional[builtins.int] = None) -> numpy.typing.NDArray[numpy.float64]: ...
+
+def langevin_central_moment(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, order: builtins.int, particles: builtins.int, time_step: builtins.float, interpretation: builtins.str = 'ito', diffusion_prime_func: typing.Optional[typing.Any] = None) -> builtins.float:
     r"""
     Get the central moment of Langevin process.
     """
 
-def langevin_eatamsd(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def langevin_eatamsd(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the effective time-averaged mean squared displacement of Langevin process.
     """
@@ -1181,12 +1887,12 @@ def langevin_fpt_raw_moment(drift_func: typing.Any, diffusion_func: typing.Any,
     Get the raw moment of the first passage time of Langevin process.
     """
 
-def langevin_frac_central_moment(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, order: builtins.float, particles: builtins.int, time_step: builtins.float) -> builtins.float:
+def langevin_frac_central_moment(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, order: builtins.float, particles: builtins.int, time_step: builtins.float, interpretation: builtins.str = 'ito', diffusion_prime_func: typing.Optional[typing.Any] = None) -> builtins.float:
     r"""
     Get the fractional central moment of Langevin process.
     """
 
-def langevin_frac_raw_moment(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, order: builtins.float, particles: builtins.int, time_step: builtins.float) -> builtins.float:
+def langevin_frac_raw_moment(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, order: builtins.float, particles: builtins.int, time_step: builtins.float, interpretation: builtins.str = 'ito', diffusion_prime_func: typing.Optional[typing.Any] = None) -> builtins.float:
     r"""
     Get the fractional raw moment of Langevin process.
     """
@@ -1216,27 +1922,69 @@ def langevin_occupation_time_raw_moment(drift_func: typing.Any, diffusion_func:
     Get the raw moment of the occupation time of Langevin process.
     """
 
-def langevin_raw_moment(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, order: builtins.int, particles: builtins.int, time_step: builtins.float) -> builtins.float:
+def langevin_raw_moment(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, order: builtins.int, particles: builtins.int, time_step: builtins.float, interpretation: builtins.str = 'ito', diffusion_prime_func: typing.Optional[typing.Any] = None) -> builtins.float:
     r"""
     Get the raw moment of Langevin process.
     """
 
-def langevin_simulate(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def langevin_simulate(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1, interpretation: builtins.str = 'ito', diffusion_prime_func: typing.Optional[typing.Any] = None) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate Langevin process.
+
+    The time axis starts at `t0`: the returned times and the time argument passed to
+    `drift_func` and `diffusion_func` are both shifted by `t0`.
+
+    `interpretation` is `"ito"` or `"stratonovich"`. A Stratonovich equation is integrated
+    as the equivalent Itô equation with the drift `f + g g' / 2`, where `g'(x, t)` is given
+    by `diffusion_prime_func`; for additive noise (`g' = 0`) the two coincide. The same
+    arguments apply to the Langevin moment functions.
+    """
+
+def langevin_simulate_tabulated(x_grid: numpy.typing.NDArray[numpy.float64], drift_table: numpy.typing.NDArray[numpy.float64], diffusion_table: numpy.typing.NDArray[numpy.float64], start_position: builtins.float, duration: builtins.float, time_step: builtins.float, clamp: builtins.bool = True) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate Langevin process with position-dependent drift and diffusion given as tables.
+
+    The coefficients are linearly interpolated over `x_grid`. Outside the grid they are
+    clamped to the boundary values if `clamp` is true; otherwise leaving the grid is an error.
+    """
+
+def langevin_simulate_with_work(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate Langevin process together with the work done by the drift along the path.
+
+    The work is accumulated in the Stratonovich (midpoint) sense,
+    `W_{i+1} = W_i + f((x_i + x_{i+1}) / 2, (t_i + t_{i+1}) / 2) (x_{i+1} - x_i)`, with `W_0 = 0`.
+    Errors raised by `drift_func` or `diffusion_func`, during the simulation or while
+    computing the work, are propagated unchanged.
+
+    As in `langevin_simulate`, the time axis starts at `t0` and `thin` keeps every
+    `thin`-th point of all three arrays; the work is accumulated on the full grid.
     """
 
-def langevin_tamsd(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def langevin_tamsd(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean squared displacement of Langevin process.
     """
 
+def langevin_vectorized_moment(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, duration: builtins.float, order: builtins.int, particles: builtins.int, time_step: builtins.float, central: builtins.bool = False, interpretation: builtins.str = 'ito', diffusion_prime_func: typing.Optional[typing.Any] = None) -> builtins.float:
+    r"""
+    Get the raw or central moment of Langevin process with vectorized coefficients.
+
+    All particles are advanced together by Euler–Maruyama, and `drift_func(x, t)` and
+    `diffusion_func(x, t)` are called once per step with the float64 array of all positions
+    and the current time. Each must return a float64 array of the same length or a scalar,
+    which is broadcast. This trades one Python call per particle and step for one per step.
+
+    `interpretation` and `diffusion_prime_func` are as in `langevin_simulate`; under the
+    Stratonovich interpretation `diffusion_prime_func` is vectorized in the same way.
+    """
+
 def levy_central_moment(start_position: builtins.float, alpha: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
     r"""
     Get the central moment of Levy process.
     """
 
-def levy_eatamsd(start_position: builtins.float, alpha: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def levy_eatamsd(start_position: builtins.float, alpha: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the effective time-averaged mean squared displacement of Levy process.
     """
@@ -1286,12 +2034,37 @@ def levy_raw_moment(start_position: builtins.float, alpha: builtins.float, durat
     Get the raw moment of Levy process.
     """
 
-def levy_simulate(start_position: builtins.float, alpha: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def levy_raw_moment_grid(start_position: builtins.float, alphas: typing.Sequence[builtins.float], durations: typing.Sequence[builtins.float], time_step: builtins.float, order: builtins.int, particles: builtins.int) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Get the raw moment of Levy process on a grid of `alphas` and `durations`.
+
+    Returns an array of shape `(len(alphas), len(durations))`; every cell is an independent
+    ensemble of `particles` paths and the cells run in parallel. All parameters are
+    validated before any simulation starts.
+    """
+
+def levy_simulate(start_position: builtins.float, alpha: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate Levy process.
+
+    The increments over a step `dt` are `dt^(1 / alpha) S_alpha(1, 0, 0)`, drawn from the
+    crate's random stream with the sampler of `StableGenerator`, so `set_seed` makes the
+    paths reproducible. For `alpha = 2` the unit-scale stable law is `S_2(1, 0, 0) = N(0, 2)`,
+    so the increments are `N(0, 2 dt)` and the process is Brownian motion with diffusion
+    coefficient `D = 1`, i.e. `bm_simulate(start_position, 1.0, ...)`.
+    """
+
+def levy_simulate_sweep(params: typing.Sequence[tuple[builtins.float, builtins.float, builtins.float, builtins.float]]) -> builtins.list[tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]]:
+    r"""
+    Simulate Levy process for a batch of `(start_position, alpha, duration, time_step)` entries.
+
+    Every entry is validated before any simulation starts; the first invalid one
+    raises an error naming its index, so no partial results are returned. The paths are
+    simulated in parallel but equal those of successive `levy_simulate` calls under the
+    same `set_seed`.
     """
 
-def levy_tamsd(start_position: builtins.float, alpha: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def levy_tamsd(start_position: builtins.float, alpha: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean squared displacement of Levy process.
     """
@@ -1331,11 +2104,35 @@ def levy_walk_raw_moment(alpha: builtins.float, velocity: builtins.float, start_
     Get the raw moment of Levy walk.
     """
 
-def levy_walk_simulate(alpha: builtins.float, velocity: builtins.float, start_position: builtins.float, duration: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def levy_walk_simulate(alpha: builtins.float, velocity: builtins.float, start_position: builtins.float, duration: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate Levy walk.
     """
 
+def local_scaling_exponent(simulate_fn: typing.Any, duration: builtins.float, time_step: builtins.float, particles: builtins.int, window: builtins.int) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Time-resolved anomalous diffusion exponent `d log MSD / d log t`.
+
+    The ensemble-averaged MSD `<(X(t) - X(0))^2>` is estimated on the simulation grid from
+    `particles` paths, then the exponent is the least-squares slope of `log MSD` against
+    `log t` over each run of `window` consecutive grid points with `t > 0`. The returned
+    times are the geometric means of the windows, so crossovers, e.g. from ballistic to
+    diffusive motion, show up directly. Windows with a vanishing MSD give NaN.
+    """
+
+def log_returns(prices: numpy.typing.NDArray[numpy.float64]) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Log returns `ln(p[i + 1] / p[i])` of a price series.
+    """
+
+def make_process(name: builtins.str, params: typing.Optional[typing.Mapping[builtins.str, builtins.float]] = None) -> Process:
+    r"""
+    Create a built-in process from its name and a dictionary of parameters.
+
+    Parameter names match the corresponding `*_simulate` functions; `start_position`
+    and `diffusion_coefficient` may be omitted where they have a natural default.
+    """
+
 def mean(simulate_fn: typing.Any, duration: builtins.float, time_step: builtins.float, particles: builtins.int) -> builtins.float: ...
 
 def meander_central_moment(duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
@@ -1343,7 +2140,7 @@ def meander_central_moment(duration: builtins.float, time_step: builtins.float,
     Get the central moment of Brownian meander.
     """
 
-def meander_eatamsd(duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def meander_eatamsd(duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the ensemble average of the time-averaged mean square displacement of Brownian meander.
     """
@@ -1403,30 +2200,84 @@ def meander_raw_moment(duration: builtins.float, time_step: builtins.float, orde
     Get the raw moment of Brownian meander.
     """
 
-def meander_simulate(duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def meander_simulate(duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate Brownian meander.
     """
 
-def meander_tamsd(duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def meander_tamsd(duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean square displacement of Brownian meander.
     """
 
-def moment(simulate_fn: typing.Any, central: builtins.bool, order: builtins.int, duration: builtins.float, time_step: builtins.float, particles: builtins.int) -> builtins.float: ...
+def mittag_leffler_rand(alpha: builtins.float, /, scale: builtins.float = 1.0) -> builtins.float: ...
+
+def mittag_leffler_rands(n: builtins.int, /, alpha: builtins.float, scale: builtins.float = 1.0, seed: typing.Optional[builtins.int] = None) -> numpy.typing.NDArray[numpy.float64]: ...
+
+def moment(simulate_fn: typing.Any, central: builtins.bool, order: builtins.int, duration: builtins.float, time_step: builtins.float, particles: builtins.int, compensated: builtins.bool = True, skip_nonfinite: builtins.bool = False) -> builtins.float:
+    r"""
+    Moment of the process at `duration`.
+
+    Central moments are accumulated online in a single simulation pass; `compensated`
+    only applies to raw moments.
+
+    An `order` of 0 gives 1 without simulating. A negative `order` gives an inverse raw
+    moment and requires every endpoint to be strictly positive; central moments need a
+    non-negative order. A result that overflowed to `inf` or `nan` issues a `RuntimeWarning`.
+    """
+
+def moment_diagnostic(simulate_fn: typing.Any, central: builtins.bool, order: builtins.int, duration: builtins.float, time_step: builtins.float, particles: builtins.int, compensated: builtins.bool = True, skip_nonfinite: builtins.bool = False) -> tuple[builtins.float, builtins.int]:
+    r"""
+    Like `moment`, but also returns the number of particles whose contribution was non-finite.
+
+    For central moments these are the particles with a non-finite position at `duration`.
+    """
 
 def msd(simulate_fn: typing.Any, duration: builtins.float, time_step: builtins.float, particles: builtins.int) -> builtins.float: ...
 
+def nig_raw_moment(alpha: builtins.float, beta: builtins.float, delta: builtins.float, mu: builtins.float, start_position: builtins.float, duration: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
+    r"""
+    Get the raw moment of a normal-inverse Gaussian (NIG) Levy process.
+
+    `X(duration)` is sampled exactly, since the NIG law is closed under convolution, so no
+    time step is needed. Moments of every order are finite.
+    """
+
+def nig_simulate(alpha: builtins.float, beta: builtins.float, delta: builtins.float, mu: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate a normal-inverse Gaussian (NIG) Levy process.
+
+    The process is Brownian motion with drift `beta` run on an inverse Gaussian clock: over
+    a step `h` the clock advances by `Z ~ IG(delta * h / gamma, (delta * h)^2)` with
+    `gamma = sqrt(alpha^2 - beta^2)`, and the position by `mu * h + beta * Z + sqrt(Z) * N`.
+    Unlike stable processes, all moments are finite; the mean at time `t` is
+    `start_position + (mu + delta * beta / gamma) * t`.
+    """
+
 def normal_rand(mu: builtins.float = 0.0, sigma: builtins.float = 1.0) -> builtins.float: ...
 
-def normal_rands(n: builtins.int, /, mu: builtins.float = 0.0, sigma: builtins.float = 1.0) -> numpy.typing.NDArray[numpy.float64]: ...
+def normal_rands(n: builtins.int, /, mu: builtins.float = 0.0, sigma: builtins.float = 1.0, seed: typing.Optional[builtins.int] = None) -> numpy.typing.NDArray[numpy.float64]: ...
+
+def normal_rands_into(out: numpy.typing.NDArray[numpy.float64], /, mu: builtins.float = 0.0, sigma: builtins.float = 1.0, seed: typing.Optional[builtins.int] = None) -> None: ...
+
+def num_grid_points(duration: builtins.float, time_step: builtins.float) -> builtins.int:
+    r"""
+    Get the number of grid points of a path simulated with `duration` and `time_step`.
+
+    The grid is `0, time_step, 2 * time_step, ...` with a final, possibly shorter, step
+    ending exactly at `duration`, i.e. `ceil(duration / time_step) + 1` points. This is the
+    `endpoint="extend"` policy of the simulators that accept one.
+    """
 
-def ou_central_moment(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
+def ou_central_moment(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int, burn_in: builtins.float = 0.0) -> builtins.float:
     r"""
     Get the central moment of Ornstein-Uhlenbeck process.
+
+    With `burn_in`, the moment is taken at `burn_in + duration`, after the transient from
+    `start_position` has decayed.
     """
 
-def ou_eatamsd(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def ou_eatamsd(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the effective time-averaged mean squared displacement of Ornstein-Uhlenbeck process.
     """
@@ -1446,19 +2297,27 @@ def ou_fpt_raw_moment(theta: builtins.float, sigma: builtins.float, start_positi
     Get the raw moment of the first passage time of Ornstein-Uhlenbeck process.
     """
 
-def ou_frac_central_moment(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.float, particles: builtins.int) -> builtins.float:
+def ou_frac_central_moment(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.float, particles: builtins.int, burn_in: builtins.float = 0.0) -> builtins.float:
     r"""
     Get the fractional central moment of Ornstein-Uhlenbeck process.
+
+    With `burn_in`, the moment is taken at `burn_in + duration`, after the transient from
+    `start_position` has decayed.
     """
 
-def ou_frac_raw_moment(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.float, particles: builtins.int) -> builtins.float:
+def ou_frac_raw_moment(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.float, particles: builtins.int, burn_in: builtins.float = 0.0) -> builtins.float:
     r"""
     Get the fractional raw moment of Ornstein-Uhlenbeck process.
+
+    With `burn_in`, the moment is taken at `burn_in + duration`, after the transient from
+    `start_position` has decayed.
     """
 
-def ou_mean(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, particles: builtins.int, time_step: builtins.float) -> builtins.float:
+def ou_mean(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, particles: builtins.int, time_step: builtins.float, burn_in: builtins.float = 0.0) -> builtins.float:
     r"""
     Get the mean of Ornstein-Uhlenbeck process.
+
+    See `ou_raw_moment` for `burn_in`.
     """
 
 def ou_msd(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, particles: builtins.int, time_step: builtins.float) -> builtins.float:
@@ -1481,21 +2340,65 @@ def ou_occupation_time_raw_moment(theta: builtins.float, sigma: builtins.float,
     Get the raw moment of the occupation time of Ornstein-Uhlenbeck process.
     """
 
-def ou_raw_moment(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
+def ou_raw_moment(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int, burn_in: builtins.float = 0.0) -> builtins.float:
     r"""
     Get the raw moment of Ornstein-Uhlenbeck process.
+
+    With `burn_in`, the moment is taken at `burn_in + duration`, after the transient from
+    `start_position` has decayed.
     """
 
-def ou_simulate(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def ou_raw_moment_crn(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int, seed: builtins.int) -> builtins.float:
+    r"""
+    Get the raw moment of Ornstein-Uhlenbeck process with common random numbers.
+
+    Particle `i` is integrated by Euler-Maruyama with Gaussian increments drawn from a
+    generator derived from `(seed, i)` only, so calls that share `seed` and `time_step` use
+    the same driving noise for any `theta`, `sigma` or `start_position`. The difference of
+    two such estimates at nearby parameter values is then a low-variance finite-difference
+    sensitivity.
+    """
+
+def ou_raw_moment_extrapolated(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
+    r"""
+    Get the raw moment of Ornstein-Uhlenbeck process, Richardson-extrapolated over `time_step`.
+
+    Every particle is integrated by Euler-Maruyama at `time_step` and at `time_step / 2`
+    with coupled noise (the coarse increments are sums of the fine ones), and the result is
+    `2 M(time_step / 2) - M(time_step)`. Without this coupling the extrapolation would
+    amplify the statistical error instead of reducing the bias.
+    """
+
+def ou_simulate(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float, endpoint: builtins.str = 'extend', burn_in: builtins.float = 0.0, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate Ornstein-Uhlenbeck process.
+
+    See `bm_simulate` for `endpoint`. A positive `burn_in` first runs the process for that
+    time and discards it, so the returned path starts from the state reached at `burn_in`
+    with times shifted back to 0.
+    """
+
+def ou_simulate_integrated(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate Ornstein-Uhlenbeck process together with its running time integral.
+
+    Returns the times, the path `V` and `X(t) = ∫_0^t V(s) ds` by the trapezoidal rule over
+    the simulation grid, e.g. the position of a particle with OU velocity `V`.
     """
 
-def ou_tamsd(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def ou_tamsd(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean squared displacement of Ornstein-Uhlenbeck process.
     """
 
+def ou_terminals(theta: builtins.float, sigma: builtins.float, start_position: builtins.float, duration: builtins.float, particles: builtins.int, qmc: builtins.bool = False) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Get the terminal values `X(duration)` of `particles` independent Ornstein-Uhlenbeck processes.
+
+    The values are sampled from the exact Gaussian transition
+    `N(x0 e^{-theta t}, sigma^2 (1 - e^{-2 theta t}) / (2 theta))`; see `bm_terminals` for `qmc`.
+    """
+
 def poisson_central_moment(lambda_: builtins.float, duration: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
     r"""
     Get the central moment of Poisson process.
@@ -1553,7 +2456,7 @@ def poisson_occupation_time_raw_moment(lambda_: builtins.float, domain: tuple[bu
 
 def poisson_rand(lambda_: builtins.float = 1.0) -> builtins.int: ...
 
-def poisson_rands(n: builtins.int, /, lambda_: builtins.float = 1.0) -> typing.Annotated[numpy.typing.NDArray[numpy.uintp], typing.Literal["N"]]: ...
+def poisson_rands(n: builtins.int, /, lambda_: builtins.float = 1.0, seed: typing.Optional[builtins.int] = None) -> typing.Annotated[numpy.typing.NDArray[numpy.uintp], typing.Literal["N"]]: ...
 
 def poisson_raw_moment(lambda_: builtins.float, duration: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
     r"""
@@ -1570,24 +2473,195 @@ def poisson_simulate_step(lambda_: builtins.float, num_step: builtins.int) -> tu
     Simulate Poisson process.
     """
 
-def skew_stable_rand(alpha: builtins.float) -> builtins.float: ...
+def process_central_moment(process: Process, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
+    r"""
+    Get the central moment of a process created by `make_process`.
+    """
+
+def process_fpt(process: Process, time_step: builtins.float, domain: tuple[builtins.float, builtins.float], max_duration: builtins.float) -> typing.Optional[builtins.float]:
+    r"""
+    Get the first passage time of a process created by `make_process`.
+    """
+
+def process_fpt_central_moment(process: Process, domain: tuple[builtins.float, builtins.float], order: builtins.int, particles: builtins.int, time_step: builtins.float, max_duration: builtins.float) -> typing.Optional[builtins.float]:
+    r"""
+    Get the central moment of the first passage time of a process created by `make_process`.
+    """
+
+def process_fpt_raw_moment(process: Process, domain: tuple[builtins.float, builtins.float], order: builtins.int, particles: builtins.int, time_step: builtins.float, max_duration: builtins.float) -> typing.Optional[builtins.float]:
+    r"""
+    Get the raw moment of the first passage time of a process created by `make_process`.
+    """
+
+def process_occupation_time(process: Process, time_step: builtins.float, domain: tuple[builtins.float, builtins.float], duration: builtins.float) -> builtins.float:
+    r"""
+    Get the occupation time of a process created by `make_process`.
+    """
+
+def process_occupation_time_central_moment(process: Process, domain: tuple[builtins.float, builtins.float], order: builtins.int, particles: builtins.int, time_step: builtins.float, duration: builtins.float) -> builtins.float:
+    r"""
+    Get the central moment of the occupation time of a process created by `make_process`.
+    """
+
+def process_occupation_time_raw_moment(process: Process, domain: tuple[builtins.float, builtins.float], order: builtins.int, particles: builtins.int, time_step: builtins.float, duration: builtins.float) -> builtins.float:
+    r"""
+    Get the raw moment of the occupation time of a process created by `make_process`.
+    """
+
+def process_raw_moment(process: Process, duration: builtins.float, time_step: builtins.float, order: builtins.int, particles: builtins.int) -> builtins.float:
+    r"""
+    Get the raw moment of a process created by `make_process`.
+
+    Negative orders are only supported for `gamma` and for `geometric_bm` with a positive
+    `start_position`.
+    """
+
+def raw_moment_from_array(positions: numpy.typing.NDArray[numpy.float64], order: builtins.int) -> builtins.float:
+    r"""
+    Raw moment `E[X(T)^order]` of an ensemble given as a `(particles, points)` array.
+
+    Only the last column, i.e. the terminal positions, enters the estimate.
+    """
+
+def reflected_fbm_simulate(start_position: builtins.float, hurst_exponent: builtins.float, duration: builtins.float, time_step: builtins.float, lower: builtins.float, upper: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate FBm reflected at `lower` and `upper`.
+
+    The unconstrained FBm path `X` is folded pointwise into `[lower, upper]`: with
+    `L = upper - lower` and `r = (X - lower) mod 2L`, the reflected position is
+    `lower + r` if `r <= L` and `lower + 2L - r` otherwise. This is the iterated mirror
+    image at both walls, so the increments keep their long-range correlations away from the
+    walls; it is not the Skorokhod (minimal pushing) reflection, which differs from folding
+    for `H != 0.5`. `t0` and `thin` are as in `fbm_simulate`.
+    """
+
+def resample_step_path(times: numpy.typing.NDArray[numpy.float64], values: numpy.typing.NDArray[numpy.float64], grid: numpy.typing.NDArray[numpy.float64]) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Evaluate the right-continuous step function defined by `(times, values)` on `grid`.
+
+    Each grid point takes the last value whose time is at or before it, which aligns the
+    event-based output of jump processes such as `ctrw_simulate_duration` on a common grid.
+    `times` and `grid` must be non-decreasing, and `grid` must not start before `times[0]`.
+    """
+
+def rng_get_state() -> builtins.bytes:
+    r"""
+    Serialize the state of the global random stream, including its backend.
+
+    The stream only drives the samplers implemented in this crate: the unseeded random
+    number functions and the simulators whose documentation says they draw from it. The
+    upstream `diffusionx` simulators use their own generators.
+    """
+
+def rng_set_state(state: builtins.bytes) -> None:
+    r"""
+    Restore the global random stream from a state returned by `rng_get_state`.
+
+    This also restores the backend. States written before backends were selectable are
+    restored with the default `"xoshiro256plusplus"` backend.
+    """
+
+def seed_from_entropy() -> builtins.int:
+    r"""
+    Draw a fresh seed from system entropy, install it with `set_seed` and return it.
+
+    Logging the returned seed allows the subsequent draws to be reproduced exactly by
+    calling `set_seed` with it.
+    """
+
+def set_max_points(max_points: builtins.int) -> None:
+    r"""
+    Set the maximum number of grid points a single simulated path may have.
+    """
+
+def set_rng_backend(name: builtins.str) -> None:
+    r"""
+    Select the generator behind the global random stream and behind seeded draws.
+
+    `name` is one of `"xoshiro256plusplus"` (the default), `"pcg64"` and `"chacha12"`.
+    The stream position is kept, so call `set_seed` afterwards for a reproducible sequence;
+    the same seed gives different numbers under different backends.
+    """
+
+def set_seed(seed: builtins.int) -> None:
+    r"""
+    Reset the global random stream to the one determined by `seed`.
+
+    This reproduces the crate's own samplers only, see `rng_get_state`; paths of the
+    upstream `diffusionx` simulators are not seeded by it.
+    """
+
+def simple_returns(prices: numpy.typing.NDArray[numpy.float64]) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Simple returns `p[i + 1] / p[i] - 1` of a price series.
+    """
+
+def simulate_reduce(process_name: builtins.str, params: typing.Optional[typing.Mapping[builtins.str, builtins.float]], duration: builtins.float, time_step: builtins.float, particles: builtins.int, reducer: builtins.str) -> builtins.float:
+    r"""
+    Simulate `particles` paths of a built-in process and reduce them to one statistic.
+
+    The process is created as by `make_process(process_name, params)`. Each path is reduced
+    inside the parallel loop and dropped at once, so no trajectory reaches Python. `reducer`
+    is one of `mean_terminal`, `var_terminal` (population variance of `X(duration)`),
+    `mean_max` (mean running maximum) and `fraction_positive_terminal`.
+    """
+
+def skew_stable_rand(alpha: builtins.float, scale: builtins.float = 1.0, shift: builtins.float = 0.0) -> builtins.float:
+    r"""
+    Totally skewed stable random number `S_alpha(scale, 1, shift)` in the S1 parameterization.
+
+    This is `stable_rand(alpha, 1.0, scale, shift)`, drawn from the crate's random stream.
+    The variate is one-sided, at least `shift`, only for `alpha < 1`.
+    """
+
+def skew_stable_rands(n: builtins.int, alpha: builtins.float, scale: builtins.float = 1.0, shift: builtins.float = 0.0, seed: typing.Optional[builtins.int] = None) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Totally skewed stable random numbers, see `skew_stable_rand`.
+    """
+
+def stable_cdf(x: numpy.typing.NDArray[numpy.float64], alpha: builtins.float, beta: builtins.float, sigma: builtins.float = 1.0, mu: builtins.float = 0.0) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Cumulative distribution function of the stable distribution `S(alpha, beta, sigma, mu)`.
+
+    The parameterization matches the samplers in `random` (Samorodnitsky–Taqqu `S1`).
+    """
+
+def stable_pdf(x: numpy.typing.NDArray[numpy.float64], alpha: builtins.float, beta: builtins.float, sigma: builtins.float = 1.0, mu: builtins.float = 0.0) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Probability density function of the stable distribution `S(alpha, beta, sigma, mu)`.
 
-def skew_stable_rands(n: builtins.int, alpha: builtins.float) -> numpy.typing.NDArray[numpy.float64]: ...
+    The parameterization matches the samplers in `random` (Samorodnitsky–Taqqu `S1`).
+    """
 
-def stable_rand(alpha: builtins.float, beta: builtins.float, /, sigma: builtins.float = 1.0, mu: builtins.float = 0.0) -> builtins.float: ...
+def stable_rand(alpha: builtins.float, beta: builtins.float, /, sigma: builtins.float = 1.0, mu: builtins.float = 0.0, parameterization: builtins.str = 'S1') -> builtins.float:
+    r"""
+    Stable random number `S_alpha(sigma, beta, mu)`.
 
-def stable_rands(n: builtins.int, /, alpha: builtins.float, beta: builtins.float, sigma: builtins.float = 1.0, mu: builtins.float = 0.0) -> numpy.typing.NDArray[numpy.float64]: ...
+    Every `alpha` is sampled from the crate's random stream, so `set_seed` makes the draws
+    reproducible. For `alpha = 2` this is the normal distribution with mean `mu` and
+    standard deviation `sqrt(2) * sigma`, which is sampled directly.
+    """
+
+def stable_rands(n: builtins.int, /, alpha: builtins.float, beta: builtins.float, sigma: builtins.float = 1.0, mu: builtins.float = 0.0, parameterization: builtins.str = 'S1', seed: typing.Optional[builtins.int] = None) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Stable random numbers, see `stable_rand`.
+    """
 
 def subordinated_langevin_central_moment(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, order: builtins.int, particles: builtins.int, time_step: builtins.float) -> builtins.float:
     r"""
     Get the central moment of SubordinatedLangevin process.
     """
 
-def subordinated_langevin_eatamsd(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def subordinated_langevin_eatamsd(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the effective time-averaged mean squared displacement of SubordinatedLangevin process.
     """
 
+def subordinated_langevin_eb_parameter(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, delta: builtins.float, particles: builtins.int, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+    r"""
+    Get the ergodicity-breaking parameter `Var(TAMSD) / E[TAMSD]^2` of SubordinatedLangevin process.
+    """
+
 def subordinated_langevin_fpt(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, domain: tuple[builtins.float, builtins.float], max_duration: builtins.float, time_step: builtins.float) -> typing.Optional[builtins.float]:
     r"""
     Get the first passage time of SubordinatedLangevin process.
@@ -1643,12 +2717,12 @@ def subordinated_langevin_raw_moment(drift_func: typing.Any, diffusion_func: typ
     Get the raw moment of SubordinatedLangevin process.
     """
 
-def subordinated_langevin_simulate(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def subordinated_langevin_simulate(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate SubordinatedLangevin process.
     """
 
-def subordinated_langevin_tamsd(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float:
+def subordinated_langevin_tamsd(drift_func: typing.Any, diffusion_func: typing.Any, start_position: builtins.float, alpha: builtins.float, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float:
     r"""
     Get the time-averaged mean squared displacement of SubordinatedLangevin process.
     """
@@ -1688,18 +2762,58 @@ def subordinator_occupation_time_raw_moment(alpha: builtins.float, domain: tuple
     Get the raw moment of the occupation time of subordinator process.
     """
 
-def subordinator_simulate(alpha: builtins.float, duration: builtins.float, time_step: builtins.float) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+def subordinator_pair_simulate(alpha: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
+    r"""
+    Simulate an alpha-stable subordinator `S(u)` together with its inverse `E(t)`.
+
+    `S` is simulated on the operational grid `u_i = i * time_step` until it first exceeds
+    `duration`, and `E(t) = min{u_i : S(u_i) > t}` is evaluated from the same path on the
+    physical grid `t_j = j * time_step` up to `duration`. Returns
+    `(operational_time, subordinator, physical_time, inverse)`.
+
+    `t0` shifts both time axes, as in `subordinator_simulate`, and `thin` keeps every
+    `thin`-th point of each pair; `E` is always evaluated from the full path.
+    """
+
+def subordinator_simulate(alpha: builtins.float, duration: builtins.float, time_step: builtins.float, t0: builtins.float = 0.0, thin: builtins.int = 1) -> tuple[numpy.typing.NDArray[numpy.float64], numpy.typing.NDArray[numpy.float64]]:
     r"""
     Simulate subordinator process.
     """
 
-def tamsd(simulate_fn: typing.Any, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int) -> builtins.float: ...
+def tamsd(simulate_fn: typing.Any, duration: builtins.float, delta: builtins.float, time_step: builtins.float, quad_order: builtins.int, rule: builtins.str = 'gauss_legendre') -> builtins.float: ...
+
+def tamsd_convergence(simulate_fn: typing.Any, duration: builtins.float, delta: builtins.float, time_step: builtins.float, refinement_factors: typing.Sequence[builtins.int], quad_order: builtins.int) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    TAMSD at `time_step` and at `time_step / f` for each `f` in `refinement_factors`.
+
+    Each entry is a single-path `tamsd` with Gauss–Legendre quadrature; a sequence that
+    plateaus indicates that `time_step` is fine enough. All entries use one path simulated at
+    `time_step / lcm(refinement_factors)`, and the coarser levels subsample it, so the
+    differences between entries reflect the discretization and not the path-to-path spread.
+    """
+
+def tamsd_from_array(positions: numpy.typing.NDArray[numpy.float64], delta: builtins.float, time_step: builtins.float) -> numpy.typing.NDArray[numpy.float64]:
+    r"""
+    Time-averaged mean squared displacement of every row of a `(particles, points)` array.
+
+    The rows are sampled on a uniform grid with spacing `time_step`; `delta` is rounded to
+    the nearest multiple of it. The mean of the result is the ensemble-averaged TAMSD.
+    """
 
 def uniform_rand_float(low: builtins.float = 0.0, high: builtins.float = 1.0, /, end: builtins.bool = False) -> builtins.float: ...
 
 def uniform_rand_int(low: builtins.int, high: builtins.int, /, end: builtins.bool = False) -> builtins.int: ...
 
-def uniform_rands_float(n: builtins.int, /, low: builtins.float = 0.0, high: builtins.float = 1.0, end: builtins.bool = False) -> numpy.typing.NDArray[numpy.float64]: ...
+def uniform_rands_float(n: builtins.int, /, low: builtins.float = 0.0, high: builtins.float = 1.0, end: builtins.bool = False, seed: typing.Optional[builtins.int] = None) -> numpy.typing.NDArray[numpy.float64]: ...
+
+def uniform_rands_float_into(out: numpy.typing.NDArray[numpy.float64], /, low: builtins.float = 0.0, high: builtins.float = 1.0, end: builtins.bool = False, seed: typing.Optional[builtins.int] = None) -> None: ...
 
-def uniform_rands_int(n: builtins.int, low: builtins.int, high: builtins.int, /, end: builtins.bool = False) -> numpy.typing.NDArray[numpy.int64]: ...
+def uniform_rands_int(n: builtins.int, low: builtins.int, high: builtins.int, /, end: builtins.bool = False, seed: typing.Optional[builtins.int] = None) -> numpy.typing.NDArray[numpy.int64]: ...
+
+def weibull_rand(shape: builtins.float, scale: builtins.float = 1.0) -> builtins.float:
+    r"""
+    Weibull random number with shape `shape` and scale `scale`, with survival function
+    `exp(-(x / scale)^shape)`; `shape = 1` is the exponential distribution.
+    """
 
+def weibull_rands(n: builtins.int, /, shape: builtins.float, scale: builtins.float = 1.0, seed: typing.Optional[builtins.int] = None) -> numpy.typing.NDArray[numpy.float64]: ...
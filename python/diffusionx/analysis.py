@@ -0,0 +1,286 @@
+from math import isfinite
+from typing import Mapping, Sequence, Union
+
+import numpy as np
+import numpy.typing as npt
+
+from . import _core
+
+real = Union[float, int]
+
+
+def _ensure_ensemble(positions: npt.ArrayLike) -> np.ndarray:
+    try:
+        array = np.ascontiguousarray(positions, dtype=np.float64)
+    except (TypeError, ValueError) as e:
+        raise TypeError(f"positions must be an array of real numbers. Error: {e}") from e
+    if array.ndim == 1:
+        array = array.reshape(1, -1)
+    if array.ndim != 2 or array.size == 0:
+        raise ValueError(
+            f"positions must be a non-empty (particles, points) array, got shape {array.shape}"
+        )
+    return array
+
+
+def _ensure_vector(data: npt.ArrayLike, name: str) -> np.ndarray:
+    try:
+        array = np.ascontiguousarray(data, dtype=np.float64)
+    except (TypeError, ValueError) as e:
+        raise TypeError(f"{name} must be an array of real numbers. Error: {e}") from e
+    if array.ndim != 1:
+        raise ValueError(f"{name} must be one-dimensional, got shape {array.shape}")
+    return array
+
+
+def _ensure_positive(value: real, name: str) -> float:
+    if isinstance(value, bool) or not isinstance(value, (int, float)):
+        raise TypeError(f"{name} must be a real number, got {type(value).__name__}")
+    value = float(value)
+    if not (isfinite(value) and value > 0):
+        raise ValueError(f"{name} must be positive and finite, got {value}")
+    return value
+
+
+def raw_moment(positions: npt.ArrayLike, order: int) -> float:
+    """Raw moment of the terminal positions of a pre-simulated ensemble
+
+    Args:
+        positions (array_like): trajectories as a (particles, points) array, one row per particle. A 1-D array is a single trajectory.
+        order (int): order of the moment.
+
+    Returns:
+        float: the raw moment E[X(T)^order]
+    """
+    if isinstance(order, bool) or not isinstance(order, int):
+        raise TypeError(f"order must be an integer, got {type(order).__name__}")
+    return _core.raw_moment_from_array(_ensure_ensemble(positions), order)
+
+
+def tamsd(positions: npt.ArrayLike, delta: real, time_step: real) -> np.ndarray:
+    """Time-averaged mean squared displacement of every trajectory of a pre-simulated ensemble
+
+    Args:
+        positions (array_like): trajectories on a uniform time grid as a (particles, points) array. A 1-D array is a single trajectory.
+        delta (real): lag time, rounded to the nearest multiple of time_step.
+        time_step (real): spacing of the time grid.
+
+    Returns:
+        np.ndarray: the TAMSD of each trajectory; its mean is the ensemble-averaged TAMSD
+    """
+    delta = _ensure_positive(delta, "delta")
+    time_step = _ensure_positive(time_step, "time_step")
+    return _core.tamsd_from_array(_ensure_ensemble(positions), delta, time_step)
+
+
+def estimate_diffusion_coefficient(
+    positions: npt.ArrayLike, time_step: real, deltas: Sequence[real]
+) -> float:
+    """Estimate the diffusion coefficient of a single trajectory from the slope of its TAMSD
+
+    Args:
+        positions (array_like): one-dimensional trajectory on a uniform time grid.
+        time_step (real): spacing of the time grid.
+        deltas (Sequence[real]): lag times, each rounded to the nearest multiple of time_step.
+
+    Returns:
+        float: D fitted by least squares to TAMSD(delta) = 2 * D * delta, assuming normal diffusion
+    """
+    time_step = _ensure_positive(time_step, "time_step")
+    deltas = [_ensure_positive(delta, "delta") for delta in deltas]
+    return _core.estimate_diffusion_coefficient(
+        _ensure_vector(positions, "positions"), time_step, deltas
+    )
+
+
+def increment_normality_test(
+    positions: npt.ArrayLike, time_step: real, diffusion_coefficient: real
+) -> tuple[float, float]:
+    """Anderson-Darling test that the increments of a path are those of Brownian motion
+
+    The increments are standardized by sqrt(2 D time_step) and compared with the standard
+    normal distribution, which checks a simulator of a Gaussian-driven process.
+
+    Args:
+        positions (array_like): one-dimensional path sampled every time_step, with at least 3 points.
+        time_step (real): spacing of the samples. Positive real number.
+        diffusion_coefficient (real): expected diffusion coefficient D. Positive real number.
+
+    Returns:
+        tuple[float, float]: the Anderson-Darling statistic and its p-value
+    """
+    return _core.increment_normality_test(
+        _ensure_vector(positions, "positions"),
+        _ensure_positive(time_step, "time_step"),
+        _ensure_positive(diffusion_coefficient, "diffusion_coefficient"),
+    )
+
+
+def fit_stable(data: npt.ArrayLike) -> tuple[float, float, float, float]:
+    """Fit a stable distribution with McCulloch's quantile estimator
+
+    Args:
+        data (array_like): one-dimensional sample of at least 100 finite values.
+
+    Returns:
+        tuple[float, float, float, float]: the estimates (alpha, beta, sigma, mu), with mu in the
+            "S1" parameterization, the default of `diffusionx.random.stable_rand`
+    """
+    return _core.fit_stable(_ensure_vector(data, "data"))
+
+
+def fit_ou(data: npt.ArrayLike, time_step: real) -> tuple[float, float, float]:
+    """Fit an Ornstein-Uhlenbeck process dX = theta (mu - X) dt + sigma dW by maximum likelihood
+
+    The exact OU transition is a Gaussian AR(1) model, so the estimates follow from the least
+    squares regression of X[k+1] on X[k].
+
+    Args:
+        data (array_like): one path of at least 3 finite values sampled every time_step.
+        time_step (real): sampling interval of data, positive.
+
+    Returns:
+        tuple[float, float, float]: the estimates (theta, sigma, mu); a RuntimeWarning is issued
+            if theta <= 0, i.e. the data do not look stationary
+    """
+    time_step = float(time_step)
+    return _core.fit_ou(_ensure_vector(data, "data"), time_step)
+
+
+def resample_step_path(
+    times: npt.ArrayLike, values: npt.ArrayLike, grid: npt.ArrayLike
+) -> np.ndarray:
+    """Resample the event-based output of a jump process onto a time grid
+
+    Args:
+        times (array_like): non-decreasing event times, e.g. from `CTRW.simulate` or `Poisson.simulate`.
+        values (array_like): value of the path from each event time on.
+        grid (array_like): non-decreasing times to evaluate at, none of them before times[0].
+
+    Returns:
+        np.ndarray: the last value at or before each grid time
+    """
+    return _core.resample_step_path(
+        _ensure_vector(times, "times"),
+        _ensure_vector(values, "values"),
+        _ensure_vector(grid, "grid"),
+    )
+
+
+def integrate_path(positions: npt.ArrayLike, time_step: real) -> np.ndarray:
+    """Running time integral of a path sampled on a uniform grid
+
+    Args:
+        positions (array_like): one-dimensional path sampled every time_step.
+        time_step (real): spacing of the samples. Positive real number.
+
+    Returns:
+        np.ndarray: trapezoidal integral from the first sample to each sample, starting at 0
+    """
+    return _core.integrate_path(
+        _ensure_vector(positions, "positions"), _ensure_positive(time_step, "time_step")
+    )
+
+
+def log_returns(prices: npt.ArrayLike) -> np.ndarray:
+    """Log returns of a price series, e.g. from `GeometricBm.simulate`
+
+    Args:
+        prices (array_like): one-dimensional series of at least 2 strictly positive prices.
+
+    Returns:
+        np.ndarray: log(p[i + 1] / p[i]), one element shorter than prices
+    """
+    return _core.log_returns(_ensure_vector(prices, "prices"))
+
+
+def simple_returns(prices: npt.ArrayLike) -> np.ndarray:
+    """Simple returns of a price series
+
+    Args:
+        prices (array_like): one-dimensional series of at least 2 strictly positive prices.
+
+    Returns:
+        np.ndarray: p[i + 1] / p[i] - 1, one element shorter than prices
+    """
+    return _core.simple_returns(_ensure_vector(prices, "prices"))
+
+
+def analytic_moment(
+    process: str,
+    params: Mapping[str, real] | None,
+    duration: real,
+    order: int,
+) -> float | None:
+    """Closed-form raw moment E[X(duration)^order], useful to validate Monte Carlo estimates
+
+    Args:
+        process (str): one of "bm", "drifted_bm", "ou", "ou_stationary", "geometric_bm" and "poisson"; any other name gives None.
+        params (Mapping[str, real] | None): parameters named as for `simulation.make_process`, plus "drift" for "drifted_bm" and "lambda_" for "poisson".
+        duration (real): time at which the moment is taken. Non-negative real number.
+        order (int): order of the moment; negative orders are only supported for "geometric_bm".
+
+    Returns:
+        float | None: the moment, or None if no closed form is known
+    """
+    if isinstance(order, bool) or not isinstance(order, int):
+        raise TypeError(f"order must be an integer, got {type(order).__name__}")
+    if isinstance(duration, bool) or not isinstance(duration, (int, float)):
+        raise TypeError(f"duration must be a real number, got {type(duration).__name__}")
+    params = None if params is None else {k: float(v) for k, v in params.items()}
+    return _core.analytic_moment(process, params, float(duration), order)
+
+
+def bm_fpt_pdf(
+    start_position: real,
+    diffusion_coefficient: real,
+    drift: real,
+    boundary: real,
+    t: npt.ArrayLike,
+) -> np.ndarray:
+    """Analytic first passage density of drifted Brownian motion to a single level
+
+    For X(t) = start_position + drift * t + sqrt(2 D) W(t), the density is the inverse
+    Gaussian a / sqrt(4 pi D t^3) exp(-(a - v t)^2 / (4 D t)), where a is the distance to
+    the boundary and v the drift towards it. If the drift points away from the boundary the
+    density integrates to the hitting probability exp(-a |v| / D) < 1.
+
+    Args:
+        start_position (real): starting position, different from boundary.
+        diffusion_coefficient (real): diffusion coefficient D. Positive real number.
+        drift (real): drift velocity.
+        boundary (real): the absorbing level.
+        t (array_like): one-dimensional array of times; the density is 0 for t <= 0.
+
+    Returns:
+        np.ndarray: the density at each time in t
+    """
+    return _core.bm_fpt_pdf(
+        float(start_position),
+        float(diffusion_coefficient),
+        float(drift),
+        float(boundary),
+        _ensure_vector(t, "t"),
+    )
+
+
+def bm_fpt_mean_analytic(
+    start_position: real,
+    diffusion_coefficient: real,
+    drift: real,
+    boundary: real,
+) -> float:
+    """Analytic mean first passage time of drifted Brownian motion to a single level
+
+    Args:
+        start_position (real): starting position, different from boundary.
+        diffusion_coefficient (real): diffusion coefficient D. Positive real number.
+        drift (real): drift velocity.
+        boundary (real): the absorbing level.
+
+    Returns:
+        float: distance / drift when the drift points towards the boundary, otherwise inf
+    """
+    return _core.bm_fpt_mean_analytic(
+        float(start_position), float(diffusion_coefficient), float(drift), float(boundary)
+    )
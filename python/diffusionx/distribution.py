@@ -3,7 +3,7 @@ from typing import Union
 
 import numpy as np
 
-from . import random
+from . import _core, random
 from .types import DType
 
 real = Union[float, int]
@@ -257,6 +257,36 @@ class Stable:
         else:
             return random.stable_rand(self.alpha, self.beta, self.sigma, self.mu, size)
 
+    def pdf(self, x: real | np.ndarray) -> real | np.ndarray:
+        """Probability density function, evaluated by numerical integration
+
+        Args:
+            x (real | np.ndarray): points at which to evaluate the density.
+
+        Returns:
+            real | np.ndarray: density values, with the same shape as x
+        """
+        return self._evaluate(_core.stable_pdf, x)
+
+    def cdf(self, x: real | np.ndarray) -> real | np.ndarray:
+        """Cumulative distribution function, evaluated by numerical integration
+
+        Args:
+            x (real | np.ndarray): points at which to evaluate the distribution function.
+
+        Returns:
+            real | np.ndarray: probabilities, with the same shape as x
+        """
+        return self._evaluate(_core.stable_cdf, x)
+
+    def _evaluate(self, func, x: real | np.ndarray) -> real | np.ndarray:
+        scalar = _is_real(x)
+        points = np.asarray(x, dtype=np.float64)
+        result = func(points.ravel(), self.alpha, self.beta, self.sigma, self.mu)
+        if scalar:
+            return float(result[0])
+        return result.reshape(points.shape)
+
     def __neg__(self):
         return Stable(self.alpha, -self.beta, self.sigma, -self.mu)
 
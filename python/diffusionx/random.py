@@ -1,9 +1,12 @@
+from contextlib import contextmanager
+from functools import partial
 from math import isfinite
-from typing import Callable, Union
+from typing import Callable, Iterator, Union
 
 import numpy as np
 
 from . import _core
+from ._core import StableGenerator as StableGenerator
 from .types import DType
 
 real = Union[float, int]
@@ -22,6 +25,16 @@ def _ensure_real(value: real, name: str) -> float:
     return value
 
 
+def _ensure_seed(seed: int | None) -> int | None:
+    if seed is None:
+        return None
+    if isinstance(seed, bool) or not isinstance(seed, int):
+        raise TypeError(f"seed must be an integer or None, got {type(seed).__name__}")
+    if not (0 <= seed < 2**64):
+        raise ValueError(f"Invalid seed {seed}, expected integer in [0, 2**64)")
+    return seed
+
+
 def _ensure_integer(value: real, name: str) -> int:
     value = _ensure_real(value, name)
     if not value.is_integer():
@@ -29,13 +42,27 @@ def _ensure_integer(value: real, name: str) -> int:
     return int(value)
 
 
+def _first_value(
+    array_generator: Callable[..., np.ndarray], *args
+) -> Union[float, int, bool]:
+    return array_generator(1, *args)[0].item()
+
+
 def _generate_random_values(
     size: int | tuple[int, ...],
     single_val_generator: Callable[..., Union[float, int, bool]],
     array_generator: Callable[..., np.ndarray],
     func_args: tuple,
+    seed: int | None = None,
 ) -> Union[float, int, bool, np.ndarray]:
-    """Helper function to generate single or multiple random values based on size."""
+    """Helper function to generate single or multiple random values based on size.
+
+    With a seed, every value comes from the chunk-seeded array generator, so the
+    output depends only on the seed and the element index, not on the thread count.
+    """
+    if seed is not None:
+        array_generator = partial(array_generator, seed=seed)
+        single_val_generator = partial(_first_value, array_generator)
     if isinstance(size, int) and not isinstance(size, bool):
         if size == 1:
             return single_val_generator(*func_args)
@@ -60,7 +87,7 @@ def _generate_random_values(
 
 
 def randexp(
-    size: int | tuple[int, ...] = 1, scale: real = 1.0
+    size: int | tuple[int, ...] = 1, scale: real = 1.0, seed: int | None = None
 ) -> Union[float, np.ndarray]:
     """
     Exponential distribution random numbers
@@ -68,6 +95,7 @@ def randexp(
     Args:
         size (int | tuple[int, ...], optional): shape of the output array. Defaults to 1. Positive integer or tuple of integers.
         scale (real, optional): exponential distribution parameter, mean of the distribution. Defaults to 1.0. Positive real number.
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
 
     Returns:
         float | np.ndarray: exponential random numbers
@@ -76,7 +104,9 @@ def randexp(
     if scale <= 0:
         raise ValueError(f"Invalid scale {scale}, expected positive real number")
 
-    return _generate_random_values(size, _core.exp_rand, _core.exp_rands, (scale,))
+    return _generate_random_values(
+        size, _core.exp_rand, _core.exp_rands, (scale,), _ensure_seed(seed)
+    )
 
 
 def uniform(
@@ -85,6 +115,7 @@ def uniform(
     high: real = 1.0,
     end: bool = False,
     dtype: DType = DType.Float,
+    seed: int | None = None,
 ) -> real | np.ndarray:
     """Uniform distribution random numbers
 
@@ -94,10 +125,12 @@ def uniform(
         high (real, optional): upper bound. Defaults to 1.0.
         end (bool, optional): whether to include the upper bound. Defaults to False.
         dtype (DType, optional): data type. Defaults to DType.FLOAT.
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
 
     Returns:
         real | np.ndarray: uniform random numbers
     """
+    seed = _ensure_seed(seed)
     if not isinstance(end, bool):
         raise TypeError(f"end must be a boolean, got {type(end).__name__}")
     if dtype == DType.Float:
@@ -110,6 +143,7 @@ def uniform(
             _core.uniform_rand_float,
             _core.uniform_rands_float,
             (_low, _high, end),
+            seed,
         )
     elif dtype == DType.Int:
         _low = _ensure_integer(low, "low")
@@ -117,14 +151,21 @@ def uniform(
         if _low >= _high:
             raise ValueError("Invalid bounds, low must be less than high")
         return _generate_random_values(
-            size, _core.uniform_rand_int, _core.uniform_rands_int, (_low, _high, end)
+            size,
+            _core.uniform_rand_int,
+            _core.uniform_rands_int,
+            (_low, _high, end),
+            seed,
         )
     else:
         raise ValueError(f"Invalid dtype {dtype}, expected DType.Float or DType.Int")
 
 
 def randn(
-    size: int | tuple[int, ...] = 1, mu: real = 0.0, sigma: real = 1.0
+    size: int | tuple[int, ...] = 1,
+    mu: real = 0.0,
+    sigma: real = 1.0,
+    seed: int | None = None,
 ) -> float | np.ndarray:
     """Normal distribution random numbers
 
@@ -132,6 +173,7 @@ def randn(
         size (int | tuple[int, ...], optional): shape of the output array. Defaults to 1. Positive integer or tuple of integers.
         mu (real, optional): mean. Defaults to 0.0.
         sigma (real, optional): standard deviation. Defaults to 1.0. Positive real number.
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
 
     Returns:
         float | np.ndarray: normal random numbers
@@ -142,16 +184,81 @@ def randn(
         raise ValueError(f"Invalid sigma {sigma}, expected positive real number")
 
     return _generate_random_values(
-        size, _core.normal_rand, _core.normal_rands, (_mu, _sigma)
+        size, _core.normal_rand, _core.normal_rands, (_mu, _sigma), _ensure_seed(seed)
     )
 
 
-def poisson(size: int | tuple[int, ...] = 1, lambda_: real = 1.0) -> real | np.ndarray:
+def _ensure_output(out: np.ndarray) -> np.ndarray:
+    if not isinstance(out, np.ndarray):
+        raise TypeError(f"out must be a numpy.ndarray, got {type(out).__name__}")
+    if out.dtype != np.float64:
+        raise TypeError(f"out must have dtype float64, got {out.dtype}")
+    if not (out.flags.c_contiguous and out.flags.writeable):
+        raise ValueError("out must be a writeable C-contiguous array")
+    # a view sharing the memory of out, so the core writes into the caller's buffer
+    return out.reshape(-1)
+
+
+def uniform_into(
+    out: np.ndarray,
+    low: real = 0.0,
+    high: real = 1.0,
+    end: bool = False,
+    seed: int | None = None,
+) -> None:
+    """Fill an existing float64 array with uniform random numbers in place
+
+    With the same seed, the values equal those of `uniform(out.size, low, high, end, seed=seed)`.
+
+    Args:
+        out (np.ndarray): writeable C-contiguous float64 array to fill.
+        low (real, optional): lower bound. Defaults to 0.0.
+        high (real, optional): upper bound. Defaults to 1.0.
+        end (bool, optional): whether to include the upper bound. Defaults to False.
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
+    """
+    seed = _ensure_seed(seed)
+    if not isinstance(end, bool):
+        raise TypeError(f"end must be a boolean, got {type(end).__name__}")
+    _low = _ensure_real(low, "low")
+    _high = _ensure_real(high, "high")
+    if _low >= _high:
+        raise ValueError("Invalid bounds, low must be less than high")
+    _core.uniform_rands_float_into(_ensure_output(out), _low, _high, end, seed)
+
+
+def randn_into(
+    out: np.ndarray,
+    mu: real = 0.0,
+    sigma: real = 1.0,
+    seed: int | None = None,
+) -> None:
+    """Fill an existing float64 array with normal random numbers in place
+
+    With the same seed, the values equal those of `randn(out.size, mu, sigma, seed=seed)`.
+
+    Args:
+        out (np.ndarray): writeable C-contiguous float64 array to fill.
+        mu (real, optional): mean. Defaults to 0.0.
+        sigma (real, optional): standard deviation. Defaults to 1.0. Positive real number.
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
+    """
+    _mu = _ensure_real(mu, "mu")
+    _sigma = _ensure_real(sigma, "sigma")
+    if _sigma <= 0:
+        raise ValueError(f"Invalid sigma {sigma}, expected positive real number")
+    _core.normal_rands_into(_ensure_output(out), _mu, _sigma, _ensure_seed(seed))
+
+
+def poisson(
+    size: int | tuple[int, ...] = 1, lambda_: real = 1.0, seed: int | None = None
+) -> real | np.ndarray:
     """Poisson distribution random numbers
 
     Args:
         size (int | tuple[int, ...], optional): shape of the output array. Defaults to 1. Positive integer or tuple of integers.
         lambda_ (real, optional): Poisson distribution parameter. Defaults to 1.0. Positive real number.
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
 
     Returns:
         real | np.ndarray: Poisson random numbers
@@ -161,7 +268,7 @@ def poisson(size: int | tuple[int, ...] = 1, lambda_: real = 1.0) -> real | np.n
         raise ValueError(f"Invalid lambda {lambda_}, expected positive real number")
 
     return _generate_random_values(
-        size, _core.poisson_rand, _core.poisson_rands, (_lambda,)
+        size, _core.poisson_rand, _core.poisson_rands, (_lambda,), _ensure_seed(seed)
     )
 
 
@@ -171,15 +278,29 @@ def stable_rand(
     sigma: real = 1.0,
     mu: real = 0.0,
     size: int | tuple[int, ...] = 1,
+    parameterization: str = "S1",
+    seed: int | None = None,
 ) -> real | np.ndarray:
     """Stable distribution random numbers
 
+    The parameterization follows Nolan. In "S1" (Samorodnitsky-Taqqu), the characteristic
+    function is exp(-sigma^a |t|^a (1 - i beta sign(t) tan(pi a / 2)) + i mu t) for a != 1 and
+    exp(-sigma |t| (1 + i beta (2 / pi) sign(t) ln|t|) + i mu t) for a = 1. "S0" shifts the
+    location to be continuous in alpha: mu_S1 = mu_S0 - beta sigma tan(pi alpha / 2) for
+    alpha != 1 and mu_S1 = mu_S0 - (2 / pi) beta sigma ln(sigma) for alpha = 1. The two agree
+    when beta = 0.
+
+    For alpha = 2 the distribution is normal with mean mu and standard deviation
+    sqrt(2) * sigma, whatever beta; it is then drawn from the normal sampler directly.
+
     Args:
         alpha (real): stability index. Positive real number, between 0 and 2.
         beta (real): skewness parameter. Real number, between -1 and 1.
         sigma (real, optional): scale parameter. Defaults to 1.0. Positive real number.
         mu (real, optional): location parameter. Defaults to 0.0.
         size (int | tuple[int, ...], optional): shape of the output array. Defaults to 1. Positive integer or tuple of integers.
+        parameterization (str, optional): "S0" or "S1", the convention of mu. Defaults to "S1".
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
     Returns:
         real | np.ndarray: stable random numbers
     """
@@ -195,39 +316,206 @@ def stable_rand(
         raise ValueError(f"Invalid beta {beta}, expected real number between -1 and 1")
     if _sigma <= 0:
         raise ValueError(f"Invalid sigma {sigma}, expected positive real number")
+    if parameterization not in ("S0", "S1"):
+        raise ValueError(
+            f"Invalid parameterization {parameterization!r}, expected 'S0' or 'S1'"
+        )
 
     return _generate_random_values(
-        size, _core.stable_rand, _core.stable_rands, (_alpha, _beta, _sigma, _mu)
+        size,
+        _core.stable_rand,
+        _core.stable_rands,
+        (_alpha, _beta, _sigma, _mu, parameterization),
+        _ensure_seed(seed),
     )
 
 
-def skew_stable_rand(alpha: real, size: int | tuple[int, ...] = 1) -> real | np.ndarray:
+def skew_stable_rand(
+    alpha: real,
+    size: int | tuple[int, ...] = 1,
+    scale: real = 1.0,
+    shift: real = 0.0,
+    seed: int | None = None,
+) -> real | np.ndarray:
     """Skewed stable distribution random numbers
 
+    The samples follow S_alpha(scale, 1, shift) in the "S1" parameterization, as in
+    `stable_rand`; for alpha < 1 they are one-sided, i.e. at least `shift`.
+
     Args:
-        alpha (real): stability index. Positive real number, between 0 and 1 for S_alpha(1,1,0).
+        alpha (real): stability index. Real number in (0, 2].
         size (int | tuple[int, ...], optional): shape of the output array. Defaults to 1. Positive integer or tuple of integers.
+        scale (real, optional): scale parameter. Defaults to 1.0. Positive real number.
+        shift (real, optional): location parameter. Defaults to 0.0.
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
 
     Returns:
         real | np.ndarray: skewed stable random numbers
     """
     _alpha = _ensure_real(alpha, "alpha")
     if not (0 < _alpha <= 2):
-        raise ValueError(
-            f"Invalid alpha {alpha}, expected positive real number, typically (0,2]"
-        )
+        raise ValueError(f"Invalid alpha {alpha}, expected real number in (0, 2]")
+    _scale = _ensure_real(scale, "scale")
+    if _scale <= 0:
+        raise ValueError(f"Invalid scale {scale}, expected positive real number")
+    _shift = _ensure_real(shift, "shift")
+
+    return _generate_random_values(
+        size,
+        _core.skew_stable_rand,
+        _core.skew_stable_rands,
+        (_alpha, _scale, _shift),
+        _ensure_seed(seed),
+    )
+
+
+def mittag_leffler_rand(
+    alpha: real,
+    size: int | tuple[int, ...] = 1,
+    scale: real = 1.0,
+    seed: int | None = None,
+) -> Union[float, np.ndarray]:
+    """Mittag-Leffler distribution random numbers
+
+    These are the waiting times of a CTRW with power-law tail; alpha = 1 reduces to
+    the exponential distribution.
+
+    Args:
+        alpha (real): index of the distribution. Real number in (0, 1].
+        size (int | tuple[int, ...], optional): shape of the output array. Defaults to 1. Positive integer or tuple of integers.
+        scale (real, optional): scale parameter. Defaults to 1.0. Positive real number.
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
+
+    Returns:
+        float | np.ndarray: Mittag-Leffler random numbers
+    """
+    _alpha = _ensure_real(alpha, "alpha")
+    if not (0 < _alpha <= 1):
+        raise ValueError(f"Invalid alpha {alpha}, expected real number in (0, 1]")
+    _scale = _ensure_real(scale, "scale")
+    if _scale <= 0:
+        raise ValueError(f"Invalid scale {scale}, expected positive real number")
+
+    return _generate_random_values(
+        size,
+        _core.mittag_leffler_rand,
+        _core.mittag_leffler_rands,
+        (_alpha, _scale),
+        _ensure_seed(seed),
+    )
+
+
+def inverse_gaussian_rand(
+    mu: real,
+    lambda_: real,
+    size: int | tuple[int, ...] = 1,
+    seed: int | None = None,
+) -> Union[float, np.ndarray]:
+    """Inverse Gaussian distribution random numbers
+
+    These are the first passage times of a Brownian motion with drift v > 0 and diffusion
+    coefficient D to a level at distance L, with mu = L / v and lambda_ = L^2 / (2D).
+
+    Args:
+        mu (real): mean of the distribution. Positive real number.
+        lambda_ (real): shape parameter, the variance is mu^3 / lambda_. Positive real number.
+        size (int | tuple[int, ...], optional): shape of the output array. Defaults to 1. Positive integer or tuple of integers.
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
+
+    Returns:
+        float | np.ndarray: inverse Gaussian random numbers
+    """
+    _mu = _ensure_real(mu, "mu")
+    if _mu <= 0:
+        raise ValueError(f"Invalid mu {mu}, expected positive real number")
+    _lambda = _ensure_real(lambda_, "lambda_")
+    if _lambda <= 0:
+        raise ValueError(f"Invalid lambda_ {lambda_}, expected positive real number")
+
+    return _generate_random_values(
+        size,
+        _core.inverse_gaussian_rand,
+        _core.inverse_gaussian_rands,
+        (_mu, _lambda),
+        _ensure_seed(seed),
+    )
+
+
+def weibull_rand(
+    shape: real,
+    scale: real = 1.0,
+    size: int | tuple[int, ...] = 1,
+    seed: int | None = None,
+) -> Union[float, np.ndarray]:
+    """Weibull distribution random numbers
+
+    The survival function is exp(-(x / scale)^shape); shape = 1 is the exponential distribution.
+
+    Args:
+        shape (real): shape parameter. Positive real number.
+        scale (real, optional): scale parameter. Defaults to 1.0. Positive real number.
+        size (int | tuple[int, ...], optional): shape of the output array. Defaults to 1. Positive integer or tuple of integers.
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
+
+    Returns:
+        float | np.ndarray: Weibull random numbers
+    """
+    _shape = _ensure_real(shape, "shape")
+    if _shape <= 0:
+        raise ValueError(f"Invalid shape {shape}, expected positive real number")
+    _scale = _ensure_real(scale, "scale")
+    if _scale <= 0:
+        raise ValueError(f"Invalid scale {scale}, expected positive real number")
 
     return _generate_random_values(
-        size, _core.skew_stable_rand, _core.skew_stable_rands, (_alpha,)
+        size,
+        _core.weibull_rand,
+        _core.weibull_rands,
+        (_shape, _scale),
+        _ensure_seed(seed),
     )
 
 
-def bool_rand(size: tuple[int, ...] | int = 1, p: real = 0.5) -> bool | np.ndarray:
+def half_cauchy_rand(
+    scale: real = 1.0,
+    size: int | tuple[int, ...] = 1,
+    seed: int | None = None,
+) -> Union[float, np.ndarray]:
+    """Half-Cauchy distribution random numbers
+
+    The absolute value of a Cauchy variable, drawn as scale * tan(pi U / 2) with U uniform
+    on [0, 1); the median is scale and the mean is infinite.
+
+    Args:
+        scale (real, optional): scale parameter. Defaults to 1.0. Positive real number.
+        size (int | tuple[int, ...], optional): shape of the output array. Defaults to 1. Positive integer or tuple of integers.
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
+
+    Returns:
+        float | np.ndarray: half-Cauchy random numbers
+    """
+    _scale = _ensure_real(scale, "scale")
+    if _scale <= 0:
+        raise ValueError(f"Invalid scale {scale}, expected positive real number")
+
+    return _generate_random_values(
+        size,
+        _core.half_cauchy_rand,
+        _core.half_cauchy_rands,
+        (_scale,),
+        _ensure_seed(seed),
+    )
+
+
+def bool_rand(
+    size: tuple[int, ...] | int = 1, p: real = 0.5, seed: int | None = None
+) -> bool | np.ndarray:
     """Boolean random numbers (Bernoulli distribution)
 
     Args:
         size (tuple[int, ...] | int, optional): shape of the output array. Defaults to 1. Positive integer or tuple of integers.
         p (real, optional): probability of True. Defaults to 0.5. Must be between 0 and 1.
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
 
     Returns:
         bool | np.ndarray: boolean random numbers
@@ -236,4 +524,121 @@ def bool_rand(size: tuple[int, ...] | int = 1, p: real = 0.5) -> bool | np.ndarr
     if not (0 <= _p <= 1):
         raise ValueError(f"Invalid p {p}, probability must be between 0 and 1")
 
-    return _generate_random_values(size, _core.bool_rand, _core.bool_rands, (_p,))
+    return _generate_random_values(
+        size, _core.bool_rand, _core.bool_rands, (_p,), _ensure_seed(seed)
+    )
+
+
+def bool_rand_packed(
+    n: int, p: real = 0.5, seed: int | None = None
+) -> tuple[np.ndarray, int]:
+    """Boolean random numbers (Bernoulli distribution) packed eight to a byte
+
+    Args:
+        n (int): number of draws, positive.
+        p (real, optional): probability of True. Defaults to 0.5. Must be between 0 and 1.
+        seed (int | None, optional): seed for reproducible output, independent of the number of threads. Defaults to None.
+
+    Returns:
+        tuple[np.ndarray, int]: ceil(n / 8) uint8 bytes with the i-th draw in bit i % 8 (least
+            significant first) of byte i // 8, and n; unpack with
+            `np.unpackbits(bits, count=n, bitorder="little").astype(bool)`
+    """
+    _n = _ensure_integer(n, "n")
+    if _n <= 0:
+        raise ValueError(f"Invalid n {n}, expected positive integer")
+    _p = _ensure_real(p, "p")
+    if not (0 <= _p <= 1):
+        raise ValueError(f"Invalid p {p}, probability must be between 0 and 1")
+
+    return _core.bool_rands_packed(_n, _p, _ensure_seed(seed))
+
+
+def get_rng_state() -> bytes:
+    """Snapshot of the global random stream
+
+    The stream drives the random number functions of this module when no seed is given,
+    and the few simulators documented as drawing from it. Simulations that run in the
+    upstream diffusionx engine and skewed stable random numbers are not covered.
+
+    Returns:
+        bytes: opaque state, including the backend, that can be passed to `set_rng_state`
+    """
+    return _core.rng_get_state()
+
+
+def set_rng_state(state: bytes) -> None:
+    """Restore the global random stream
+
+    Args:
+        state (bytes): state previously returned by `get_rng_state`.
+    """
+    if not isinstance(state, bytes):
+        raise TypeError(f"state must be bytes, got {type(state).__name__}")
+    _core.rng_set_state(state)
+
+
+def set_rng_backend(name: str) -> None:
+    """Select the generator behind the global random stream and behind seeded draws
+
+    The stream position is kept, so call `set_seed` afterwards for a reproducible sequence.
+    The same seed gives different numbers under different backends.
+
+    Args:
+        name (str): "xoshiro256plusplus" (the default), "pcg64" or "chacha12".
+    """
+    if not isinstance(name, str):
+        raise TypeError(f"name must be a string, got {type(name).__name__}")
+    _core.set_rng_backend(name)
+
+
+def get_rng_backend() -> str:
+    """Name of the generator behind the global random stream
+
+    Returns:
+        str: "xoshiro256plusplus", "pcg64" or "chacha12"
+    """
+    return _core.get_rng_backend()
+
+
+def set_seed(seed: int) -> None:
+    """Reset the global random stream to the one determined by a seed
+
+    Draws without an explicit seed are reproducible after the same `set_seed` call.
+    Only samplers backed by the global stream are covered (see `get_rng_state`);
+    skewed stable random numbers and most simulations are not governed by this seed.
+
+    Args:
+        seed (int): integer in [0, 2**64).
+    """
+    if seed is None:
+        raise TypeError("seed must be an integer, got NoneType")
+    _core.set_seed(_ensure_seed(seed))
+
+
+def seed_from_entropy() -> int:
+    """Seed the global random stream from system entropy and return the seed
+
+    Log the returned seed to reproduce the subsequent draws later with `set_seed`.
+
+    Returns:
+        int: the seed now in use
+    """
+    return _core.seed_from_entropy()
+
+
+@contextmanager
+def preserve_rng_state() -> Iterator[bytes]:
+    """Context manager that restores the global random stream on exit
+
+    Draws made inside the block are repeated by the same draws after it.
+    As with `set_seed`, this only covers samplers backed by the global stream.
+
+    Yields:
+        bytes: the state captured on entry
+    """
+    state = get_rng_state()
+    try:
+        yield state
+    finally:
+        set_rng_state(state)
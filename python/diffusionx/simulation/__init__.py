@@ -1,7 +1,35 @@
+from diffusionx._core import (
+    Process,
+    UnitArray,
+    add_processes,
+    benchmark_simulation,
+    get_max_points,
+    make_process,
+    num_grid_points,
+    process_central_moment,
+    process_fpt,
+    process_fpt_central_moment,
+    process_fpt_raw_moment,
+    process_occupation_time,
+    process_occupation_time_central_moment,
+    process_occupation_time_raw_moment,
+    process_raw_moment,
+    set_max_points,
+    simulate_reduce,
+)
+
 from .bm import Bm
-from .levy import Levy, Subordinator, InvSubordinator, AsymmetricLevy
+from .levy import (
+    Levy,
+    Subordinator,
+    InvSubordinator,
+    FractionalDiffusion,
+    IntegratedCIRSubordinator,
+    AsymmetricLevy,
+    NIG,
+)
 from .fbm import FBm
-from .ctrw import CTRW
+from .ctrw import CTRW, WeibullCTRW
 from .poisson import Poisson
 from .langevin import Langevin, GeneralizedLangevin, SubordinatedLangevin
 from .bb import BrownianBridge
@@ -17,10 +45,14 @@ __all__ = [
     "Bm",
     "Levy",
     "AsymmetricLevy",
+    "NIG",
     "Subordinator",
     "InvSubordinator",
+    "FractionalDiffusion",
+    "IntegratedCIRSubordinator",
     "FBm",
     "CTRW",
+    "WeibullCTRW",
     "Poisson",
     "Langevin",
     "GeneralizedLangevin",
@@ -34,4 +66,21 @@ __all__ = [
     "GeometricBm",
     "LevyWalk",
     "OU",
+    "Process",
+    "UnitArray",
+    "add_processes",
+    "benchmark_simulation",
+    "make_process",
+    "get_max_points",
+    "num_grid_points",
+    "process_central_moment",
+    "process_fpt",
+    "process_fpt_central_moment",
+    "process_fpt_raw_moment",
+    "process_occupation_time",
+    "process_occupation_time_central_moment",
+    "process_occupation_time_raw_moment",
+    "process_raw_moment",
+    "set_max_points",
+    "simulate_reduce",
 ]
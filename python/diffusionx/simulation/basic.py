@@ -1,5 +1,5 @@
 from abc import ABC, abstractmethod
-from typing import Annotated, Literal, Union
+from typing import Annotated, Callable, Literal, Union
 
 import numpy as np
 import numpy.typing as npt
@@ -10,6 +10,7 @@ from .utils import (
     validate_particles,
     validate_positive_float,
     validate_positive_integer,
+    validate_quad_rule,
 )
 
 real = Union[int, float]
@@ -44,13 +45,55 @@ class ContinuousProcess(ABC):
         time_step: float = 0.01,
         central: bool = True,
         particles: int = 10_000,
+        compensated: bool = True,
+        skip_nonfinite: bool = False,
     ) -> float:
         validate_bool(central, "central")
+        validate_bool(compensated, "compensated")
+        validate_bool(skip_nonfinite, "skip_nonfinite")
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
         particles = validate_particles(particles)
         return _core.moment(
-            self.simulate, central, order, duration, time_step, particles
+            self.simulate,
+            central,
+            order,
+            duration,
+            time_step,
+            particles,
+            compensated,
+            skip_nonfinite,
+        )
+
+    def moment_diagnostic(
+        self,
+        duration: real,
+        order: int,
+        time_step: float = 0.01,
+        central: bool = True,
+        particles: int = 10_000,
+        compensated: bool = True,
+        skip_nonfinite: bool = False,
+    ) -> tuple[float, int]:
+        """Moment together with the number of particles whose contribution was inf or NaN.
+
+        With `skip_nonfinite`, those particles are excluded from the average.
+        """
+        validate_bool(central, "central")
+        validate_bool(compensated, "compensated")
+        validate_bool(skip_nonfinite, "skip_nonfinite")
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        particles = validate_particles(particles)
+        return _core.moment_diagnostic(
+            self.simulate,
+            central,
+            order,
+            duration,
+            time_step,
+            particles,
+            compensated,
+            skip_nonfinite,
         )
 
     def mean(
@@ -69,6 +112,106 @@ class ContinuousProcess(ABC):
         particles = validate_particles(particles)
         return _core.msd(self.simulate, duration, time_step, particles)
 
+    def ensemble_functional(
+        self,
+        path_functional: Callable[[Vector, Vector], float],
+        duration: real,
+        time_step: float = 0.01,
+        particles: int = 10_000,
+    ) -> float:
+        """
+        Ensemble average of `path_functional(times, positions)` over simulated paths.
+
+        `path_functional` is called from the core once per path while holding the GIL,
+        so a slow functional dominates the run time, and an exception it raises is
+        propagated unchanged. `self.simulate` is also called under the GIL for each
+        path, so the paths are not simulated truly in parallel. For example,
+        `lambda t, x: x[-1]` reproduces `mean`.
+
+        Args:
+            path_functional (Callable): Maps the time and position arrays of one path to a float.
+            duration (real): The total duration of the simulation.
+            time_step (float, optional): The time step for the simulation. Defaults to 0.01.
+            particles (int, optional): Number of paths. Defaults to 10_000.
+
+        Returns:
+            float: The ensemble average of the functional.
+        """
+        if not callable(path_functional):
+            raise TypeError("path_functional must be callable")
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        particles = validate_particles(particles)
+        # the core calls `.simulate(duration, time_step)` on the object it receives
+        return _core.ensemble_functional(
+            self, path_functional, duration, time_step, particles
+        )
+
+    def dt_convergence(
+        self,
+        duration: real,
+        dt_list: list[float],
+        statistic: Literal["mean", "msd", "raw_moment_2"] = "mean",
+        particles: int = 10_000,
+    ) -> npt.NDArray[np.float64]:
+        """
+        Compute a statistic at `duration` for each time step in `dt_list`.
+
+        Every time step starts from the same state of the global random stream, so
+        processes simulated from that stream, such as the Levy flight, reuse the same
+        seeds. The other processes use independent noise for each time step.
+
+        Args:
+            duration (real): The total duration of the simulation.
+            dt_list (list[float]): The time steps to compare, typically decreasing.
+            statistic (str, optional): "mean", "msd" or "raw_moment_2". Defaults to "mean".
+            particles (int, optional): Number of particles for each time step. Defaults to 10_000.
+
+        Returns:
+            np.ndarray: The statistic for each time step, in the order of `dt_list`.
+        """
+        duration = validate_positive_float(duration, "duration")
+        particles = validate_particles(particles)
+        if statistic not in ("mean", "msd", "raw_moment_2"):
+            raise ValueError(
+                f"statistic must be 'mean', 'msd' or 'raw_moment_2', got {statistic!r}"
+            )
+        dt_list = [validate_positive_float(dt, "time_step") for dt in dt_list]
+        if not dt_list:
+            raise ValueError("dt_list must not be empty")
+        # the core calls `.simulate(duration, dt)` on the object it receives
+        return _core.dt_convergence(self, duration, statistic, particles, dt_list)
+
+    def local_scaling_exponent(
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        particles: int = 10_000,
+        window: int = 10,
+    ) -> tuple[npt.NDArray[np.float64], npt.NDArray[np.float64]]:
+        """
+        Time-resolved anomalous diffusion exponent d log MSD / d log t.
+
+        Args:
+            duration (real): The total duration of the simulation.
+            time_step (float, optional): The time step for the simulation. Defaults to 0.01.
+            particles (int, optional): Number of particles for the ensemble-averaged MSD. Defaults to 10_000.
+            window (int, optional): Number of consecutive grid points in each log-log fit, at least 2. Defaults to 10.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray]: The geometric mean time of each window and the local exponent.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        particles = validate_particles(particles)
+        window = validate_positive_integer(window, "window")
+        if window < 2:
+            raise ValueError(f"window must be at least 2, got {window}")
+        # the core calls `.simulate(duration, time_step)` on the object it receives
+        return _core.local_scaling_exponent(
+            self, duration, time_step, particles, window
+        )
+
     def eatamsd(
         self,
         duration: real,
@@ -76,24 +219,71 @@ class ContinuousProcess(ABC):
         time_step: float = 0.01,
         quad_order: int = 5,
         particles: int = 10_000,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
         particles = validate_particles(particles)
+        rule = validate_quad_rule(rule)
         return _core.eatamsd(
-            self.simulate, duration, delta, particles, time_step, quad_order
+            self.simulate, duration, delta, particles, time_step, quad_order, rule
         )
 
     def tamsd(
-        self, duration: real, delta: float, time_step: float = 0.01, quad_order: int = 5
+        self,
+        duration: real,
+        delta: float,
+        time_step: float = 0.01,
+        quad_order: int = 5,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
-        return _core.tamsd(self.simulate, duration, delta, time_step, quad_order)
+        rule = validate_quad_rule(rule)
+        return _core.tamsd(
+            self.simulate, duration, delta, time_step, quad_order, rule
+        )
+
+    def tamsd_convergence(
+        self,
+        duration: real,
+        delta: float,
+        refinement_factors: list[int],
+        time_step: float = 0.01,
+        quad_order: int = 5,
+    ) -> npt.NDArray[np.float64]:
+        """
+        TAMSD at `time_step` and at `time_step / f` for each `f` in `refinement_factors`.
+
+        All levels use one path simulated at the finest step, which the coarser levels
+        subsample.
+
+        Args:
+            duration (real): The total duration of the simulation.
+            delta (float): The lag time of the TAMSD.
+            refinement_factors (list[int]): Positive factors dividing `time_step`, e.g. [2, 4, 8].
+            time_step (float, optional): The coarsest time step. Defaults to 0.01.
+            quad_order (int, optional): Order of the Gauss–Legendre quadrature. Defaults to 5.
+
+        Returns:
+            np.ndarray: The TAMSD for `time_step` followed by each refinement; a plateau
+                indicates that `time_step` is fine enough.
+        """
+        duration = validate_positive_float(duration, "duration")
+        delta = validate_positive_float(delta, "delta")
+        time_step = validate_positive_float(time_step, "time_step")
+        quad_order = validate_positive_integer(quad_order, "quad_order")
+        refinement_factors = [
+            validate_positive_integer(f, "refinement_factors") for f in refinement_factors
+        ]
+        # the core calls `.simulate(duration, time_step)` on the object it receives
+        return _core.tamsd_convergence(
+            self, duration, delta, time_step, refinement_factors, quad_order
+        )
 
     # def fpt(
     #     self,
@@ -2,12 +2,14 @@ from diffusionx import _core
 
 from .basic import Vector, real
 from .utils import (
+    ensure_float,
     validate_bool,
     validate_domain,
     validate_order,
     validate_particles,
     validate_positive_float,
     validate_positive_integer,
+    validate_quad_rule,
 )
 
 
@@ -18,7 +20,11 @@ class BrownianBridge:
         """
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         """
         Simulate the Brownian bridge.
@@ -26,14 +32,45 @@ class BrownianBridge:
         Args:
             duration (real): Total duration of the simulation.
             time_step (float, optional): Step size for the simulation. Defaults to 0.01.
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray]: A tuple containing the times and positions of the Brownian bridge.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
+
+        return _core.bb_simulate(duration, time_step, t0, thin)
+
+    def simulate_endpoints(
+        self,
+        start_value: real,
+        end_value: real,
+        duration: real,
+        time_step: float = 0.01,
+    ) -> tuple[Vector, Vector]:
+        """
+        Simulate a Brownian bridge pinned at given endpoints.
+
+        Args:
+            start_value (real): Value of the bridge at time 0.
+            end_value (real): Value of the bridge at time `duration`.
+            duration (real): Total duration of the simulation.
+            time_step (float, optional): Step size for the simulation. Defaults to 0.01.
 
         Returns:
             tuple[np.ndarray, np.ndarray]: A tuple containing the times and positions of the Brownian bridge.
         """
+        start_value = ensure_float(start_value)
+        end_value = ensure_float(end_value)
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
 
-        return _core.bb_simulate(duration, time_step)
+        return _core.bb_simulate_endpoints(start_value, end_value, duration, time_step)
 
     def moment(
         self,
@@ -234,6 +271,7 @@ class BrownianBridge:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         """
         Calculate the time-averaged mean squared displacement (TAMSD) of the Brownian bridge.
@@ -243,6 +281,7 @@ class BrownianBridge:
             delta (real): The time interval for the TAMSD calculation.
             time_step (real, optional): Step size for the simulation. Defaults to 0.01.
             quad_order (int, optional): Order of the quadrature rule. Defaults to 10.
+            rule (str, optional): Quadrature rule for the start-time integral, one of "gauss_legendre", "trapezoid" or "simpson". Defaults to "gauss_legendre".
 
         Returns:
             float: The time-averaged mean squared displacement.
@@ -251,12 +290,14 @@ class BrownianBridge:
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.bb_tamsd(
             duration,
             delta,
             time_step,
             quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -266,6 +307,7 @@ class BrownianBridge:
         particles: int = 10_000,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         """
         Calculate the ensemble-averaged time-averaged mean squared displacement (EATAMSD) of the Brownian bridge.
@@ -276,6 +318,7 @@ class BrownianBridge:
             particles (int, optional): Number of particles (positive integer) for ensemble averaging. Defaults to 10_000.
             time_step (real, optional): Step size for the simulation. Defaults to 0.01.
             quad_order (int, optional): Order of the quadrature rule. Defaults to 10.
+            rule (str, optional): Quadrature rule for the start-time integral, one of "gauss_legendre", "trapezoid" or "simpson". Defaults to "gauss_legendre".
 
         Returns:
             float: The ensemble-averaged time-averaged mean squared displacement.
@@ -285,6 +328,7 @@ class BrownianBridge:
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.bb_eatamsd(
             duration,
@@ -292,6 +336,7 @@ class BrownianBridge:
             particles,
             time_step,
             quad_order,
+            rule,
         )
 
     def mean(
@@ -2,12 +2,14 @@ from diffusionx import _core
 
 from .basic import Vector, real
 from .utils import (
+    ensure_float,
     validate_bool,
     validate_domain,
     validate_order,
     validate_particles,
     validate_positive_float,
     validate_positive_integer,
+    validate_quad_rule,
 )
 
 
@@ -19,7 +21,11 @@ class BrownianExcursion:
         """
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         """
         Simulate the Brownian excursion.
@@ -27,16 +33,23 @@ class BrownianExcursion:
         Args:
             duration (real): Total duration of the simulation.
             time_step (float, optional): Step size for the simulation. Defaults to 0.01.
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
 
         Returns:
             tuple[np.ndarray, np.ndarray]: A tuple containing the times and positions of the Brownian excursion.
         """
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.be_simulate(
             duration,
             time_step,
+            t0,
+            thin,
         )
 
     def moment(
@@ -251,6 +264,7 @@ class BrownianExcursion:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         """
         Calculate the time-averaged mean squared displacement (TAMSD) of the Brownian excursion.
@@ -260,6 +274,7 @@ class BrownianExcursion:
             delta (real): The time interval for the TAMSD calculation.
             time_step (real, optional): Step size for the simulation. Defaults to 0.01.
             quad_order (int, optional): Order of the quadrature rule. Defaults to 10.
+            rule (str, optional): Quadrature rule for the start-time integral, one of "gauss_legendre", "trapezoid" or "simpson". Defaults to "gauss_legendre".
 
         Returns:
             float: The time-averaged mean squared displacement.
@@ -268,12 +283,14 @@ class BrownianExcursion:
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.be_tamsd(
             duration,
             delta,
             time_step,
             quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -283,6 +300,7 @@ class BrownianExcursion:
         particles: int = 10_000,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         """
         Calculate the ensemble-averaged time-averaged mean squared displacement (EATAMSD) of the Brownian excursion.
@@ -293,6 +311,7 @@ class BrownianExcursion:
             particles (int, optional): Number of particles (positive integer) for ensemble averaging. Defaults to 10_000.
             time_step (real, optional): Step size for the simulation. Defaults to 0.01.
             quad_order (int, optional): Order of the quadrature rule. Defaults to 10.
+            rule (str, optional): Quadrature rule for the start-time integral, one of "gauss_legendre", "trapezoid" or "simpson". Defaults to "gauss_legendre".
 
         Returns:
             float: The ensemble-averaged time-averaged mean squared displacement.
@@ -302,6 +321,7 @@ class BrownianExcursion:
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.be_eatamsd(
             duration,
@@ -309,6 +329,7 @@ class BrownianExcursion:
             particles,
             time_step,
             quad_order,
+            rule,
         )
 
     def mean(
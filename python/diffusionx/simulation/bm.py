@@ -1,3 +1,8 @@
+import os
+from typing import Callable
+
+import numpy as np
+
 from diffusionx import _core
 
 from .basic import Vector, real
@@ -5,10 +10,14 @@ from .utils import (
     ensure_float,
     validate_bool,
     validate_domain,
+    validate_edges,
+    validate_endpoint,
     validate_order,
     validate_particles,
     validate_positive_float,
     validate_positive_integer,
+    validate_quad_rule,
+    validate_seed,
 )
 
 
@@ -31,26 +40,644 @@ class Bm:
         self.start_position: float = ensure_float(start_position)
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
-    ) -> tuple[Vector, Vector]:
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        return_units: bool = False,
+        endpoint: str = "extend",
+        t0: real = 0.0,
+        validate_output: bool = False,
+        thin: int = 1,
+        diffusion_unit: str = "m^2/s",
+    ) -> tuple[Vector, Vector] | tuple[_core.UnitArray, _core.UnitArray]:
         """
         Simulate the Brownian motion.
 
         Args:
             duration (real): Total duration of the simulation.
             time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+            return_units (bool, optional): Whether to wrap the outputs in UnitArray objects, with the units
+                derived from diffusion_unit. Defaults to False.
+            endpoint (str, optional): If time_step does not divide duration, "extend" ends with a shorter step
+                landing exactly at duration and "clip" stops at the last full step. Defaults to "extend".
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            validate_output (bool, optional): Check that the returned arrays are C-contiguous float64, which
+                they always should be; a debugging aid. Defaults to False.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
+            diffusion_unit (str, optional): Unit "L^2/T" of the diffusion coefficient; with return_units the
+                times are in T and the positions in L. Defaults to "m^2/s".
 
         Returns:
             tuple[np.ndarray, np.ndarray]: A tuple containing the times and positions of the Brownian motion.
         """
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        validate_bool(return_units, "return_units")
+        endpoint = validate_endpoint(endpoint)
+        t0 = ensure_float(t0)
+        validate_bool(validate_output, "validate_output")
+        thin = validate_positive_integer(thin, "thin")
+        if not isinstance(diffusion_unit, str):
+            raise TypeError(
+                f"diffusion_unit must be a string, got {type(diffusion_unit).__name__}"
+            )
 
         return _core.bm_simulate(
             self.start_position,
             self.diffusion_coefficient,
             duration,
             time_step,
+            return_units,
+            endpoint,
+            t0,
+            validate_output,
+            thin,
+            diffusion_unit,
+        )
+
+    def terminal(self, duration: real, time_step: float = 0.01) -> float:
+        """
+        Sample the terminal value X(duration) of the Brownian motion without simulating the path.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            time_step (float, optional): Step size of the Brownian motion. Only validated, the sample is exact. Defaults to 0.01.
+
+        Returns:
+            float: The terminal value.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.bm_terminal(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+        )
+
+    def simulate_batch(
+        self, duration: real, particles: int = 10_000, time_step: float = 0.01
+    ) -> np.ndarray:
+        """
+        Simulate an ensemble of Brownian motion paths as one contiguous array.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            particles (int, optional): Number of paths. Defaults to 10_000.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+
+        Returns:
+            np.ndarray: The positions as a (particles, points) array, one row per path, on the
+                grid of `simulate`. It is a view of the flat array built by the core, not a copy.
+        """
+        duration = validate_positive_float(duration, "duration")
+        particles = validate_particles(particles)
+        time_step = validate_positive_float(time_step, "time_step")
+
+        flat, points = _core.bm_simulate_batch_flat(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            particles,
+        )
+        return flat.reshape(particles, points)
+
+    def simulate_with_increments(
+        self, duration: real, time_step: float = 0.01, seed: int | None = None
+    ) -> tuple[Vector, Vector, Vector]:
+        """
+        Simulate the Brownian motion together with the Gaussian increments that produced it.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+            seed (int | None, optional): Seed for reproducible output. Defaults to None.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray, np.ndarray]: The times, the positions and the increments,
+                with positions[i + 1] = positions[i] + increments[i].
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        seed = validate_seed(seed)
+
+        return _core.bm_simulate_with_increments(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            seed,
+        )
+
+    def simulate_continue(
+        self,
+        last_position: real,
+        additional_duration: real,
+        t0: real,
+        time_step: float = 0.01,
+        seed: int | None = None,
+    ) -> tuple[Vector, Vector]:
+        """
+        Continue a previously simulated path of the Brownian motion.
+
+        Args:
+            last_position (real): Position at the end of the previous segment.
+            additional_duration (real): Duration of the continuation.
+            t0 (real): Time at the end of the previous segment.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+            seed (int | None, optional): Seed for reproducible output. Defaults to None.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray]: The times and positions, starting with
+                (t0, last_position); drop that point when appending to the previous segment.
+        """
+        last_position = ensure_float(last_position)
+        additional_duration = validate_positive_float(
+            additional_duration, "additional_duration"
+        )
+        t0 = ensure_float(t0)
+        time_step = validate_positive_float(time_step, "time_step")
+        seed = validate_seed(seed)
+
+        return _core.bm_simulate_continue(
+            last_position,
+            self.diffusion_coefficient,
+            additional_duration,
+            time_step,
+            t0,
+            seed,
+        )
+
+    def ensemble_iter(
+        self, duration: real, particles: int = 10_000, time_step: float = 0.01
+    ) -> "_core.BmEnsembleIterator":
+        """
+        Iterate over independent Brownian motion paths, simulating one path per step.
+
+        The iterator supports len(), so `tqdm(bm.ensemble_iter(...))` shows the total.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            particles (int, optional): Number of paths. Defaults to 10_000.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+
+        Returns:
+            Iterator[tuple[np.ndarray, np.ndarray]]: The times and positions of each path.
+        """
+        duration = validate_positive_float(duration, "duration")
+        particles = validate_particles(particles)
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.bm_ensemble_iter(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            particles,
+        )
+
+    def terminals(
+        self,
+        duration: real,
+        particles: int = 10_000,
+        time_step: float = 0.01,
+        qmc: bool = False,
+    ) -> Vector:
+        """
+        Sample the terminal values X(duration) of independent Brownian motions without simulating paths.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            particles (int, optional): Number of terminal values. Defaults to 10_000.
+            time_step (float, optional): Step size of the Brownian motion. Only validated, the samples are exact. Defaults to 0.01.
+            qmc (bool, optional): Whether to draw the Gaussian variates from a scrambled Sobol sequence.
+                This reduces the error of averages of smooth functions of the values, but the values
+                are not independent. Defaults to False.
+
+        Returns:
+            np.ndarray: The terminal values.
+        """
+        duration = validate_positive_float(duration, "duration")
+        particles = validate_particles(particles)
+        time_step = validate_positive_float(time_step, "time_step")
+        validate_bool(qmc, "qmc")
+
+        return _core.bm_terminals(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            particles,
+            qmc,
+        )
+
+    def simulate_absorbing(
+        self,
+        domain: tuple[real, real],
+        duration: real,
+        time_step: float = 0.01,
+    ) -> tuple[Vector, Vector]:
+        """
+        Simulate the Brownian motion until it leaves a domain.
+
+        Args:
+            domain (tuple[real, real]): The domain (a, b) containing the start position. a must be less than b.
+            duration (real): Maximum duration of the simulation.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray]: The times and positions up to and including the first point
+                outside the domain, or the whole path if the domain is never left.
+        """
+        a, b = validate_domain(domain, process_name="Bm absorbing simulation")
+        if not (a < self.start_position < b):
+            raise ValueError(
+                f"start_position {self.start_position} must lie inside the domain ({a}, {b})"
+            )
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.bm_simulate_absorbing(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            (a, b),
+        )
+
+    def simulate_double_barrier(
+        self,
+        lower: real,
+        upper: real,
+        duration: real,
+        time_step: float = 0.01,
+    ) -> tuple[Vector, Vector, int]:
+        """
+        Simulate the Brownian motion until it hits one of two absorbing barriers.
+
+        Args:
+            lower (real): The lower barrier, below the start position.
+            upper (real): The upper barrier, above the start position.
+            duration (real): Maximum duration of the simulation.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray, int]: The times and positions truncated as in
+                `simulate_absorbing`, and -1 if lower absorbed the path, 1 if upper did, 0 if it survived.
+        """
+        a, b = validate_domain(
+            (lower, upper), process_name="Bm double barrier simulation"
+        )
+        if not (a < self.start_position < b):
+            raise ValueError(
+                f"start_position {self.start_position} must lie between the barriers ({a}, {b})"
+            )
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.bm_simulate_double_barrier(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            a,
+            b,
+        )
+
+    def simulate_until(
+        self,
+        predicate: Callable[[float, float], bool],
+        max_duration: real = 1000,
+        time_step: float = 0.01,
+    ) -> tuple[Vector, Vector, bool]:
+        """
+        Simulate the Brownian motion until a condition on the current time and position holds.
+
+        Args:
+            predicate (Callable[[float, float], bool]): Called as predicate(t, x) after every step;
+                the simulation stops once it returns a truthy value.
+            max_duration (real, optional): Maximum duration of the simulation. Defaults to 1000.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray, bool]: The times and positions up to and including the
+                stopping point, and whether the predicate stopped the simulation before max_duration.
+        """
+        if not callable(predicate):
+            raise TypeError(f"predicate must be callable, got {type(predicate).__name__}")
+        max_duration = validate_positive_float(max_duration, "max_duration")
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.bm_simulate_until(
+            self.start_position,
+            self.diffusion_coefficient,
+            time_step,
+            max_duration,
+            predicate,
+        )
+
+    def survival_curve(
+        self,
+        domain: tuple[real, real],
+        duration: real,
+        particles: int = 10_000,
+        time_step: float = 0.01,
+    ) -> tuple[Vector, Vector]:
+        """
+        Estimate the survival probability S(t) = P(T > t) of the first exit time T from a domain.
+
+        Args:
+            domain (tuple[real, real]): The domain (a, b) containing the start position. a must be less than b;
+                use an infinite bound for a single absorbing boundary.
+            duration (real): Total duration of the simulation.
+            particles (int, optional): Number of particles. Defaults to 10_000.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray]: The grid times and the fraction of paths that have not left
+                the domain up to each time.
+        """
+        a, b = validate_domain(domain, process_name="Bm survival curve")
+        if not (a < self.start_position < b):
+            raise ValueError(
+                f"start_position {self.start_position} must lie inside the domain ({a}, {b})"
+            )
+        duration = validate_positive_float(duration, "duration")
+        particles = validate_particles(particles)
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.bm_survival_curve(
+            self.start_position,
+            self.diffusion_coefficient,
+            (a, b),
+            duration,
+            time_step,
+            particles,
+        )
+
+    def first_crossing_path(
+        self,
+        domain: tuple[real, real],
+        duration: real,
+        particles: int = 10_000,
+        time_step: float = 0.01,
+    ) -> tuple[Vector, Vector, float] | None:
+        """
+        Simulate many paths and return the one that leaves a domain first.
+
+        Args:
+            domain (tuple[real, real]): The domain (a, b) containing the start position. a must be less than b;
+                use an infinite bound for a single absorbing boundary.
+            duration (real): Total duration of each path.
+            particles (int, optional): Number of paths. Defaults to 10_000.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray, float] | None: The times and positions of the path with the smallest
+                first exit time, and that time; None if no path left the domain within duration.
+        """
+        a, b = validate_domain(domain, process_name="Bm first crossing path")
+        if not (a < self.start_position < b):
+            raise ValueError(
+                f"start_position {self.start_position} must lie inside the domain ({a}, {b})"
+            )
+        duration = validate_positive_float(duration, "duration")
+        particles = validate_particles(particles)
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.bm_first_crossing_path(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            (a, b),
+            particles,
+        )
+
+    def running_max_simulate(
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
+    ) -> tuple[Vector, Vector, Vector]:
+        """
+        Simulate the Brownian motion together with its running maximum.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray, np.ndarray]: A tuple containing the times, positions and
+                running maximum M(t) = max_{s<=t} X(s) of the Brownian motion.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
+
+        return _core.bm_running_max_simulate(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            t0,
+            thin,
+        )
+
+    def crossing_events(
+        self,
+        duration: real,
+        level: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+    ) -> tuple[Vector, np.ndarray]:
+        """
+        Times and directions of the crossings of `level` along one simulated path.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            level (real): The level whose crossings are recorded.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+            t0 (real, optional): Start of the time axis; the crossing times are shifted by it.
+                Defaults to 0.0.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray]: Crossing times, linearly interpolated between grid
+                points, and int8 directions, +1 upwards and -1 downwards; the directions alternate.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        level = ensure_float(level)
+        t0 = ensure_float(t0)
+
+        return _core.bm_crossing_events(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            level,
+            t0,
+        )
+
+    def extreme_stats(
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        particles: int = 10_000,
+        return_arrays: bool = False,
+    ) -> dict:
+        """
+        Ensemble statistics of the global maximum of each path and the time it occurs.
+
+        By the arcsine law, `argmax_time / duration` has the U-shaped density
+        `1 / (pi sqrt(u (1 - u)))` on (0, 1), so its mean is `duration / 2`.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+            particles (int, optional): Number of paths. Defaults to 10_000.
+            return_arrays (bool, optional): Also return the per-path arrays. Defaults to False.
+
+        Returns:
+            dict: `mean_max` and `mean_argmax_time`; with `return_arrays`, also the arrays
+                `max` and `argmax_time`, where ties keep the earliest time.
+        """
+        validate_bool(return_arrays, "return_arrays")
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        particles = validate_particles(particles)
+
+        return _core.bm_extreme_stats(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            particles,
+            return_arrays,
+        )
+
+    def simulate_dict(
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
+    ) -> dict:
+        """
+        Simulate the Brownian motion as a dict of arrays, ready for `pandas.DataFrame`.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+            t0 (real, optional): Start of the time axis. Defaults to 0.0.
+            thin (int, optional): Keep only every thin-th point, always keeping the last one.
+                Defaults to 1.
+
+        Returns:
+            dict: {"time": np.ndarray, "position": np.ndarray}.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
+
+        return _core.bm_simulate_dict(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            t0,
+            thin,
+        )
+
+    def simulate_ensemble_dict(
+        self,
+        duration: real,
+        particles: int = 10_000,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
+    ) -> dict:
+        """
+        Simulate an ensemble of paths as a long-format dict of arrays.
+
+        Rows are ordered by particle and then time, so `pandas.DataFrame(result)` has
+        `particles * points` rows.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            particles (int, optional): Number of paths. Defaults to 10_000.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+            t0 (real, optional): Start of the time axis of every path. Defaults to 0.0.
+            thin (int, optional): Keep only every thin-th point of every path, always keeping
+                the last one. Defaults to 1.
+
+        Returns:
+            dict: {"time": np.ndarray, "particle": np.ndarray, "position": np.ndarray}.
+        """
+        duration = validate_positive_float(duration, "duration")
+        particles = validate_particles(particles)
+        time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
+
+        return _core.bm_simulate_ensemble_dict(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            particles,
+            t0,
+            thin,
+        )
+
+    def simulate_ensemble_to_parquet(
+        self,
+        path: str | os.PathLike,
+        duration: real,
+        particles: int = 10_000,
+        time_step: float = 0.01,
+    ) -> int:
+        """
+        Simulate an ensemble of Brownian motion paths and stream them to a Parquet file.
+
+        The file has columns `particle_id`, `time` and `position`. Paths are written in row
+        groups as they are simulated, so memory use stays bounded for large ensembles.
+        Requires the extension to be built with the `parquet` feature.
+
+        Args:
+            path (str | os.PathLike): Destination file; overwritten if it exists.
+            duration (real): Total duration of the simulation.
+            particles (int, optional): Number of paths (positive integer). Defaults to 10_000.
+            time_step (float, optional): Step size of the Brownian motion. Defaults to 0.01.
+
+        Returns:
+            int: The number of rows written.
+        """
+        if not _core.has_parquet():
+            raise RuntimeError(
+                "diffusionx was built without Parquet support; rebuild with the `parquet` feature"
+            )
+        path = os.fspath(path)
+        duration = validate_positive_float(duration, "duration")
+        particles = validate_particles(particles)
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.bm_simulate_ensemble_to_parquet(
+            path,
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            particles,
         )
 
     def moment(
@@ -122,6 +749,130 @@ class Bm:
             )
         )
 
+    def raw_moment_diagnostic(
+        self,
+        duration: real,
+        order: int,
+        particles: int = 10_000,
+        time_step: float = 0.01,
+        skip_nonfinite: bool = False,
+    ) -> tuple[float, int]:
+        """
+        Calculate the raw moment of the Brownian motion and count non-finite contributions.
+
+        Args:
+            duration (real): The total duration of the simulation.
+            order (int): The order of the moment.
+            particles (int, optional): Number of particles (positive integer) for ensemble averaging. Defaults to 10_000.
+            time_step (float, optional): Step size for the simulation. Defaults to 0.01.
+            skip_nonfinite (bool, optional): Whether to exclude inf/NaN contributions from the average. Defaults to False.
+
+        Returns:
+            tuple[float, int]: The raw moment and the number of particles whose contribution was inf or NaN.
+        """
+        validate_order(order)
+        if not isinstance(order, int):
+            raise TypeError(f"order must be an integer, got {type(order).__name__}")
+        validate_bool(skip_nonfinite, "skip_nonfinite")
+        particles = validate_particles(particles)
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.bm_raw_moment_diagnostic(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            order,
+            particles,
+            skip_nonfinite,
+        )
+
+    def moment_about(
+        self,
+        duration: real,
+        order: int,
+        center: real,
+        particles: int = 10_000,
+        time_step: float = 0.01,
+    ) -> float:
+        """
+        Calculate the moment E[(X(duration) - center)^order] of the Brownian motion about a fixed center.
+
+        Args:
+            duration (real): The total duration of the simulation.
+            order (int): The order of the moment.
+            center (real): The fixed center, e.g. 0 for the raw moment or start_position for the
+                displacement moment.
+            particles (int, optional): Number of particles (positive integer) for ensemble averaging. Defaults to 10_000.
+            time_step (float, optional): Step size for the simulation. Defaults to 0.01.
+
+        Returns:
+            float: The moment about center.
+        """
+        validate_order(order)
+        if not isinstance(order, int):
+            raise TypeError(f"order must be an integer, got {type(order).__name__}")
+        center = ensure_float(center)
+        particles = validate_particles(particles)
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.bm_moment_about(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            order,
+            particles,
+            center,
+        )
+
+    def raw_moment_ci(
+        self,
+        duration: real,
+        order: int,
+        particles: int = 10_000,
+        time_step: float = 0.01,
+        confidence: float = 0.95,
+    ) -> tuple[float, float, float]:
+        """
+        Calculate the raw moment of the Brownian motion with a confidence interval.
+
+        The interval is the normal-approximation interval from the standard error of the mean.
+
+        Args:
+            duration (real): The total duration of the simulation.
+            order (int): The order of the moment.
+            particles (int, optional): Number of particles for ensemble averaging. Must be at least 2. Defaults to 10_000.
+            time_step (float, optional): Step size for the simulation. Defaults to 0.01.
+            confidence (float, optional): Confidence level in (0, 1). Defaults to 0.95.
+
+        Returns:
+            tuple[float, float, float]: The estimate and the lower and upper bounds of the interval.
+        """
+        validate_order(order)
+        if not isinstance(order, int):
+            raise TypeError(f"order must be an integer, got {type(order).__name__}")
+        particles = validate_particles(particles)
+        if particles < 2:
+            raise ValueError(f"particles must be at least 2, got {particles}")
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        confidence = ensure_float(confidence)
+        if not (0 < confidence < 1):
+            raise ValueError(f"confidence must be in (0, 1), got {confidence}")
+
+        return _core.bm_raw_moment_ci(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            order,
+            particles,
+            confidence,
+        )
+
     def fpt(
         self,
         domain: tuple[real, real],
@@ -151,6 +902,45 @@ class Bm:
             max_duration,
         )
 
+    def fpt_moving(
+        self,
+        lower_func: Callable[[float], float],
+        upper_func: Callable[[float], float],
+        max_duration: real = 1000,
+        time_step: float = 0.01,
+    ) -> float | None:
+        """
+        Calculate the first passage time of the Brownian motion through moving boundaries.
+
+        Args:
+            lower_func (Callable[[float], float]): Lower boundary as a function of time.
+            upper_func (Callable[[float], float]): Upper boundary as a function of time.
+            max_duration (real, optional): Maximum duration to simulate for FPT. Defaults to 1000.
+            time_step (real, optional): Step size for the simulation. Defaults to 0.01.
+
+        Returns:
+            Optional[float]: The first passage time, or None if max_duration is reached before FPT.
+        """
+        if not callable(lower_func):
+            raise TypeError(
+                f"lower_func must be callable, got {type(lower_func).__name__}"
+            )
+        if not callable(upper_func):
+            raise TypeError(
+                f"upper_func must be callable, got {type(upper_func).__name__}"
+            )
+        time_step = validate_positive_float(time_step, "time_step")
+        max_duration = validate_positive_float(max_duration, "max_duration")
+
+        return _core.bm_fpt_moving(
+            self.start_position,
+            self.diffusion_coefficient,
+            time_step,
+            lower_func,
+            upper_func,
+            max_duration,
+        )
+
     def fpt_moment(
         self,
         domain: tuple[real, real],
@@ -205,6 +995,86 @@ class Bm:
 
         return result
 
+    def fpt_moment_corrected(
+        self,
+        domain: tuple[real, real],
+        order: int,
+        particles: int = 10_000,
+        max_duration: real = 1000,
+        time_step: float = 0.01,
+    ) -> float | None:
+        """
+        Calculate the raw moment of the first passage time with the O(sqrt(time_step)) bias removed.
+
+        The discrete first passage time is late by O(sqrt(dt)) because crossings between grid points
+        are missed. The moment is estimated at time_step and time_step / 4, which halves sqrt(dt), and
+        extrapolated as 2 M(time_step / 4) - M(time_step).
+
+        Args:
+            domain (tuple[real, real]): The domain (a, b). a must be less than b.
+            order (int): Order of the moment (non-negative integer).
+            particles (int, optional): Number of particles for each of the two runs. Defaults to 10_000.
+            max_duration (real, optional): Maximum duration. Defaults to 1000.
+            time_step (real, optional): The coarser of the two step sizes. Defaults to 0.01.
+
+        Returns:
+            Optional[float]: The corrected raw moment of FPT, or None if either run has no passage for some particles.
+        """
+        validate_order(order)
+        a, b = validate_domain(domain, process_name="Bm FPT raw moment")
+        particles = validate_particles(particles)
+        max_duration = validate_positive_float(max_duration, "max_duration")
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.bm_fpt_raw_moment_corrected(
+            self.start_position,
+            self.diffusion_coefficient,
+            (a, b),
+            order,
+            particles,
+            max_duration,
+            time_step,
+        )
+
+    def fpt_and_occupation(
+        self,
+        target_domain: tuple[real, real],
+        trap_domain: tuple[real, real],
+        particles: int = 10_000,
+        time_step: float = 0.01,
+        max_duration: real = 1000,
+    ) -> tuple[float, float] | None:
+        """
+        Mean first passage time out of `target_domain` and mean time spent in `trap_domain`
+        before it, computed from the same paths.
+
+        Args:
+            target_domain (tuple[real, real]): The domain (a, b) whose exit defines the first passage; must contain the start position.
+            trap_domain (tuple[real, real]): The closed domain whose occupation is accumulated until the passage.
+            particles (int, optional): Number of paths. Defaults to 10_000.
+            time_step (float, optional): Step size for the simulation. Defaults to 0.01.
+            max_duration (real, optional): Maximum simulated time per path. Defaults to 1000.
+
+        Returns:
+            tuple[float, float] | None: The mean first passage time and mean trap occupation,
+                or None if some path did not leave `target_domain` within `max_duration`.
+        """
+        target = validate_domain(target_domain, process_name="Bm FPT and occupation")
+        trap = validate_domain(trap_domain, process_name="Bm FPT and occupation")
+        particles = validate_particles(particles)
+        time_step = validate_positive_float(time_step, "time_step")
+        max_duration = validate_positive_float(max_duration, "max_duration")
+
+        return _core.bm_fpt_and_occupation(
+            self.start_position,
+            self.diffusion_coefficient,
+            time_step,
+            target,
+            trap,
+            max_duration,
+            particles,
+        )
+
     def occupation_time(
         self,
         domain: tuple[real, real],
@@ -234,6 +1104,167 @@ class Bm:
             duration,
         )
 
+    def occupation_time_curve(
+        self,
+        domain: tuple[real, real],
+        duration: real,
+        time_step: float = 0.01,
+    ) -> tuple[Vector, Vector]:
+        """
+        Calculate the cumulative occupation time of one Brownian path in a given domain.
+
+        Args:
+            domain (tuple[real, real]): The domain (a, b) for occupation time. a must be less than b.
+            duration (real): The total duration of the simulation.
+            time_step (real, optional): Step size for the simulation. Defaults to 0.01.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray]: The times and the occupation time accumulated up to
+                each of them; the last value is the total occupation time of the path.
+        """
+        a, b = validate_domain(domain, process_name="Bm Occupation time")
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.bm_occupation_time_curve(
+            self.start_position,
+            self.diffusion_coefficient,
+            (a, b),
+            duration,
+            time_step,
+        )
+
+    def local_time_profile(
+        self,
+        edges: Vector,
+        duration: real,
+        time_step: float = 0.01,
+        particles: int = 10_000,
+    ) -> Vector:
+        """
+        Calculate the ensemble-averaged occupation time of the Brownian motion in each bin.
+
+        The ensemble is simulated once and every path is tallied into all bins, which is much
+        faster than calling `occupation_time` once per bin. Dividing the result by the bin widths
+        gives a coarse estimate of the occupation-time (local-time) density.
+
+        Args:
+            edges (Vector): Strictly increasing bin edges; bin k is [edges[k], edges[k+1]), the last bin is closed.
+            duration (real): The total duration of the simulation.
+            time_step (real, optional): Step size for the simulation. Defaults to 0.01.
+            particles (int, optional): Number of particles (positive integer) for ensemble averaging. Defaults to 10_000.
+
+        Returns:
+            np.ndarray: The mean occupation time in each of the len(edges) - 1 bins.
+        """
+        edges = validate_edges(edges)
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        particles = validate_particles(particles)
+
+        return _core.bm_local_time_profile(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            particles,
+            edges,
+        )
+
+    def propagator(
+        self,
+        snapshot_times: Vector,
+        bins: int,
+        range: tuple[real, real],
+        time_step: float = 0.01,
+        particles: int = 10_000,
+    ) -> tuple[Vector, Vector]:
+        """
+        Estimate the propagator P(x, t) of the Brownian motion at several snapshot times.
+
+        The ensemble is simulated once up to max(snapshot_times) and every path is histogrammed at
+        all snapshots, each snapshot using the grid point nearest to it.
+
+        Args:
+            snapshot_times (Vector): Positive snapshot times.
+            bins (int): Number of equally spaced bins over range.
+            range (tuple[real, real]): Finite (low, high) histogram range with low < high.
+            time_step (real, optional): Step size for the simulation. Defaults to 0.01.
+            particles (int, optional): Number of particles (positive integer) for ensemble averaging. Defaults to 10_000.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray]: The bins + 1 edges and the (len(snapshot_times), bins) densities,
+                normalized by the number of particles so that mass outside range is not counted.
+        """
+        try:
+            snapshot_times = np.asarray(snapshot_times, dtype=np.float64).ravel()
+        except (TypeError, ValueError) as e:
+            raise TypeError(
+                f"snapshot_times must be a sequence of real numbers. Error: {e}"
+            ) from e
+        if snapshot_times.size == 0:
+            raise ValueError("snapshot_times must not be empty")
+        if not np.all(np.isfinite(snapshot_times) & (snapshot_times > 0)):
+            raise ValueError("snapshot_times must be positive and finite")
+        bins = validate_positive_integer(bins, "bins")
+        low, high = range
+        low, high = validate_edges((low, high), "range")
+        time_step = validate_positive_float(time_step, "time_step")
+        particles = validate_particles(particles)
+
+        return _core.bm_propagator(
+            self.start_position,
+            self.diffusion_coefficient,
+            snapshot_times.tolist(),
+            time_step,
+            particles,
+            bins,
+            (float(low), float(high)),
+        )
+
+    def occupation_time_windows(
+        self,
+        centers: Vector,
+        half_width: real,
+        duration: real,
+        time_step: float = 0.01,
+        particles: int = 10_000,
+    ) -> Vector:
+        """
+        Calculate the ensemble-averaged occupation time of the Brownian motion in sliding windows.
+
+        The ensemble is simulated once and every path is tallied into all windows
+        [c - half_width, c + half_width], which may overlap. For centers spaced by h, summing the
+        result times h / (2 * half_width) approximates the total duration.
+
+        Args:
+            centers (Vector): Centers of the windows, in any order.
+            half_width (real): Half of the window width.
+            duration (real): The total duration of the simulation.
+            time_step (real, optional): Step size for the simulation. Defaults to 0.01.
+            particles (int, optional): Number of particles (positive integer) for ensemble averaging. Defaults to 10_000.
+
+        Returns:
+            np.ndarray: The mean occupation time in the window around each center.
+        """
+        centers = np.ascontiguousarray(centers, dtype=np.float64)
+        if centers.ndim != 1 or not np.all(np.isfinite(centers)):
+            raise ValueError("centers must be a one-dimensional array of finite numbers")
+        half_width = validate_positive_float(half_width, "half_width")
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        particles = validate_particles(particles)
+
+        return _core.bm_occupation_time_windows(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            particles,
+            centers,
+            half_width,
+        )
+
     def occupation_time_moment(
         self,
         domain: tuple[real, real],
@@ -293,6 +1324,7 @@ class Bm:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         """
         Calculate the time-averaged mean-square displacement of the Brownian motion.
@@ -302,6 +1334,7 @@ class Bm:
             delta (real): Time lag for the mean-square displacement.
             time_step (real, optional): Step size. Defaults to 0.01.
             quad_order (int, optional): Quadrature order. Defaults to 10.
+            rule (str, optional): Quadrature rule for the start-time integral, one of "gauss_legendre", "trapezoid" or "simpson". Defaults to "gauss_legendre".
 
         Returns:
             float: The time-averaged mean-square displacement.
@@ -310,6 +1343,7 @@ class Bm:
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.bm_tamsd(
             self.start_position,
@@ -318,6 +1352,48 @@ class Bm:
             delta,
             time_step,
             quad_order,
+            rule,
+        )
+
+    def tamsd_curve(
+        self,
+        duration: real,
+        deltas: list[real],
+        time_step: float = 0.01,
+        quad_order: int = 10,
+        rule: str = "gauss_legendre",
+    ) -> Vector:
+        """
+        Calculate the time-averaged mean-square displacement of one Brownian path for several lags.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            deltas (list[real]): Time lags, each less than duration.
+            time_step (real, optional): Step size. Defaults to 0.01.
+            quad_order (int, optional): Quadrature order. Defaults to 10.
+            rule (str, optional): Quadrature rule for the start-time integral, one of "gauss_legendre", "trapezoid" or "simpson". Defaults to "gauss_legendre".
+
+        Returns:
+            np.ndarray: The time-averaged mean-square displacement for each lag.
+        """
+        duration = validate_positive_float(duration, "duration")
+        deltas = [validate_positive_float(delta, "delta") for delta in deltas]
+        if not deltas:
+            raise ValueError("deltas must not be empty")
+        if any(delta >= duration for delta in deltas):
+            raise ValueError("every delta must be less than duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
+
+        return _core.bm_tamsd_curve(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            deltas,
+            time_step,
+            quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -327,6 +1403,7 @@ class Bm:
         particles: int = 10_000,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         """
         Calculate the time-averaged mean-square displacement of the Brownian motion.
@@ -337,6 +1414,7 @@ class Bm:
             particles (int, optional): Number of particles for ensemble average (positive integer). Defaults to 10_000.
             time_step (real, optional): Step size. Defaults to 0.01.
             quad_order (int, optional): Quadrature order. Defaults to 10.
+            rule (str, optional): Quadrature rule for the start-time integral, one of "gauss_legendre", "trapezoid" or "simpson". Defaults to "gauss_legendre".
 
         Returns:
             float: The time-averaged mean-square displacement.
@@ -346,6 +1424,7 @@ class Bm:
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.bm_eatamsd(
             self.start_position,
@@ -355,6 +1434,51 @@ class Bm:
             particles,
             time_step,
             quad_order,
+            rule,
+        )
+
+    def eatamsd_samples(
+        self,
+        duration: real,
+        delta: real,
+        particles: int = 10_000,
+        time_step: float = 0.01,
+        quad_order: int = 10,
+        rule: str = "gauss_legendre",
+    ) -> Vector:
+        """
+        Calculate the time-averaged mean-square displacement of each particle separately.
+
+        The mean of the result estimates `eatamsd`; its spread gives the TAMSD distribution
+        and the ergodicity breaking parameter.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            delta (real): Time lag for the mean-square displacement.
+            particles (int, optional): Number of particles (positive integer). Defaults to 10_000.
+            time_step (real, optional): Step size. Defaults to 0.01.
+            quad_order (int, optional): Quadrature order. Defaults to 10.
+            rule (str, optional): Quadrature rule for the start-time integral, one of "gauss_legendre", "trapezoid" or "simpson". Defaults to "gauss_legendre".
+
+        Returns:
+            np.ndarray: The time-averaged mean-square displacement of every particle.
+        """
+        duration = validate_positive_float(duration, "duration")
+        delta = validate_positive_float(delta, "delta")
+        particles = validate_particles(particles)
+        time_step = validate_positive_float(time_step, "time_step")
+        quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
+
+        return _core.bm_eatamsd_samples(
+            self.start_position,
+            self.diffusion_coefficient,
+            duration,
+            delta,
+            particles,
+            time_step,
+            quad_order,
+            rule,
         )
 
     def mean(
@@ -9,6 +9,7 @@ from .utils import (
     validate_particles,
     validate_positive_float,
     validate_positive_integer,
+    validate_quad_rule,
 )
 
 
@@ -29,6 +30,8 @@ class Cauchy:
         self,
         duration: real,
         time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         """
         Simulate the Cauchy process.
@@ -36,17 +39,24 @@ class Cauchy:
         Args:
             duration (real): Total duration of the simulation.
             time_step (float, optional): Step size of the simulation. Defaults to 0.01.
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
 
         Returns:
             tuple[np.ndarray, np.ndarray]: A tuple containing the times and positions of the Cauchy process.
         """
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.cauchy_simulate(
             self.start_position,
             duration,
             time_step,
+            t0,
+            thin,
         )
 
     def moment(
@@ -278,6 +288,7 @@ class Cauchy:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         """
         Calculate the time-averaged mean squared displacement (TAMS) of the Cauchy process.
@@ -287,6 +298,7 @@ class Cauchy:
             delta (real): The time interval for TAMS calculation.
             time_step (real, optional): Step size for the simulation. Defaults to 0.01.
             quad_order (int, optional): Order of the quadrature for integration. Defaults to 10.
+            rule (str, optional): Quadrature rule for the start-time integral, one of "gauss_legendre", "trapezoid" or "simpson". Defaults to "gauss_legendre".
 
         Returns:
             float: The time-averaged mean squared displacement of the Cauchy process.
@@ -295,6 +307,7 @@ class Cauchy:
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.cauchy_tamsd(
             self.start_position,
@@ -302,6 +315,7 @@ class Cauchy:
             delta,
             time_step,
             quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -311,6 +325,7 @@ class Cauchy:
         particles: int = 10_000,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         """
         Calculate the ensemble-averaged time-averaged mean squared displacement (EATAMS) of the Cauchy process.
@@ -321,6 +336,7 @@ class Cauchy:
             particles (int, optional): Number of particles (positive integer) for ensemble averaging. Defaults to 10_000.
             time_step (real, optional): Step size for the simulation. Defaults to 0.01.
             quad_order (int, optional): Order of the quadrature for integration. Defaults to 10.
+            rule (str, optional): Quadrature rule for the start-time integral, one of "gauss_legendre", "trapezoid" or "simpson". Defaults to "gauss_legendre".
 
         Returns:
             float: The ensemble-averaged time-averaged mean squared displacement of the Cauchy process.
@@ -330,6 +346,7 @@ class Cauchy:
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.cauchy_eatamsd(
             self.start_position,
@@ -338,6 +355,7 @@ class Cauchy:
             particles,
             time_step,
             quad_order,
+            rule,
         )
 
 
@@ -365,7 +383,11 @@ class AsymmetricCauchy:
         self.beta: float = beta
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         """
         Simulate the Asymmetric Cauchy process.
@@ -373,18 +395,25 @@ class AsymmetricCauchy:
         Args:
             duration (real): Total duration of the simulation.
             time_step (float, optional): Step size of the simulation. Defaults to 0.01.
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
 
         Returns:
             tuple[np.ndarray, np.ndarray]: A tuple containing the times and positions of the Asymmetric Cauchy process.
         """
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.asymmetric_cauchy_simulate(
             self.start_position,
             self.beta,
             duration,
             time_step,
+            t0,
+            thin,
         )
 
     def moment(
@@ -628,6 +657,7 @@ class AsymmetricCauchy:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         """
         Calculate the time-averaged mean squared displacement (TAMS) of the Asymmetric Cauchy process.
@@ -637,6 +667,7 @@ class AsymmetricCauchy:
             delta (real): The time interval for TAMS calculation.
             time_step (real, optional): Step size. Defaults to 0.01.
             quad_order (int, optional): Order of the quadrature. Defaults to 10.
+            rule (str, optional): Quadrature rule for the start-time integral, one of "gauss_legendre", "trapezoid" or "simpson". Defaults to "gauss_legendre".
 
         Returns:
             float: The time-averaged mean squared displacement of the Asymmetric Cauchy process.
@@ -645,6 +676,7 @@ class AsymmetricCauchy:
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.asymmetric_cauchy_tamsd(
             self.start_position,
@@ -653,6 +685,7 @@ class AsymmetricCauchy:
             delta,
             time_step,
             quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -662,6 +695,7 @@ class AsymmetricCauchy:
         particles: int = 10_000,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         """
         Calculate the ensemble-averaged time-averaged mean squared displacement (EATAMS) of the Asymmetric Cauchy process.
@@ -672,6 +706,7 @@ class AsymmetricCauchy:
             particles (int, optional): Number of particles for ensemble average (positive integer). Defaults to 10_000.
             time_step (real, optional): Step size. Defaults to 0.01.
             quad_order (int, optional): Order of the quadrature. Defaults to 10.
+            rule (str, optional): Quadrature rule for the start-time integral, one of "gauss_legendre", "trapezoid" or "simpson". Defaults to "gauss_legendre".
 
         Returns:
             float: The ensemble-averaged time-averaged mean squared displacement of the Asymmetric Cauchy process.
@@ -681,6 +716,7 @@ class AsymmetricCauchy:
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.asymmetric_cauchy_eatamsd(
             self.start_position,
@@ -690,4 +726,5 @@ class AsymmetricCauchy:
             particles,
             time_step,
             quad_order,
+            rule,
         )
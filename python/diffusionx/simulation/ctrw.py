@@ -125,6 +125,50 @@ class CTRW:
             )
         )
 
+    def raw_moment_running(
+        self,
+        duration: real,
+        order: int,
+        checkpoints: list[int],
+        particles: int = 10_000,
+    ) -> Vector:
+        """
+        Calculate the raw moment of the CTRW from the first c paths, for every c in checkpoints.
+
+        All paths are simulated once, so the result shows the Monte Carlo convergence in particles.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            order (int): Integer order of the moment.
+            checkpoints (list[int]): Strictly increasing positive path counts, the last at most particles.
+            particles (int, optional): Number of simulated paths. Defaults to 10_000.
+
+        Returns:
+            np.ndarray: The running raw moment at each checkpoint.
+        """
+        duration = validate_positive_float(duration, "duration")
+        order = validate_positive_integer(order, "order")
+        particles = validate_particles(particles)
+        checkpoints = [
+            validate_positive_integer(c, "checkpoints entry") for c in checkpoints
+        ]
+        if not checkpoints or any(a >= b for a, b in zip(checkpoints, checkpoints[1:])):
+            raise ValueError("checkpoints must be a non-empty, strictly increasing sequence")
+        if checkpoints[-1] > particles:
+            raise ValueError(
+                f"The last checkpoint {checkpoints[-1]} exceeds particles = {particles}"
+            )
+
+        return _core.ctrw_raw_moment_running(
+            self.alpha,
+            self.beta,
+            self.start_position,
+            duration,
+            order,
+            particles,
+            checkpoints,
+        )
+
     def fpt(
         self,
         domain: tuple[real, real],
@@ -294,3 +338,59 @@ class CTRW:
             duration,
             particles,
         )
+
+
+class WeibullCTRW:
+    def __init__(
+        self,
+        shape: real = 1.0,
+        scale: real = 1.0,
+        jump_beta: real = 2.0,
+        start_position: real = 0.0,
+    ):
+        """
+        Continuous Time Random Walk with Weibull waiting times.
+
+        Args:
+            shape (real, optional): Shape of the Weibull waiting times; 1 gives exponential waits. Defaults to 1.0.
+            scale (real, optional): Scale of the Weibull waiting times. Defaults to 1.0.
+            jump_beta (real, optional): Stable index of the jump lengths (0, 2]. Defaults to 2.0.
+            start_position (real, optional): Starting position. Defaults to 0.0.
+        """
+        self.shape: float = validate_positive_float(shape, "shape")
+        self.scale: float = validate_positive_float(scale, "scale")
+        self.jump_beta: float = validate_positive_float(jump_beta, "jump_beta")
+        self.start_position: float = ensure_float(start_position)
+
+        if not (self.jump_beta <= 2):
+            raise ValueError(
+                f"jump_beta must be in the range (0, 2], got {self.jump_beta}"
+            )
+
+    def simulate(
+        self, duration: real, t0: real = 0.0, thin: int = 1
+    ) -> tuple[Vector, Vector]:
+        """
+        Simulate the CTRW up to the given duration.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            t0 (real, optional): Start of the time axis. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one.
+                Defaults to 1.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray]: Jump times and positions, ending with a point at duration.
+        """
+        duration = validate_positive_float(duration, "duration")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
+        return _core.ctrw_weibull_simulate(
+            self.shape,
+            self.scale,
+            self.jump_beta,
+            self.start_position,
+            duration,
+            t0,
+            thin,
+        )
@@ -9,6 +9,8 @@ from .utils import (
     validate_particles,
     validate_positive_float,
     validate_positive_integer,
+    validate_quad_rule,
+    validate_seed,
 )
 
 
@@ -36,7 +38,11 @@ class FBm:
         self.hurst_exponent: float = hurst_exponent
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         """
         Simulate the fractional Brownian motion.
@@ -44,18 +50,139 @@ class FBm:
         Args:
             duration (real): Total duration of the simulation.
             time_step (real, optional): Step size for the simulation. Defaults to 0.01.
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
 
         Returns:
             tuple[np.ndarray, np.ndarray]: Times and positions of the FBM.
         """
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.fbm_simulate(
             self.start_position,
             self.hurst_exponent,
             duration,
             time_step,
+            t0,
+            thin,
+        )
+
+    def reflected_simulate(
+        self,
+        duration: real,
+        lower: real,
+        upper: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
+    ) -> tuple[Vector, Vector]:
+        """
+        Simulate the fractional Brownian motion reflected at `lower` and `upper`.
+
+        The unconstrained path is folded into [lower, upper] by iterated mirror images at
+        both walls; this is not the Skorokhod reflection, which differs for H != 0.5.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            lower (real): Lower wall; must be below the start position.
+            upper (real): Upper wall; must be above the start position.
+            time_step (real, optional): Step size for the simulation. Defaults to 0.01.
+            t0 (real, optional): Start of the time axis. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one.
+                Defaults to 1.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray]: Times and reflected positions.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        lower, upper = validate_domain((lower, upper), process_name="reflected FBm")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
+
+        return _core.reflected_fbm_simulate(
+            self.start_position,
+            self.hurst_exponent,
+            duration,
+            time_step,
+            lower,
+            upper,
+            t0,
+            thin,
+        )
+
+    def fgn(
+        self,
+        n: int,
+        time_step: float = 0.01,
+        seed: int | None = None,
+        thin: int = 1,
+    ) -> Vector:
+        """
+        Simulate fractional Gaussian noise, the increments of the fBm over steps of time_step.
+
+        The noise is sampled exactly and directly, so it does not lose precision by differencing a path.
+
+        Args:
+            n (int): Number of samples.
+            time_step (real, optional): Step size of the increments. Defaults to 0.01.
+            seed (int | None, optional): Seed for reproducible output. Defaults to None.
+            thin (int, optional): Return the sums of thin consecutive samples, i.e. the increments
+                of the thinned fBm path; the last sum may be shorter. Defaults to 1.
+
+        Returns:
+            np.ndarray: The n stationary fGn samples, or their ceil(n / thin) block sums.
+        """
+        n = validate_positive_integer(n, "n")
+        time_step = validate_positive_float(time_step, "time_step")
+        seed = validate_seed(seed)
+        thin = validate_positive_integer(thin, "thin")
+
+        return _core.fgn_simulate(self.hurst_exponent, n, time_step, seed, thin)
+
+    def vacf(
+        self,
+        duration: real,
+        max_lag: int,
+        particles: int = 10_000,
+        time_step: float = 0.01,
+    ) -> tuple[Vector, Vector]:
+        """
+        Calculate the velocity autocorrelation function (VACF) of the fractional Brownian motion.
+
+        Velocities are the discrete increments divided by the time step, i.e. scaled fractional
+        Gaussian noise, so the VACF is negative at nonzero lags for H < 0.5 (anti-persistence)
+        and positive for H > 0.5 (persistence).
+
+        Args:
+            duration (real): The total duration of the simulation.
+            max_lag (int): The largest lag, in time steps (positive integer).
+            particles (int, optional): Number of particles (positive integer) for ensemble averaging. Defaults to 10_000.
+            time_step (real, optional): Step size for the simulation. Defaults to 0.01.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray]: The lags in time units and the VACF at each lag.
+        """
+        duration = validate_positive_float(duration, "duration")
+        max_lag = validate_positive_integer(max_lag, "max_lag")
+        particles = validate_particles(particles)
+        time_step = validate_positive_float(time_step, "time_step")
+        if max_lag * time_step >= duration:
+            raise ValueError(
+                f"max_lag * time_step must be less than duration, got {max_lag * time_step} >= {duration}"
+            )
+
+        return _core.fbm_vacf(
+            self.start_position,
+            self.hurst_exponent,
+            duration,
+            time_step,
+            particles,
+            max_lag,
         )
 
     def fpt(
@@ -270,11 +397,13 @@ class FBm:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.fbm_tamsd(
             self.start_position,
@@ -283,6 +412,7 @@ class FBm:
             delta,
             time_step,
             quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -292,12 +422,14 @@ class FBm:
         particles: int = 10_000,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.fbm_eatamsd(
             self.start_position,
@@ -307,6 +439,7 @@ class FBm:
             particles,
             time_step,
             quad_order,
+            rule,
         )
 
     def mean(
@@ -2,12 +2,14 @@ from diffusionx import _core
 
 from .basic import Vector, real
 from .utils import (
+    ensure_float,
     validate_bool,
     validate_domain,
     validate_order,
     validate_particles,
     validate_positive_float,
     validate_positive_integer,
+    validate_quad_rule,
 )
 
 
@@ -31,16 +33,24 @@ class Gamma:
         self.rate: float = validate_positive_float(rate, "rate")
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.gamma_simulate(
             self.shape,
             self.rate,
             duration,
             time_step,
+            t0,
+            thin,
         )
 
     def moment(
@@ -221,11 +231,13 @@ class Gamma:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.gamma_tamsd(
             self.shape,
@@ -234,6 +246,7 @@ class Gamma:
             delta,
             time_step,
             quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -243,12 +256,14 @@ class Gamma:
         particles: int = 10_000,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.gamma_eatamsd(
             self.shape,
@@ -258,6 +273,7 @@ class Gamma:
             particles,
             time_step,
             quad_order,
+            rule,
         )
 
     def mean(
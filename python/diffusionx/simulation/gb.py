@@ -5,10 +5,12 @@ from .utils import (
     ensure_float,
     validate_bool,
     validate_domain,
+    validate_endpoint,
     validate_order,
     validate_particles,
     validate_positive_float,
     validate_positive_integer,
+    validate_quad_rule,
 )
 
 
@@ -33,10 +35,37 @@ class GeometricBm:
         self.sigma = validate_positive_float(sigma, "sigma")
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        endpoint: str = "extend",
+        exact: bool = False,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
+        """
+        Simulate the geometric Brownian motion.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            time_step (float, optional): Step size of the simulation. Defaults to 0.01.
+            endpoint (str, optional): "extend" or "clip", see `Bm.simulate`. Defaults to "extend".
+            exact (bool, optional): Whether to use the exact log-normal transition instead of the Euler scheme.
+                The exact update keeps prices strictly positive for any time_step, so `log_returns` of the
+                path never fails; it is recommended. Defaults to False.
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
+
+        Returns:
+            tuple[Vector, Vector]: The times and values of the process.
+        """
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        endpoint = validate_endpoint(endpoint)
+        validate_bool(exact, "exact")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.gb_simulate(
             self.start_value,
@@ -44,6 +73,46 @@ class GeometricBm:
             self.sigma,
             duration,
             time_step,
+            endpoint,
+            exact,
+            t0,
+            thin,
+        )
+
+    def running_max_simulate(
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
+    ) -> tuple[Vector, Vector, Vector]:
+        """
+        Simulate the Geometric Brownian Motion together with its running maximum.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            time_step (float, optional): Step size of the simulation. Defaults to 0.01.
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
+
+        Returns:
+            tuple[np.ndarray, np.ndarray, np.ndarray]: A tuple containing the times, values and
+                running maximum M(t) = max_{s<=t} S(s) of the process.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
+
+        return _core.gb_running_max_simulate(
+            self.start_value,
+            self.mu,
+            self.sigma,
+            duration,
+            time_step,
+            t0,
+            thin,
         )
 
     def moment(
@@ -53,12 +122,25 @@ class GeometricBm:
         central: bool = False,
         particles: int = 10_000,
         time_step: float = 0.01,
+        control_variate: bool = False,
+        qmc: bool = False,
     ) -> float:
         validate_bool(central, "central")
+        validate_bool(control_variate, "control_variate")
+        validate_bool(qmc, "qmc")
         validate_order(order)
         particles = validate_particles(particles)
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        if control_variate and (central or not isinstance(order, int)):
+            raise ValueError(
+                "control_variate is only supported for raw moments of integer order"
+            )
+        if qmc and (central or not isinstance(order, int)):
+            # quasi-Monte Carlo from the exact log-normal terminal law, smooth functionals only
+            raise ValueError("qmc is only supported for raw moments of integer order")
+        if qmc and control_variate:
+            raise ValueError("control_variate and qmc cannot be combined")
 
         return (
             (
@@ -70,6 +152,8 @@ class GeometricBm:
                     time_step,
                     order,
                     particles,
+                    control_variate,
+                    qmc,
                 )
                 if not central
                 else _core.gb_central_moment(
@@ -233,11 +317,13 @@ class GeometricBm:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.gb_tamsd(
             self.start_value,
@@ -247,6 +333,7 @@ class GeometricBm:
             delta,
             time_step,
             quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -256,12 +343,14 @@ class GeometricBm:
         particles: int = 10_000,
         time_step: float = 0.01,
         quad_order: int = 32,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.gb_eatamsd(
             self.start_value,
@@ -272,6 +361,7 @@ class GeometricBm:
             particles,
             time_step,
             quad_order,
+            rule,
         )
 
     def mean(
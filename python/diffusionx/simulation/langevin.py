@@ -1,5 +1,8 @@
 from typing import Callable
 
+import numpy as np
+from numpy.typing import ArrayLike
+
 from diffusionx import _core
 
 from .basic import Vector, real
@@ -7,13 +10,26 @@ from .utils import (
     ensure_float,
     validate_bool,
     validate_domain,
+    validate_edges,
     validate_order,
     validate_particles,
     validate_positive_float,
     validate_positive_integer,
+    validate_quad_rule,
 )
 
 
+def _validate_table(table: ArrayLike, size: int, name: str) -> np.ndarray:
+    array = np.ascontiguousarray(table, dtype=np.float64)
+    if array.shape != (size,):
+        raise ValueError(
+            f"{name} must be a 1-D array of the same length as x_grid ({size}), got shape {array.shape}"
+        )
+    if not np.all(np.isfinite(array)):
+        raise ValueError(f"{name} must be finite")
+    return array
+
+
 class Langevin:
     """
     Langevin equation:
@@ -31,6 +47,12 @@ class Langevin:
         Diffusion function g(x, t).
     start_position : float, optional
         Initial position x0. Defaults to 0.0.
+    interpretation : str, optional
+        "ito" or "stratonovich", the meaning of g dW. Defaults to "ito". A Stratonovich
+        equation is integrated as the Ito equation with drift f + g g' / 2; for additive
+        noise the two coincide. Used by `simulate` and `moment`.
+    diffusion_prime_func : Callable[[float, float], float] | None, optional
+        Derivative g'(x, t) = dg/dx, required for "stratonovich". Defaults to None.
     """
 
     def __init__(
@@ -38,6 +60,8 @@ class Langevin:
         drift_func: Callable[[float, float], float],
         diffusion_func: Callable[[float, float], float],
         start_position: real = 0.0,
+        interpretation: str = "ito",
+        diffusion_prime_func: Callable[[float, float], float] | None = None,
     ) -> None:
         if not callable(drift_func):
             raise TypeError(
@@ -47,11 +71,31 @@ class Langevin:
             raise TypeError(
                 f"diffusion_func must be a callable function, got {type(diffusion_func).__name__}"
             )
+        if interpretation not in ("ito", "stratonovich"):
+            raise ValueError(
+                f"interpretation must be 'ito' or 'stratonovich', got {interpretation!r}"
+            )
+        if diffusion_prime_func is not None and not callable(diffusion_prime_func):
+            raise TypeError(
+                f"diffusion_prime_func must be a callable function, got {type(diffusion_prime_func).__name__}"
+            )
+        if interpretation == "stratonovich" and diffusion_prime_func is None:
+            raise ValueError(
+                "the Stratonovich interpretation requires diffusion_prime_func"
+            )
         self.drift_func = drift_func
         self.diffusion_func = diffusion_func
         self.start_position = ensure_float(start_position)
+        self.interpretation = interpretation
+        self.diffusion_prime_func = diffusion_prime_func
 
-    def simulate(self, duration: real, time_step: real) -> tuple[Vector, Vector]:
+    def simulate(
+        self,
+        duration: real,
+        time_step: real,
+        t0: real = 0.0,
+        thin: int = 1,
+    ) -> tuple[Vector, Vector]:
         """
         Simulate the Langevin process.
 
@@ -61,6 +105,12 @@ class Langevin:
             Simulation duration (must be positive).
         time_step : real
             Time step size (must be positive).
+        t0 : real, optional
+            Start of the time axis; the drift and diffusion functions see the
+            shifted times. Defaults to 0.0.
+        thin : int, optional
+            Return only every thin-th point, always keeping the last one; the path
+            is still simulated at full resolution. Defaults to 1.
 
         Returns
         -------
@@ -69,6 +119,8 @@ class Langevin:
         """
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.langevin_simulate(
             self.drift_func,
@@ -76,6 +128,109 @@ class Langevin:
             self.start_position,
             duration,
             time_step,
+            t0,
+            thin,
+            self.interpretation,
+            self.diffusion_prime_func,
+        )
+
+    def simulate_with_work(
+        self, duration: real, time_step: real, t0: real = 0.0, thin: int = 1
+    ) -> tuple[Vector, Vector, Vector]:
+        """
+        Simulate the Langevin process together with the work done by the drift.
+
+        The work is accumulated in the Stratonovich (midpoint) sense,
+        W_{i+1} = W_i + f((x_i + x_{i+1}) / 2, (t_i + t_{i+1}) / 2) * (x_{i+1} - x_i).
+
+        Parameters
+        ----------
+        duration : real
+            Simulation duration (must be positive).
+        time_step : real
+            Time step size (must be positive).
+        t0 : real, optional
+            Start of the time axis, as in `simulate`. Defaults to 0.0.
+        thin : int, optional
+            Return only every thin-th point of the three arrays, always keeping the
+            last one; the work is still accumulated at full resolution. Defaults to 1.
+
+        Returns
+        -------
+        tuple[numpy.ndarray, numpy.ndarray, numpy.ndarray]
+            (time points array, position array, cumulative work array starting at 0)
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
+
+        return _core.langevin_simulate_with_work(
+            self.drift_func,
+            self.diffusion_func,
+            self.start_position,
+            duration,
+            time_step,
+            t0,
+            thin,
+        )
+
+    @staticmethod
+    def simulate_tabulated(
+        x_grid: ArrayLike,
+        drift_table: ArrayLike,
+        diffusion_table: ArrayLike,
+        duration: real,
+        start_position: real = 0.0,
+        time_step: real = 0.01,
+        clamp: bool = True,
+    ) -> tuple[Vector, Vector]:
+        """
+        Simulate a Langevin process whose drift and diffusion are tabulated in position.
+
+        The coefficients are linearly interpolated over `x_grid` without calling back into Python.
+
+        Parameters
+        ----------
+        x_grid : array_like
+            Strictly increasing positions at which the coefficients are tabulated.
+        drift_table : array_like
+            Drift f(x) at each point of `x_grid`.
+        diffusion_table : array_like
+            Diffusion g(x) at each point of `x_grid`.
+        duration : real
+            Simulation duration (must be positive).
+        start_position : real, optional
+            Initial position x0. Defaults to 0.0.
+        time_step : real, optional
+            Time step size (must be positive). Defaults to 0.01.
+        clamp : bool, optional
+            Use the boundary values outside `x_grid`; if False, leaving the grid raises
+            ValueError. Defaults to True.
+
+        Returns
+        -------
+        tuple[numpy.ndarray, numpy.ndarray]
+            (time points array, position array)
+        """
+        x_grid = validate_edges(x_grid, "x_grid")
+        drift_table = _validate_table(drift_table, x_grid.size, "drift_table")
+        diffusion_table = _validate_table(
+            diffusion_table, x_grid.size, "diffusion_table"
+        )
+        start_position = ensure_float(start_position)
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        validate_bool(clamp, "clamp")
+
+        return _core.langevin_simulate_tabulated(
+            x_grid,
+            drift_table,
+            diffusion_table,
+            start_position,
+            duration,
+            time_step,
+            clamp,
         )
 
     def moment(
@@ -85,6 +240,7 @@ class Langevin:
         central: bool = True,
         particles: int = 10_000,
         time_step: real = 0.01,
+        vectorized: bool = False,
     ) -> float:
         """
         Calculate the moment of the Langevin process.
@@ -99,6 +255,11 @@ class Langevin:
             Number of particles to simulate (must be positive).
         time_step : real
             Time step size (must be positive).
+        vectorized : bool
+            If True, drift_func and diffusion_func are called once per step with the
+            float64 array of all positions and the time, and must return an array of the
+            same length or a scalar, and so must diffusion_prime_func under the Stratonovich
+            interpretation. Much faster for many particles; integer orders only.
 
         Returns
         -------
@@ -106,11 +267,30 @@ class Langevin:
             Raw moment.
         """
         validate_bool(central, "central")
+        validate_bool(vectorized, "vectorized")
         validate_order(order)
         particles = validate_particles(particles)
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
 
+        if vectorized:
+            if not isinstance(order, int):
+                raise ValueError(
+                    f"vectorized moments require an integer order, got {order}"
+                )
+            return _core.langevin_vectorized_moment(
+                self.drift_func,
+                self.diffusion_func,
+                self.start_position,
+                duration,
+                order,
+                particles,
+                time_step,
+                central,
+                self.interpretation,
+                self.diffusion_prime_func,
+            )
+
         return (
             (
                 _core.langevin_raw_moment(
@@ -121,6 +301,8 @@ class Langevin:
                     order,
                     particles,
                     time_step,
+                    self.interpretation,
+                    self.diffusion_prime_func,
                 )
                 if not central
                 else _core.langevin_central_moment(
@@ -131,6 +313,8 @@ class Langevin:
                     order,
                     particles,
                     time_step,
+                    self.interpretation,
+                    self.diffusion_prime_func,
                 )
             )
             if isinstance(order, int)
@@ -143,6 +327,8 @@ class Langevin:
                     order,
                     particles,
                     time_step,
+                    self.interpretation,
+                    self.diffusion_prime_func,
                 )
                 if not central
                 else _core.langevin_frac_central_moment(
@@ -153,6 +339,8 @@ class Langevin:
                     order,
                     particles,
                     time_step,
+                    self.interpretation,
+                    self.diffusion_prime_func,
                 )
             )
         )
@@ -283,11 +471,13 @@ class Langevin:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.langevin_tamsd(
             self.drift_func,
@@ -297,6 +487,7 @@ class Langevin:
             delta,
             time_step,
             quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -306,12 +497,14 @@ class Langevin:
         particles: int = 10_000,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.langevin_eatamsd(
             self.drift_func,
@@ -322,6 +515,7 @@ class Langevin:
             particles,
             time_step,
             quad_order,
+            rule,
         )
 
 
@@ -375,7 +569,11 @@ class GeneralizedLangevin:
         self.alpha = alpha
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         """
         Simulate the Generalized Langevin process.
@@ -386,6 +584,12 @@ class GeneralizedLangevin:
             Simulation duration (must be positive).
         time_step : real
             Time step size (must be positive).
+        t0 : real, optional
+            Start of the time axis; the drift and diffusion functions see the
+            shifted times. Defaults to 0.0.
+        thin : int, optional
+            Return only every thin-th point, always keeping the last one; the path
+            is still simulated at full resolution. Defaults to 1.
 
         Returns
         -------
@@ -394,6 +598,8 @@ class GeneralizedLangevin:
         """
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.generalized_langevin_simulate(
             self.drift_func,
@@ -402,6 +608,8 @@ class GeneralizedLangevin:
             self.alpha,
             duration,
             time_step,
+            t0,
+            thin,
         )
 
     def moment(
@@ -625,11 +833,13 @@ class GeneralizedLangevin:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.generalized_langevin_tamsd(
             self.drift_func,
@@ -640,6 +850,7 @@ class GeneralizedLangevin:
             delta,
             time_step,
             quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -649,12 +860,14 @@ class GeneralizedLangevin:
         particles: int = 10_000,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.generalized_langevin_eatamsd(
             self.drift_func,
@@ -666,6 +879,7 @@ class GeneralizedLangevin:
             particles,
             time_step,
             quad_order,
+            rule,
         )
 
 
@@ -713,7 +927,11 @@ class SubordinatedLangevin:
         self.start_position = start_position
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         """
         Simulate the Subordinated Langevin process.
@@ -721,6 +939,8 @@ class SubordinatedLangevin:
         """
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.subordinated_langevin_simulate(
             self.drift_func,
@@ -729,6 +949,8 @@ class SubordinatedLangevin:
             self.alpha,
             duration,
             time_step,
+            t0,
+            thin,
         )
 
     def moment(
@@ -933,11 +1155,13 @@ class SubordinatedLangevin:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.subordinated_langevin_tamsd(
             self.drift_func,
@@ -948,6 +1172,7 @@ class SubordinatedLangevin:
             delta,
             time_step,
             quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -957,12 +1182,14 @@ class SubordinatedLangevin:
         particles: int = 10_000,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.subordinated_langevin_eatamsd(
             self.drift_func,
@@ -974,6 +1201,48 @@ class SubordinatedLangevin:
             particles,
             time_step,
             quad_order,
+            rule,
+        )
+
+    def eb_parameter(
+        self,
+        duration: real,
+        delta: real,
+        particles: int = 1_000,
+        time_step: float = 0.01,
+        quad_order: int = 10,
+    ) -> float:
+        """
+        Calculate the ergodicity-breaking parameter EB = Var(TAMSD) / E[TAMSD]^2.
+
+        Args:
+            duration (real): Measurement time of each trajectory.
+            delta (real): Lag time of the TAMSD.
+            particles (int, optional): Number of trajectories. Must be at least 2. Defaults to 1000.
+            time_step (float, optional): Step size for the simulation. Defaults to 0.01.
+            quad_order (int, optional): Order of the quadrature used for the TAMSD. Defaults to 10.
+
+        Returns:
+            float: The ergodicity-breaking parameter.
+        """
+        duration = validate_positive_float(duration, "duration")
+        delta = validate_positive_float(delta, "delta")
+        particles = validate_particles(particles)
+        if particles < 2:
+            raise ValueError(f"particles must be at least 2, got {particles}")
+        time_step = validate_positive_float(time_step, "time_step")
+        quad_order = validate_positive_integer(quad_order, "quad_order")
+
+        return _core.subordinated_langevin_eb_parameter(
+            self.drift_func,
+            self.diffusion_func,
+            self.start_position,
+            self.alpha,
+            duration,
+            delta,
+            particles,
+            time_step,
+            quad_order,
         )
 
     def mean(
@@ -1,3 +1,6 @@
+import numpy as np
+import numpy.typing as npt
+
 from diffusionx import _core
 
 from .basic import Vector, real
@@ -9,6 +12,7 @@ from .utils import (
     validate_particles,
     validate_positive_float,
     validate_positive_integer,
+    validate_quad_rule,
 )
 
 
@@ -33,7 +37,11 @@ class Levy:
         self.alpha = alpha
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         """
         Simulate the Lévy process.
@@ -41,18 +49,106 @@ class Levy:
         Args:
             duration (real): Total duration of the simulation.
             time_step (real, optional): Step size for the simulation. Defaults to 0.01.
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
 
         Returns:
             tuple[Vector, Vector]: Simulation times and positions.
         """
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.levy_simulate(
             self.start_position,
             self.alpha,
             duration,
             time_step,
+            t0,
+            thin,
+        )
+
+    @staticmethod
+    def simulate_sweep(
+        params: list[tuple[real, real, real, real]],
+    ) -> list[tuple[Vector, Vector]]:
+        """
+        Simulate several Lévy processes in parallel.
+
+        Under the same `set_seed`, the paths equal those of successive `simulate` calls.
+
+        Args:
+            params (list[tuple[real, real, real, real]]): Entries of
+                (start_position, alpha, duration, time_step).
+
+        Returns:
+            list[tuple[Vector, Vector]]: Simulation times and positions, one pair per entry.
+
+        Raises:
+            ValueError: If any entry is invalid; the message names the offending index
+                and no results are returned.
+        """
+        entries = []
+        for index, entry in enumerate(params):
+            if len(entry) != 4:
+                raise ValueError(
+                    f"sweep entry {index} must have 4 values, got {len(entry)}"
+                )
+            start_position, alpha, duration, time_step = entry
+            entries.append(
+                (
+                    ensure_float(start_position),
+                    ensure_float(alpha),
+                    ensure_float(duration),
+                    ensure_float(time_step),
+                )
+            )
+
+        return _core.levy_simulate_sweep(entries)
+
+    @staticmethod
+    def raw_moment_grid(
+        alphas: list[real],
+        durations: list[real],
+        order: int,
+        start_position: real = 0.0,
+        particles: int = 10_000,
+        time_step: float = 0.01,
+    ) -> npt.NDArray[np.float64]:
+        """
+        Raw moment of Lévy processes over a grid of stability indices and durations.
+
+        Args:
+            alphas (list[real]): Stability indices, each in (0, 2].
+            durations (list[real]): Durations at which the moment is evaluated.
+            order (int): Order of the moment.
+            start_position (real, optional): Starting position of every process. Defaults to 0.0.
+            particles (int, optional): Number of particles in each cell. Defaults to 10_000.
+            time_step (float, optional): Step size for the simulation. Defaults to 0.01.
+
+        Returns:
+            np.ndarray: The moments, of shape (len(alphas), len(durations)).
+        """
+        validate_order(order)
+        if not isinstance(order, int):
+            raise TypeError(f"order must be an integer, got {type(order).__name__}")
+        alphas = [validate_positive_float(alpha, "alpha") for alpha in alphas]
+        for alpha in alphas:
+            if not (alpha <= 2):
+                raise ValueError(f"alpha must be in the range (0, 2], got {alpha}")
+        durations = [validate_positive_float(d, "duration") for d in durations]
+        particles = validate_particles(particles)
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.levy_raw_moment_grid(
+            ensure_float(start_position),
+            alphas,
+            durations,
+            time_step,
+            order,
+            particles,
         )
 
     def fpt(
@@ -215,14 +311,52 @@ class Subordinator:
         self.alpha = alpha
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
         return _core.subordinator_simulate(
             self.alpha,
             duration,
             time_step,
+            t0,
+            thin,
+        )
+
+    def simulate_pair(
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
+    ) -> tuple[Vector, Vector, Vector, Vector]:
+        """
+        Simulate the subordinator S(u) together with its inverse E(t) from the same path.
+
+        Args:
+            duration (real): Physical time up to which the inverse is evaluated; S is simulated
+                until it first exceeds it.
+            time_step (float, optional): Step size of both the operational and the physical grid. Defaults to 0.01.
+            t0 (real, optional): Shift applied to both time axes. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point of each pair, always keeping
+                the last one; E is still evaluated from the full path. Defaults to 1.
+
+        Returns:
+            tuple[Vector, Vector, Vector, Vector]: The operational times, S on them, the physical
+                times and E on them.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
+        return _core.subordinator_pair_simulate(
+            self.alpha, duration, time_step, t0, thin
         )
 
     def fpt(
@@ -355,14 +489,22 @@ class InvSubordinator:
         self.alpha = alpha
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
         return _core.inv_subordinator_simulate(
             self.alpha,
             duration,
             time_step,
+            t0,
+            thin,
         )
 
     def fpt(
@@ -478,6 +620,97 @@ class InvSubordinator:
         return result
 
 
+class FractionalDiffusion:
+    def __init__(
+        self,
+        alpha: real,
+        diffusion_coefficient: real = 1.0,
+        start_position: real = 0.0,
+    ):
+        """
+        Initialize a fractional-time diffusion object.
+
+        The process is a Brownian motion evaluated at the inverse alpha-stable subordinator E(t),
+        with mean squared displacement 2 D t^alpha / Gamma(1 + alpha).
+
+        Args:
+            alpha (real): The alpha parameter of the inverse subordinator, value must be in (0, 1).
+            diffusion_coefficient (real, optional): Diffusion coefficient D of the Brownian motion. Defaults to 1.0.
+            start_position (real, optional): Starting position. Defaults to 0.0.
+        """
+        alpha = validate_positive_float(alpha, "alpha")
+        if alpha >= 1:
+            raise ValueError(
+                "alpha must be in the range (0, 1) for FractionalDiffusion"
+            )
+        self.alpha = alpha
+        self.diffusion_coefficient = validate_positive_float(
+            diffusion_coefficient, "diffusion_coefficient"
+        )
+        self.start_position = ensure_float(start_position)
+
+    def simulate(
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
+    ) -> tuple[Vector, Vector]:
+        """
+        Simulate the fractional-time diffusion.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            time_step (float, optional): Step size for the simulation. Defaults to 0.01.
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
+
+        Returns:
+            tuple[Vector, Vector]: Simulation times and positions.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
+
+        return _core.fractional_diffusion_simulate(
+            self.alpha,
+            self.diffusion_coefficient,
+            self.start_position,
+            duration,
+            time_step,
+            t0,
+            thin,
+        )
+
+    def msd(
+        self, duration: real, time_step: float = 0.01, particles: int = 10_000
+    ) -> float:
+        """
+        Calculate the mean squared displacement of the fractional-time diffusion.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            time_step (float, optional): Step size for the simulation. Defaults to 0.01.
+            particles (int, optional): Number of particles for ensemble averaging. Defaults to 10_000.
+
+        Returns:
+            float: The mean squared displacement at time `duration`.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        particles = validate_particles(particles)
+
+        return _core.fractional_diffusion_msd(
+            self.alpha,
+            self.diffusion_coefficient,
+            duration,
+            time_step,
+            particles,
+        )
+
+
 class AsymmetricLevy:
     def __init__(
         self,
@@ -507,10 +740,16 @@ class AsymmetricLevy:
         self.start_position = start_position
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.asymmetric_levy_simulate(
             self.start_position,
@@ -518,6 +757,8 @@ class AsymmetricLevy:
             self.beta,
             duration,
             time_step,
+            t0,
+            thin,
         )
 
     def fpt(
@@ -650,11 +891,13 @@ class AsymmetricLevy:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.asymmetric_levy_tamsd(
             self.start_position,
@@ -664,6 +907,7 @@ class AsymmetricLevy:
             delta,
             time_step,
             quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -673,12 +917,14 @@ class AsymmetricLevy:
         particles: int,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.asymmetric_levy_eatamsd(
             self.start_position,
@@ -689,4 +935,167 @@ class AsymmetricLevy:
             particles,
             time_step,
             quad_order,
+            rule,
+        )
+
+
+class IntegratedCIRSubordinator:
+    def __init__(self, kappa: real, theta: real, xi: real, v0: real):
+        """
+        Initialize an integrated CIR time change T(t) = int_0^t v(s) ds.
+
+        The variance follows the CIR process dv = kappa (theta - v) dt + xi sqrt(v) dW with v(0) = v0,
+        so the time change shows volatility clustering and grows at rate theta at stationarity.
+
+        Args:
+            kappa (real): Mean-reversion speed, non-negative.
+            theta (real): Long-run mean of the variance, non-negative.
+            xi (real): Volatility of the variance, non-negative.
+            v0 (real): Initial variance, non-negative.
+        """
+        params = {"kappa": kappa, "theta": theta, "xi": xi, "v0": v0}
+        for name, value in params.items():
+            value = ensure_float(value)
+            if not (0 <= value < float("inf")):
+                raise ValueError(f"{name} must be non-negative and finite, got {value}")
+            setattr(self, name, value)
+
+    def simulate(
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
+    ) -> tuple[Vector, Vector]:
+        """
+        Simulate the integrated CIR time change.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            time_step (float, optional): Step size for the simulation. Defaults to 0.01.
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
+
+        Returns:
+            tuple[Vector, Vector]: Simulation times and the nondecreasing time change.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
+
+        return _core.integrated_cir_subordinator_simulate(
+            self.kappa,
+            self.theta,
+            self.xi,
+            self.v0,
+            duration,
+            time_step,
+            t0,
+            thin,
+        )
+
+
+class NIG:
+    def __init__(
+        self,
+        alpha: real,
+        beta: real,
+        delta: real,
+        mu: real = 0.0,
+        start_position: real = 0.0,
+    ):
+        """
+        Initialize a normal-inverse Gaussian (NIG) Levy process.
+
+        It is Brownian motion with drift beta on an inverse Gaussian clock, so unlike stable
+        processes all of its moments are finite.
+
+        Args:
+            alpha (real): Tail heaviness, greater than |beta|.
+            beta (real): Skewness.
+            delta (real): Scale, positive.
+            mu (real, optional): Location drift. Defaults to 0.0.
+            start_position (real, optional): Starting position. Defaults to 0.0.
+        """
+        self.alpha = ensure_float(alpha)
+        self.beta = ensure_float(beta)
+        self.delta = validate_positive_float(delta, "delta")
+        self.mu = ensure_float(mu)
+        self.start_position = ensure_float(start_position)
+        if not self.alpha > abs(self.beta):
+            raise ValueError(
+                f"alpha must be greater than |beta|, got alpha = {self.alpha}, beta = {self.beta}"
+            )
+
+    def simulate(
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
+    ) -> tuple[Vector, Vector]:
+        """
+        Simulate the NIG process.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            time_step (float, optional): Step size for the simulation. Defaults to 0.01.
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
+
+        Returns:
+            tuple[Vector, Vector]: Simulation times and positions.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
+
+        return _core.nig_simulate(
+            self.alpha,
+            self.beta,
+            self.delta,
+            self.mu,
+            self.start_position,
+            duration,
+            time_step,
+            t0,
+            thin,
+        )
+
+    def raw_moment(
+        self,
+        duration: real,
+        order: int,
+        particles: int = 10_000,
+    ) -> float:
+        """
+        Calculate the raw moment of the NIG process, sampling X(duration) exactly.
+
+        Args:
+            duration (real): The time at which the moment is taken.
+            order (int): The order of the moment, non-negative.
+            particles (int, optional): Number of particles. Defaults to 10_000.
+
+        Returns:
+            float: The raw moment.
+        """
+        validate_order(order)
+        if not isinstance(order, int):
+            raise TypeError(f"order must be an integer, got {type(order).__name__}")
+        particles = validate_particles(particles)
+        duration = validate_positive_float(duration, "duration")
+
+        return _core.nig_raw_moment(
+            self.alpha,
+            self.beta,
+            self.delta,
+            self.mu,
+            self.start_position,
+            duration,
+            order,
+            particles,
         )
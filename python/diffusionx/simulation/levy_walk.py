@@ -8,6 +8,7 @@ from .utils import (
     validate_order,
     validate_particles,
     validate_positive_float,
+    validate_positive_integer,
 )
 
 
@@ -40,15 +41,21 @@ class LevyWalk:
         self,
         duration: real,
         time_step: float = 0.01,  # time_step interpretation can vary for LW
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.levy_walk_simulate(
             self.alpha,
             self.velocity,
             self.start_position,
             duration,
+            t0,
+            thin,
         )
 
     def moment(
@@ -2,12 +2,14 @@ from diffusionx import _core
 
 from .basic import Vector, real
 from .utils import (
+    ensure_float,
     validate_bool,
     validate_domain,
     validate_order,
     validate_particles,
     validate_positive_float,
     validate_positive_integer,
+    validate_quad_rule,
 )
 
 
@@ -21,14 +23,22 @@ class BrownianMeander:
         """
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.meander_simulate(
             duration,
             time_step,
+            t0,
+            thin,
         )
 
     def moment(
@@ -177,17 +187,20 @@ class BrownianMeander:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration (meander duration)")
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.meander_tamsd(
             duration,
             delta,
             time_step,
             quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -197,12 +210,14 @@ class BrownianMeander:
         particles: int,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration (meander duration)")
         delta = validate_positive_float(delta, "delta")
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.meander_eatamsd(
             duration,
@@ -210,6 +225,7 @@ class BrownianMeander:
             particles,
             time_step,
             quad_order,
+            rule,
         )
 
     def mean(
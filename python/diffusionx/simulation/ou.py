@@ -5,10 +5,14 @@ from .utils import (
     ensure_float,
     validate_bool,
     validate_domain,
+    validate_endpoint,
+    validate_nonnegative_float,
     validate_order,
     validate_particles,
     validate_positive_float,
     validate_positive_integer,
+    validate_quad_rule,
+    validate_seed,
 )
 
 
@@ -34,10 +38,36 @@ class OU:
         self.start_position = ensure_float(start_position)
 
     def simulate(
-        self, duration: real, time_step: float = 0.01
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        endpoint: str = "extend",
+        burn_in: real = 0.0,
+        t0: real = 0.0,
+        thin: int = 1,
     ) -> tuple[Vector, Vector]:
+        """
+        Simulate the OU process.
+
+        Args:
+            duration (real): Total duration of the returned path.
+            time_step (float, optional): Step size of the simulation. Defaults to 0.01.
+            endpoint (str, optional): "extend" or "clip", see `Bm.simulate`. Defaults to "extend".
+            burn_in (real, optional): Time to simulate and discard first, so that the path starts close to
+                the stationary distribution instead of at start_position. Defaults to 0.0.
+            t0 (real, optional): Time at which the returned time axis starts. Defaults to 0.0.
+            thin (int, optional): Return only every thin-th point, always keeping the last one; the path is
+                still simulated at full resolution. Defaults to 1.
+
+        Returns:
+            tuple[Vector, Vector]: The times, starting at t0 after the burn-in, and the positions.
+        """
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        endpoint = validate_endpoint(endpoint)
+        burn_in = validate_nonnegative_float(burn_in, "burn_in")
+        t0 = ensure_float(t0)
+        thin = validate_positive_integer(thin, "thin")
 
         return _core.ou_simulate(
             self.theta,
@@ -45,6 +75,67 @@ class OU:
             self.start_position,
             duration,
             time_step,
+            endpoint,
+            burn_in,
+            t0,
+            thin,
+        )
+
+    def simulate_integrated(
+        self,
+        duration: real,
+        time_step: float = 0.01,
+    ) -> tuple[Vector, Vector, Vector]:
+        """
+        Simulate the OU process together with its running time integral.
+
+        With the OU process as a velocity, the integral is the position of the particle.
+
+        Args:
+            duration (real): Total duration of the simulation.
+            time_step (float, optional): Step size of the simulation. Defaults to 0.01.
+
+        Returns:
+            tuple[Vector, Vector, Vector]: The times, the OU path and its trapezoidal integral from 0.
+        """
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.ou_simulate_integrated(
+            self.theta,
+            self.sigma,
+            self.start_position,
+            duration,
+            time_step,
+        )
+
+    def terminals(
+        self, duration: real, particles: int = 10_000, qmc: bool = False
+    ) -> Vector:
+        """
+        Sample the terminal values X(duration) of independent OU processes from the exact transition.
+
+        Args:
+            duration (real): Total duration.
+            particles (int, optional): Number of terminal values. Defaults to 10_000.
+            qmc (bool, optional): Whether to draw the Gaussian variates from a scrambled Sobol sequence.
+                This reduces the error of averages of smooth functions of the values, but the values
+                are not independent. Defaults to False.
+
+        Returns:
+            Vector: The terminal values.
+        """
+        duration = validate_positive_float(duration, "duration")
+        particles = validate_particles(particles)
+        validate_bool(qmc, "qmc")
+
+        return _core.ou_terminals(
+            self.theta,
+            self.sigma,
+            self.start_position,
+            duration,
+            particles,
+            qmc,
         )
 
     def moment(
@@ -54,12 +145,31 @@ class OU:
         center: bool = False,
         particles: int = 10_000,
         time_step: float = 0.01,
+        richardson: bool = False,
+        burn_in: real = 0.0,
     ) -> float:
         validate_bool(center, "center")
+        validate_bool(richardson, "richardson")
         validate_order(order)
         particles = validate_particles(particles)
         duration = validate_positive_float(duration, "duration")
         time_step = validate_positive_float(time_step, "time_step")
+        burn_in = validate_nonnegative_float(burn_in, "burn_in")
+
+        if richardson:
+            # Richardson extrapolation with coupled Euler-Maruyama noise, raw integer moments only
+            if center or not isinstance(order, int):
+                raise ValueError("richardson requires a raw moment of integer order")
+            # the moment after a burn-in is the moment at burn_in + duration
+            return _core.ou_raw_moment_extrapolated(
+                self.theta,
+                self.sigma,
+                self.start_position,
+                burn_in + duration,
+                time_step,
+                order,
+                particles,
+            )
 
         return (
             (
@@ -71,6 +181,7 @@ class OU:
                     time_step,
                     order,
                     particles,
+                    burn_in,
                 )
                 if not center
                 else _core.ou_central_moment(
@@ -81,6 +192,7 @@ class OU:
                     time_step,
                     order,
                     particles,
+                    burn_in,
                 )
             )
             if isinstance(order, int)
@@ -93,6 +205,7 @@ class OU:
                     time_step,
                     order,
                     particles,
+                    burn_in,
                 )
                 if not center
                 else _core.ou_frac_central_moment(
@@ -103,10 +216,56 @@ class OU:
                     time_step,
                     order,
                     particles,
+                    burn_in,
                 )
             )
         )
 
+    def moment_crn(
+        self,
+        duration: real,
+        order: int,
+        seed: int,
+        particles: int = 10_000,
+        time_step: float = 0.01,
+    ) -> float:
+        """
+        Calculate the raw moment of the OU process with common random numbers.
+
+        The driving noise depends only on `seed` and `time_step`, so estimates for different
+        parameters with the same seed are strongly correlated and their difference is a
+        low-variance finite-difference sensitivity.
+
+        Args:
+            duration (real): The total duration of the simulation.
+            order (int): The order of the moment.
+            seed (int): Seed of the shared driving noise.
+            particles (int, optional): Number of particles. Defaults to 10_000.
+            time_step (float, optional): Step size for the simulation. Defaults to 0.01.
+
+        Returns:
+            float: The raw moment.
+        """
+        if isinstance(order, bool) or not isinstance(order, int):
+            raise TypeError(f"order must be an integer, got {type(order).__name__}")
+        if seed is None:
+            raise ValueError("seed is required for common random numbers")
+        seed = validate_seed(seed)
+        particles = validate_particles(particles)
+        duration = validate_positive_float(duration, "duration")
+        time_step = validate_positive_float(time_step, "time_step")
+
+        return _core.ou_raw_moment_crn(
+            self.theta,
+            self.sigma,
+            self.start_position,
+            duration,
+            time_step,
+            order,
+            particles,
+            seed,
+        )
+
     def fpt(
         self,
         domain: tuple[real, real],
@@ -235,11 +394,13 @@ class OU:
         delta: real,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.ou_tamsd(
             self.theta,
@@ -249,6 +410,7 @@ class OU:
             delta,
             time_step,
             quad_order,
+            rule,
         )
 
     def eatamsd(
@@ -258,12 +420,14 @@ class OU:
         particles: int,
         time_step: float = 0.01,
         quad_order: int = 10,
+        rule: str = "gauss_legendre",
     ) -> float:
         duration = validate_positive_float(duration, "duration")
         delta = validate_positive_float(delta, "delta")
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
         quad_order = validate_positive_integer(quad_order, "quad_order")
+        rule = validate_quad_rule(rule)
 
         return _core.ou_eatamsd(
             self.theta,
@@ -274,10 +438,15 @@ class OU:
             particles,
             time_step,
             quad_order,
+            rule,
         )
 
     def mean(
-        self, duration: real, time_step: float = 0.01, particles: int = 10_000
+        self,
+        duration: real,
+        time_step: float = 0.01,
+        particles: int = 10_000,
+        burn_in: real = 0.0,
     ) -> float:
         """
         Calculate the mean of the Ornstein-Uhlenbeck process.
@@ -286,6 +455,8 @@ class OU:
             duration (real): The total duration of the simulation.
             time_step (float, optional): Step size for the simulation. Defaults to 0.01.
             particles (int, optional): Number of particles (positive integer) for ensemble averaging. Defaults to 10_000.
+            burn_in (real, optional): Time after which the mean is measured, i.e. the mean is taken at
+                burn_in + duration so that the transient from start_position has decayed. Defaults to 0.0.
 
         Returns:
             float: The mean of the Ornstein-Uhlenbeck process.
@@ -293,14 +464,16 @@ class OU:
         duration = validate_positive_float(duration, "duration (motion duration)")
         particles = validate_particles(particles)
         time_step = validate_positive_float(time_step, "time_step")
+        burn_in = validate_nonnegative_float(burn_in, "burn_in")
 
         return _core.ou_mean(
             self.theta,
             self.sigma,
             self.start_position,
             duration,
-            time_step,
             particles,
+            time_step,
+            burn_in,
         )
 
     def msd(
@@ -329,6 +502,6 @@ class OU:
             self.sigma,
             self.start_position,
             duration,
-            time_step,
             particles,
+            time_step,
         )
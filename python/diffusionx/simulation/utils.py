@@ -1,6 +1,8 @@
 from math import isfinite
 from typing import Union
 
+import numpy as np
+
 real = Union[float, int]
 
 
@@ -47,6 +49,30 @@ def validate_bool(val: bool, name: str):
         raise TypeError(f"{name} must be a boolean, got {type(val).__name__}")
 
 
+QUAD_RULES = ("gauss_legendre", "trapezoid", "simpson")
+
+
+def validate_quad_rule(rule: str) -> str:
+    """Validate that rule names a supported quadrature rule for the TAMSD."""
+    if not isinstance(rule, str):
+        raise TypeError(f"rule must be a string, got {type(rule).__name__}")
+    if rule not in QUAD_RULES:
+        raise ValueError(f"rule must be one of {QUAD_RULES}, got {rule!r}")
+    return rule
+
+
+ENDPOINTS = ("extend", "clip")
+
+
+def validate_endpoint(endpoint: str) -> str:
+    """Validate the policy for a time_step that does not divide the duration."""
+    if not isinstance(endpoint, str):
+        raise TypeError(f"endpoint must be a string, got {type(endpoint).__name__}")
+    if endpoint not in ENDPOINTS:
+        raise ValueError(f"endpoint must be one of {ENDPOINTS}, got {endpoint!r}")
+    return endpoint
+
+
 def validate_particles(particles: int) -> int:
     """Validate that particles is a positive integer."""
     return validate_positive_integer(particles, "particles")
@@ -65,6 +91,19 @@ def validate_positive_float(value: real, param_name: str) -> float:
     return float_value
 
 
+def validate_nonnegative_float(value: real, param_name: str) -> float:
+    """Validate that a parameter is a non-negative float after conversion."""
+    try:
+        float_value = ensure_float(value)
+    except TypeError as e:
+        raise TypeError(f"{param_name} must be a number. Error: {e}") from e
+    if not isfinite(float_value):
+        raise ValueError(f"{param_name} must be finite, got {float_value}")
+    if float_value < 0:
+        raise ValueError(f"{param_name} must be non-negative, got {float_value}")
+    return float_value
+
+
 def validate_domain(
     domain: tuple[real, real],
     domain_type: str = "interval",  # "interval", "poisson_fpt", "poisson_occupation"
@@ -87,7 +126,8 @@ def validate_domain(
         raise TypeError(f"{base_msg}. Error: {e}") from e
 
     if domain_type == "interval":
-        if a >= b:
+        # `not a < b` also rejects NaN bounds, for which every comparison is False
+        if not a < b:
             base_msg = f"Invalid domain [{a}, {b}]; domain[0] must be strictly less than domain[1]"
             if process_name:
                 base_msg += f" for {process_name}"
@@ -126,3 +166,29 @@ def validate_domain(
         raise ValueError(f"Unknown domain_type: {domain_type}")
 
     return a, b
+
+
+def validate_edges(edges, name: str = "edges") -> np.ndarray:
+    """Validate that edges is a strictly increasing 1-D sequence of at least two finite numbers."""
+    try:
+        array = np.asarray(edges, dtype=np.float64)
+    except (TypeError, ValueError) as e:
+        raise TypeError(f"{name} must be a sequence of real numbers. Error: {e}") from e
+    if array.ndim != 1 or array.size < 2:
+        raise ValueError(f"{name} must be a 1-D sequence with at least two entries")
+    if not np.all(np.isfinite(array)):
+        raise ValueError(f"{name} must be finite")
+    if not np.all(np.diff(array) > 0):
+        raise ValueError(f"{name} must be strictly increasing")
+    return np.ascontiguousarray(array)
+
+
+def validate_seed(seed) -> int | None:
+    """Validate that seed is None or an integer in [0, 2**64)."""
+    if seed is None:
+        return None
+    if isinstance(seed, bool) or not isinstance(seed, int):
+        raise TypeError(f"seed must be an integer or None, got {type(seed).__name__}")
+    if not (0 <= seed < 2**64):
+        raise ValueError(f"Invalid seed {seed}, expected integer in [0, 2**64)")
+    return seed
@@ -29,4 +29,4 @@ fi
 
 PYO3_PYTHON="$PYTHON_BIN" \
 PYTHONHOME="$PYTHONHOME" \
-cargo run --bin stub_gen --features stub_gen
+cargo run --bin stub_gen --features stub_gen,parquet
@@ -0,0 +1,302 @@
+use crate::{XPyError, XPyResult};
+use numpy::{IntoPyArray, Ix1, PyArray, PyReadonlyArray1};
+use pyo3::prelude::*;
+#[cfg(feature = "stub_gen")]
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use std::collections::HashMap;
+
+/// 已知解析矩的过程及其参数；`None` 表示必填，`Some(v)` 为默认值
+const SPECS: &[(&str, &[(&str, Option<f64>)])] = &[
+    (
+        "bm",
+        &[
+            ("start_position", Some(0.0)),
+            ("diffusion_coefficient", Some(1.0)),
+        ],
+    ),
+    (
+        "drifted_bm",
+        &[
+            ("start_position", Some(0.0)),
+            ("diffusion_coefficient", Some(1.0)),
+            ("drift", None),
+        ],
+    ),
+    (
+        "ou",
+        &[
+            ("theta", None),
+            ("sigma", None),
+            ("start_position", Some(0.0)),
+        ],
+    ),
+    ("ou_stationary", &[("theta", None), ("sigma", None)]),
+    (
+        "geometric_bm",
+        &[("start_position", Some(1.0)), ("mu", None), ("sigma", None)],
+    ),
+    ("poisson", &[("lambda_", None)]),
+];
+
+/// Closed-form raw moment `E[X(duration)^order]`, or `None` if no formula is known.
+///
+/// Supported processes, with the parameter names of `make_process`:
+/// `bm` and `drifted_bm` (`X = x0 + drift * t + sqrt(2D) W`), `ou` started at
+/// `start_position`, `ou_stationary` started from its stationary law, `geometric_bm` and
+/// `poisson` (`lambda_`). Negative orders are only supported for `geometric_bm`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn analytic_moment(
+    process: &str,
+    params: Option<HashMap<String, f64>>,
+    duration: f64,
+    order: i32,
+) -> XPyResult<Option<f64>> {
+    let Some(&(name, spec)) = SPECS.iter().find(|(key, _)| *key == process) else {
+        return Ok(None);
+    };
+    if !(duration >= 0.0 && duration.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "duration must be non-negative and finite, got {duration}"
+        )));
+    }
+    let p = checked_params(name, spec, params.unwrap_or_default())?;
+    let t = duration;
+    if name == "geometric_bm" {
+        let (s0, mu, sigma) = (p[0], p[1], p[2]);
+        positive(name, "start_position", s0)?;
+        positive(name, "sigma", sigma)?;
+        let n = order as f64;
+        return Ok(Some(
+            s0.powi(order) * ((n * mu + 0.5 * n * (n - 1.0) * sigma * sigma) * t).exp(),
+        ));
+    }
+    let Ok(order) = u32::try_from(order) else {
+        return Ok(None);
+    };
+    let result = match name {
+        "bm" => {
+            positive(name, "diffusion_coefficient", p[1])?;
+            gaussian_raw_moment(p[0], 2.0 * p[1] * t, order)
+        }
+        "drifted_bm" => {
+            positive(name, "diffusion_coefficient", p[1])?;
+            gaussian_raw_moment(p[0] + p[2] * t, 2.0 * p[1] * t, order)
+        }
+        "ou" => {
+            let (theta, sigma, x0) = (p[0], p[1], p[2]);
+            positive(name, "theta", theta)?;
+            positive(name, "sigma", sigma)?;
+            let variance = -sigma * sigma * (-2.0 * theta * t).exp_m1() / (2.0 * theta);
+            gaussian_raw_moment(x0 * (-theta * t).exp(), variance, order)
+        }
+        "ou_stationary" => {
+            let (theta, sigma) = (p[0], p[1]);
+            positive(name, "theta", theta)?;
+            positive(name, "sigma", sigma)?;
+            gaussian_raw_moment(0.0, sigma * sigma / (2.0 * theta), order)
+        }
+        "poisson" => {
+            positive(name, "lambda_", p[0])?;
+            poisson_raw_moment(p[0] * t, order)
+        }
+        _ => unreachable!("every name in SPECS has a formula"),
+    };
+    Ok(Some(result))
+}
+
+/// First passage density of drifted Brownian motion to the level `boundary`.
+///
+/// For `X(t) = start_position + drift * t + sqrt(2D) W(t)`, distance
+/// `a = |boundary - start_position|` and drift `v` towards the boundary, the density is
+/// the inverse Gaussian `a / sqrt(4 pi D t^3) exp(-(a - v t)^2 / (4 D t))`, and 0 for
+/// `t <= 0`. If the drift points away from the boundary the density is defective: it
+/// integrates to the hitting probability `exp(-a |v| / D)`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_fpt_pdf<'py>(
+    py: Python<'py>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    drift: f64,
+    boundary: f64,
+    t: PyReadonlyArray1<'py, f64>,
+) -> XPyResult<Bound<'py, PyArray<f64, Ix1>>> {
+    let (distance, velocity) =
+        fpt_geometry(start_position, diffusion_coefficient, drift, boundary)?;
+    let density = t
+        .as_array()
+        .iter()
+        .map(|&t| fpt_density(distance, velocity, diffusion_coefficient, t))
+        .collect::<Vec<f64>>();
+    Ok(density.into_pyarray(py))
+}
+
+/// 距离 `distance`、朝边界漂移 `velocity` 时首达时间在 `t` 处的（逆高斯）密度
+fn fpt_density(distance: f64, velocity: f64, diffusion_coefficient: f64, t: f64) -> f64 {
+    if t > 0.0 {
+        let spread = 4.0 * diffusion_coefficient * t;
+        distance / (std::f64::consts::PI * spread * t * t).sqrt()
+            * (-(distance - velocity * t).powi(2) / spread).exp()
+    } else {
+        0.0
+    }
+}
+
+/// Mean first passage time of drifted Brownian motion to the level `boundary`, see
+/// `bm_fpt_pdf`.
+///
+/// Equals `a / v` when the drift points towards the boundary and `inf` otherwise, since the
+/// boundary is then missed with positive probability (or, for zero drift, hit with an
+/// infinite mean).
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_fpt_mean_analytic(
+    start_position: f64,
+    diffusion_coefficient: f64,
+    drift: f64,
+    boundary: f64,
+) -> XPyResult<f64> {
+    let (distance, velocity) =
+        fpt_geometry(start_position, diffusion_coefficient, drift, boundary)?;
+    Ok(if velocity > 0.0 {
+        distance / velocity
+    } else {
+        f64::INFINITY
+    })
+}
+
+/// 校验单边界首达问题的参数，返回到边界的距离与朝向边界的漂移分量
+fn fpt_geometry(
+    start_position: f64,
+    diffusion_coefficient: f64,
+    drift: f64,
+    boundary: f64,
+) -> XPyResult<(f64, f64)> {
+    if let Some(value) = [start_position, drift, boundary]
+        .into_iter()
+        .find(|value| !value.is_finite())
+    {
+        return Err(XPyError::ValueError(format!(
+            "start_position, drift and boundary must be finite, got {value}"
+        )));
+    }
+    positive("bm", "diffusion_coefficient", diffusion_coefficient)?;
+    if !diffusion_coefficient.is_finite() {
+        return Err(XPyError::ValueError(format!(
+            "diffusion_coefficient must be finite, got {diffusion_coefficient}"
+        )));
+    }
+    if start_position == boundary {
+        return Err(XPyError::ValueError(format!(
+            "boundary must differ from start_position, got {boundary}"
+        )));
+    }
+    let direction = (boundary - start_position).signum();
+    Ok(((boundary - start_position).abs(), direction * drift))
+}
+
+/// 按 `spec` 取出参数，缺少必填参数或出现未知参数时报错
+fn checked_params(
+    name: &str,
+    spec: &[(&str, Option<f64>)],
+    mut given: HashMap<String, f64>,
+) -> XPyResult<Vec<f64>> {
+    let params = spec
+        .iter()
+        .map(|&(key, default)| {
+            given.remove(key).or(default).ok_or_else(|| {
+                XPyError::ValueError(format!("missing parameter '{key}' for process '{name}'"))
+            })
+        })
+        .collect::<XPyResult<Vec<_>>>()?;
+    if let Some(key) = given.keys().next() {
+        return Err(XPyError::ValueError(format!(
+            "unknown parameter '{key}' for process '{name}'"
+        )));
+    }
+    if let Some(value) = params.iter().find(|value| !value.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "parameters of process '{name}' must be finite, got {value}"
+        )));
+    }
+    Ok(params)
+}
+
+fn positive(name: &str, key: &str, value: f64) -> XPyResult<()> {
+    if value > 0.0 {
+        Ok(())
+    } else {
+        Err(XPyError::ValueError(format!(
+            "{key} must be positive for process '{name}', got {value}"
+        )))
+    }
+}
+
+/// 正态分布 `N(m, v)` 的原点矩：`E[X^n] = sum_{k 偶} C(n, k) m^(n-k) v^(k/2) (k-1)!!`
+fn gaussian_raw_moment(mean: f64, variance: f64, order: u32) -> f64 {
+    let mut total = 0.0;
+    // term = C(n, k) v^(k/2) (k-1)!!，每次 k 增加 2 时递推更新
+    let mut term = 1.0;
+    for k in (0..=order).step_by(2) {
+        total += term * mean.powi((order - k) as i32);
+        let (n, k) = (order as f64, k as f64);
+        term *= (n - k) * (n - k - 1.0) / (k + 2.0) * variance;
+    }
+    total
+}
+
+/// 参数为 `mu` 的 Poisson 分布的原点矩，即 Touchard 多项式 `sum_k S(n, k) mu^k`，
+/// 第二类 Stirling 数按 `S(i, k) = k S(i-1, k) + S(i-1, k-1)` 逐行递推
+fn poisson_raw_moment(mu: f64, order: u32) -> f64 {
+    let order = order as usize;
+    let mut stirling = vec![0.0; order + 1];
+    stirling[0] = 1.0;
+    for i in 1..=order {
+        for k in (1..=i).rev() {
+            stirling[k] = k as f64 * stirling[k] + stirling[k - 1];
+        }
+        stirling[0] = 0.0;
+    }
+    stirling
+        .iter()
+        .rev()
+        .fold(0.0, |total, &coefficient| total * mu + coefficient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_moments_match_closed_forms() {
+        assert_eq!(gaussian_raw_moment(1.0, 2.0, 4), 25.0);
+        assert_eq!(gaussian_raw_moment(0.0, 1.0, 4), 3.0);
+        assert!((gaussian_raw_moment(0.5, 3.0, 3) - 4.625).abs() < 1e-12);
+        assert_eq!(poisson_raw_moment(2.0, 3), 22.0);
+        assert_eq!(poisson_raw_moment(2.0, 0), 1.0);
+        assert!((poisson_raw_moment(1.5, 4) - 42.5625).abs() < 1e-12);
+    }
+
+    #[test]
+    fn fpt_density_integrates_to_hitting_probability() {
+        // Simpson 公式积分到 t = 60，此后的尾部可以忽略
+        let integrate = |velocity: f64| {
+            let (n, h) = (60_000, 60.0 / 60_000.0);
+            (0..=n)
+                .map(|i| {
+                    let weight = match i {
+                        i if i == 0 || i == n => 1.0,
+                        i if i % 2 == 1 => 4.0,
+                        _ => 2.0,
+                    };
+                    weight * fpt_density(1.0, velocity, 0.5, i as f64 * h)
+                })
+                .sum::<f64>()
+                * h
+                / 3.0
+        };
+        assert!((integrate(1.0) - 1.0).abs() < 1e-9);
+        assert!((integrate(-1.0) - f64::exp(-2.0)).abs() < 1e-9);
+    }
+}
@@ -1,5 +1,8 @@
 use diffusionx::XError;
-use pyo3::{PyErr, exceptions::PyValueError};
+use pyo3::{
+    PyErr,
+    exceptions::{PyIOError, PyValueError},
+};
 use thiserror::Error;
 
 pub type XPyResult<T> = Result<T, XPyError>;
@@ -8,6 +11,8 @@ pub type XPyResult<T> = Result<T, XPyError>;
 pub enum XPyError {
     #[error("Invalid value: {0}")]
     ValueError(String),
+    #[error("I/O error: {0}")]
+    IoError(String),
 }
 
 impl From<XError> for XPyError {
@@ -18,6 +23,9 @@ impl From<XError> for XPyError {
 
 impl From<XPyError> for PyErr {
     fn from(error: XPyError) -> Self {
-        PyValueError::new_err(error.to_string())
+        match error {
+            XPyError::ValueError(_) => PyValueError::new_err(error.to_string()),
+            XPyError::IoError(_) => PyIOError::new_err(error.to_string()),
+        }
     }
 }
@@ -5,6 +5,12 @@ pub use error::*;
 
 pub mod random;
 
+pub mod distribution;
+
+pub mod analysis;
+
+pub mod analytic;
+
 pub mod simulation;
 
 macro_rules! register_functions {
@@ -46,43 +52,127 @@ fn _core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         random::uniform_rand_float,
         random::uniform_rand_int,
         random::uniform_rands_float,
+        random::uniform_rands_float_into,
         random::uniform_rands_int,
         random::normal_rand,
         random::normal_rands,
+        random::normal_rands_into,
         random::poisson_rand,
         random::poisson_rands,
         random::stable_rand,
         random::stable_rands,
         random::skew_stable_rand,
         random::skew_stable_rands,
+        random::mittag_leffler_rand,
+        random::mittag_leffler_rands,
+        random::inverse_gaussian_rand,
+        random::inverse_gaussian_rands,
+        random::weibull_rand,
+        random::weibull_rands,
+        random::half_cauchy_rand,
+        random::half_cauchy_rands,
         random::bool_rand,
         random::bool_rands,
+        random::bool_rands_packed,
+        random::rng_get_state,
+        random::rng_set_state,
+        random::set_rng_backend,
+        random::get_rng_backend,
+        random::set_seed,
+        random::seed_from_entropy,
+        distribution::stable_pdf,
+        distribution::stable_cdf,
+        analysis::raw_moment_from_array,
+        analysis::tamsd_from_array,
+        analysis::estimate_diffusion_coefficient,
+        analysis::increment_normality_test,
+        analysis::fit_stable,
+        analysis::fit_ou,
+        analysis::resample_step_path,
+        analysis::log_returns,
+        analysis::simple_returns,
+        analysis::integrate_path,
+        analytic::analytic_moment,
+        analytic::bm_fpt_pdf,
+        analytic::bm_fpt_mean_analytic,
         // Trait methods wrapper
         simulation::moment,
+        simulation::moment_diagnostic,
         simulation::mean,
         simulation::msd,
         // simulation::fpt,
         // simulation::occupation_time,
         simulation::tamsd,
+        simulation::tamsd_convergence,
         simulation::eatamsd,
+        simulation::dt_convergence,
+        simulation::add_processes,
+        simulation::local_scaling_exponent,
         // Brownian Motion
+        simulation::has_parquet,
+        simulation::set_max_points,
+        simulation::get_max_points,
+        simulation::num_grid_points,
+        simulation::make_process,
+        simulation::process_raw_moment,
+        simulation::process_central_moment,
+        simulation::process_fpt,
+        simulation::process_fpt_raw_moment,
+        simulation::process_fpt_central_moment,
+        simulation::process_occupation_time,
+        simulation::process_occupation_time_raw_moment,
+        simulation::process_occupation_time_central_moment,
+        simulation::simulate_reduce,
+        simulation::ensemble_functional,
+        simulation::benchmark_simulation,
         simulation::bm_simulate,
+        simulation::bm_simulate_dict,
+        simulation::bm_simulate_ensemble_dict,
+        simulation::bm_simulate_absorbing,
+        simulation::bm_simulate_double_barrier,
+        simulation::bm_simulate_until,
+        simulation::bm_simulate_batch_flat,
+        simulation::bm_simulate_with_increments,
+        simulation::bm_simulate_continue,
+        simulation::bm_ensemble_iter,
+        simulation::bm_terminal,
+        simulation::bm_survival_curve,
+        simulation::bm_first_crossing_path,
+        simulation::bm_terminals,
+        simulation::bm_running_max_simulate,
+        simulation::bm_extreme_stats,
+        simulation::bm_crossing_events,
         simulation::bm_raw_moment,
+        simulation::bm_raw_moment_diagnostic,
+        simulation::bm_raw_moment_ci,
+        simulation::bm_moment_about,
         simulation::bm_central_moment,
         simulation::bm_frac_raw_moment,
         simulation::bm_frac_central_moment,
         simulation::bm_fpt,
+        simulation::bm_fpt_moving,
         simulation::bm_fpt_raw_moment,
+        simulation::bm_fpt_raw_moment_corrected,
         simulation::bm_fpt_central_moment,
+        simulation::bm_fpt_and_occupation,
         simulation::bm_occupation_time,
+        simulation::bm_occupation_time_curve,
+        simulation::bm_local_time_profile,
+        simulation::bm_occupation_time_windows,
+        simulation::bm_propagator,
         simulation::bm_occupation_time_raw_moment,
         simulation::bm_occupation_time_central_moment,
         simulation::bm_tamsd,
+        simulation::bm_tamsd_curve,
         simulation::bm_eatamsd,
+        simulation::bm_eatamsd_samples,
         simulation::bm_mean,
         simulation::bm_msd,
         // Fractional Brownian Motion
         simulation::fbm_simulate,
+        simulation::reflected_fbm_simulate,
+        simulation::fgn_simulate,
+        simulation::fbm_vacf,
         simulation::fbm_raw_moment,
         simulation::fbm_central_moment,
         simulation::fbm_frac_raw_moment,
@@ -100,7 +190,9 @@ fn _core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         // Continuous Time Random Walk
         simulation::ctrw_simulate_duration,
         simulation::ctrw_simulate_step,
+        simulation::ctrw_weibull_simulate,
         simulation::ctrw_raw_moment,
+        simulation::ctrw_raw_moment_running,
         simulation::ctrw_central_moment,
         simulation::ctrw_frac_raw_moment,
         simulation::ctrw_frac_central_moment,
@@ -112,8 +204,11 @@ fn _core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         simulation::ctrw_occupation_time_central_moment,
         // Langevin Process
         simulation::langevin_simulate,
+        simulation::langevin_simulate_tabulated,
+        simulation::langevin_simulate_with_work,
         simulation::langevin_raw_moment,
         simulation::langevin_central_moment,
+        simulation::langevin_vectorized_moment,
         simulation::langevin_frac_raw_moment,
         simulation::langevin_frac_central_moment,
         simulation::langevin_fpt,
@@ -152,10 +247,13 @@ fn _core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         simulation::subordinated_langevin_occupation_time_central_moment,
         simulation::subordinated_langevin_tamsd,
         simulation::subordinated_langevin_eatamsd,
+        simulation::subordinated_langevin_eb_parameter,
         // Levy Process
         simulation::levy_simulate,
+        simulation::levy_simulate_sweep,
         simulation::levy_fpt,
         simulation::levy_raw_moment,
+        simulation::levy_raw_moment_grid,
         simulation::levy_central_moment,
         simulation::levy_frac_raw_moment,
         simulation::levy_frac_central_moment,
@@ -180,6 +278,9 @@ fn _core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         simulation::asymmetric_levy_occupation_time_central_moment,
         simulation::asymmetric_levy_tamsd,
         simulation::asymmetric_levy_eatamsd,
+        // Normal-Inverse Gaussian Levy Process
+        simulation::nig_simulate,
+        simulation::nig_raw_moment,
         // Poisson Process
         simulation::poisson_simulate_duration,
         simulation::poisson_simulate_step,
@@ -205,6 +306,10 @@ fn _core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         simulation::subordinator_occupation_time_raw_moment,
         simulation::subordinator_occupation_time_central_moment,
         simulation::inv_subordinator_simulate,
+        simulation::subordinator_pair_simulate,
+        simulation::fractional_diffusion_simulate,
+        simulation::fractional_diffusion_msd,
+        simulation::integrated_cir_subordinator_simulate,
         simulation::inv_subordinator_raw_moment,
         simulation::inv_subordinator_central_moment,
         simulation::inv_subordinator_fpt,
@@ -217,6 +322,7 @@ fn _core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         simulation::inv_subordinator_frac_central_moment,
         // Brownian Bridge
         simulation::bb_simulate,
+        simulation::bb_simulate_endpoints,
         simulation::bb_raw_moment,
         simulation::bb_central_moment,
         simulation::bb_frac_raw_moment,
@@ -309,6 +415,7 @@ fn _core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         simulation::gamma_eatamsd,
         // Geometric Brownian Motion
         simulation::gb_simulate,
+        simulation::gb_running_max_simulate,
         simulation::gb_raw_moment,
         simulation::gb_central_moment,
         simulation::gb_frac_raw_moment,
@@ -332,7 +439,11 @@ fn _core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         simulation::levy_walk_fpt,
         // Ornstein-Uhlenbeck Process
         simulation::ou_simulate,
+        simulation::ou_simulate_integrated,
+        simulation::ou_terminals,
         simulation::ou_raw_moment,
+        simulation::ou_raw_moment_extrapolated,
+        simulation::ou_raw_moment_crn,
         simulation::ou_central_moment,
         simulation::ou_frac_raw_moment,
         simulation::ou_frac_central_moment,
@@ -347,6 +458,12 @@ fn _core(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
         simulation::ou_tamsd,
         simulation::ou_eatamsd,
     );
+    #[cfg(feature = "parquet")]
+    register_functions!(m, simulation::bm_simulate_ensemble_to_parquet);
+    m.add_class::<simulation::Process>()?;
+    m.add_class::<simulation::BmEnsembleIterator>()?;
+    m.add_class::<simulation::UnitArray>()?;
+    m.add_class::<random::StableGenerator>()?;
     Ok(())
 }
 
@@ -1,14 +1,32 @@
-use crate::{XPyError, XPyResult, simulation::PyArrayPair};
+use crate::{
+    XPyError, XPyResult,
+    random::{set_stream_state, stream_state},
+    simulation::{
+        PyArrayPair, checked_num_steps, make_process,
+        summation::{self, CentralMoments},
+        thin_path, vec_to_pyarray, warn_nonfinite_moment,
+    },
+};
+use diffusionx::XResult;
 use gauss_quad::GaussLegendre;
-use numpy::PyArrayMethods;
-use pyo3::prelude::*;
+use numpy::{IntoPyArray, Ix1, PyArray, PyArrayMethods};
+use pyo3::{prelude::*, types::PyDict};
 #[cfg(feature = "stub_gen")]
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
 use rayon::prelude::*;
-use std::{num::NonZero, sync::Arc};
+use std::{collections::HashMap, num::NonZero, sync::Arc, time::Instant};
 
+/// Moment of the process at `duration`.
+///
+/// Central moments are accumulated online in a single simulation pass; `compensated`
+/// only applies to raw moments.
+///
+/// An `order` of 0 gives 1 without simulating. A negative `order` gives an inverse raw
+/// moment and requires every endpoint to be strictly positive; central moments need a
+/// non-negative order. A result that overflowed to `inf` or `nan` issues a `RuntimeWarning`.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (simulate_fn, central, order, duration, time_step, particles, compensated = true, skip_nonfinite = false))]
 pub fn moment(
     py: Python<'_>,
     simulate_fn: Py<PyAny>,
@@ -17,11 +35,62 @@ pub fn moment(
     duration: f64,
     time_step: f64,
     particles: usize,
-) -> XPyResult<f64> {
+    compensated: bool,
+    skip_nonfinite: bool,
+) -> PyResult<f64> {
+    let (result, _) = moment_diagnostic(
+        py,
+        simulate_fn,
+        central,
+        order,
+        duration,
+        time_step,
+        particles,
+        compensated,
+        skip_nonfinite,
+    )?;
+    warn_nonfinite_moment(order, result)?;
+    Ok(result)
+}
+
+/// Like `moment`, but also returns the number of particles whose contribution was non-finite.
+///
+/// For central moments these are the particles with a non-finite position at `duration`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (simulate_fn, central, order, duration, time_step, particles, compensated = true, skip_nonfinite = false))]
+pub fn moment_diagnostic(
+    py: Python<'_>,
+    simulate_fn: Py<PyAny>,
+    central: bool,
+    order: i32,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+    compensated: bool,
+    skip_nonfinite: bool,
+) -> XPyResult<(f64, usize)> {
     if central {
-        central_moment(py, simulate_fn, order, duration, time_step, particles)
+        central_moment(
+            py,
+            simulate_fn,
+            order,
+            duration,
+            time_step,
+            particles,
+            skip_nonfinite,
+        )
     } else {
-        raw_moment(py, simulate_fn, order, duration, time_step, particles)
+        raw_moment(
+            py,
+            simulate_fn,
+            order,
+            duration,
+            time_step,
+            particles,
+            compensated,
+            skip_nonfinite,
+        )
     }
 }
 
@@ -41,7 +110,7 @@ pub fn mean(
         .map(|_| endpoint(&simulate, duration, time_step))
         .collect();
 
-    Ok(values?.into_iter().sum::<f64>() / particles as f64)
+    Ok(summation::sum(values?, true) / particles as f64)
 }
 
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
@@ -69,40 +138,415 @@ pub fn msd(
         })
         .collect();
 
-    Ok(values?.into_iter().sum::<f64>() / particles as f64)
+    Ok(summation::sum(values?, true) / particles as f64)
 }
 
+/// Statistic at `duration` for each time step in `dt_list`, to study convergence as `dt -> 0`.
+///
+/// `statistic` is one of `mean`, `msd` and `raw_moment_2`. The global random stream of
+/// `diffusionx.random` is reset to the same state before each time step, so every time step
+/// hands the same set of seeds to its particles. This couples the runs only for simulators
+/// that draw each path from that stream, such as the Levy flight of `levy_simulate`;
+/// the upstream simulators (Brownian motion, OU, ...) use their own generators, so for them the
+/// runs stay independent and the differences include Monte Carlo noise of order
+/// `std / sqrt(particles)`.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
-pub fn tamsd(
+pub fn dt_convergence<'py>(
+    py: Python<'py>,
+    simulate_fn: Py<PyAny>,
+    duration: f64,
+    statistic: &str,
+    particles: usize,
+    dt_list: Vec<f64>,
+) -> XPyResult<Bound<'py, PyArray<f64, Ix1>>> {
+    if !matches!(statistic, "mean" | "msd" | "raw_moment_2") {
+        return Err(value_error(format!(
+            "statistic must be 'mean', 'msd' or 'raw_moment_2', got '{statistic}'"
+        )));
+    }
+    if particles == 0 {
+        return Err(value_error("particles must be positive"));
+    }
+    if dt_list.is_empty() {
+        return Err(value_error("dt_list must not be empty"));
+    }
+    for &time_step in &dt_list {
+        checked_num_steps(duration, time_step)?;
+    }
+
+    let state = stream_state();
+    let result = dt_list
+        .iter()
+        .map(|&time_step| {
+            set_stream_state(state);
+            let simulate_fn = simulate_fn.clone_ref(py);
+            match statistic {
+                "mean" => mean(py, simulate_fn, duration, time_step, particles),
+                "msd" => msd(py, simulate_fn, duration, time_step, particles),
+                _ => raw_moment(
+                    py,
+                    simulate_fn,
+                    2,
+                    duration,
+                    time_step,
+                    particles,
+                    true,
+                    false,
+                )
+                .map(|(value, _)| value),
+            }
+        })
+        .collect::<XPyResult<Vec<f64>>>()?;
+    Ok(result.into_pyarray(py))
+}
+
+/// Time-resolved anomalous diffusion exponent `d log MSD / d log t`.
+///
+/// The ensemble-averaged MSD `<(X(t) - X(0))^2>` is estimated on the simulation grid from
+/// `particles` paths, then the exponent is the least-squares slope of `log MSD` against
+/// `log t` over each run of `window` consecutive grid points with `t > 0`. The returned
+/// times are the geometric means of the windows, so crossovers, e.g. from ballistic to
+/// diffusive motion, show up directly. Windows with a vanishing MSD give NaN.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn local_scaling_exponent(
+    py: Python<'_>,
+    simulate_fn: Py<PyAny>,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+    window: usize,
+) -> XPyResult<PyArrayPair<'_>> {
+    checked_num_steps(duration, time_step)?;
+    if particles == 0 {
+        return Err(value_error("particles must be positive"));
+    }
+    if window < 2 {
+        return Err(value_error(format!(
+            "window must be at least 2, got {window}"
+        )));
+    }
+    let simulate = Arc::new(simulate_fn.clone_ref(py));
+    let (times, msd) = eamsd_curve(&simulate, duration, time_step, particles)?;
+    // 跳过 t = 0，在对数坐标下逐窗口做最小二乘
+    let points: Vec<(f64, f64)> = times
+        .iter()
+        .zip(&msd)
+        .filter(|(t, _)| **t > 0.0)
+        .map(|(t, m)| (t.ln(), m.ln()))
+        .collect();
+    if points.len() < window {
+        return Err(value_error(format!(
+            "the path has {} points with t > 0, fewer than window = {window}",
+            points.len()
+        )));
+    }
+    let (centers, exponents): (Vec<f64>, Vec<f64>) = points
+        .windows(window)
+        .map(|w| {
+            let n = window as f64;
+            let mean_x = w.iter().map(|p| p.0).sum::<f64>() / n;
+            let mean_y = w.iter().map(|p| p.1).sum::<f64>() / n;
+            let (cov, var) = w.iter().fold((0.0, 0.0), |(cov, var), &(x, y)| {
+                (
+                    cov + (x - mean_x) * (y - mean_y),
+                    var + (x - mean_x).powi(2),
+                )
+            });
+            let slope = if mean_y.is_finite() {
+                cov / var
+            } else {
+                f64::NAN
+            };
+            (mean_x.exp(), slope)
+        })
+        .unzip();
+    Ok(vec_to_pyarray(py, centers, exponents))
+}
+
+/// Simulate `X(t) = A(t) + B(t)` for two independent processes on the same grid.
+///
+/// Both objects must provide `simulate(duration, time_step)`; the paths are simulated
+/// independently and their positions summed. The times are those of `simulate_fn_a`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn add_processes(
+    py: Python<'_>,
+    simulate_fn_a: Py<PyAny>,
+    simulate_fn_b: Py<PyAny>,
+    duration: f64,
+    time_step: f64,
+) -> XPyResult<PyArrayPair<'_>> {
+    checked_num_steps(duration, time_step)?;
+    let (times, a) = simulate_path(&simulate_fn_a, duration, time_step)?;
+    let (_, b) = simulate_path(&simulate_fn_b, duration, time_step)?;
+    if a.len() != b.len() {
+        return Err(value_error(format!(
+            "the processes returned paths of different lengths, {} and {}",
+            a.len(),
+            b.len()
+        )));
+    }
+    let positions: Vec<f64> = a.iter().zip(&b).map(|(a, b)| a + b).collect();
+    Ok(vec_to_pyarray(py, times, positions))
+}
+
+/// Simulate `particles` paths of a built-in process and reduce them to one statistic.
+///
+/// The process is created as by `make_process(process_name, params)`. Each path is reduced
+/// inside the parallel loop and dropped at once, so no trajectory reaches Python. `reducer`
+/// is one of `mean_terminal`, `var_terminal` (population variance of `X(duration)`),
+/// `mean_max` (mean running maximum) and `fraction_positive_terminal`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn simulate_reduce(
+    process_name: &str,
+    params: Option<HashMap<String, f64>>,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+    reducer: &str,
+) -> XPyResult<f64> {
+    let reducer = Reducer::parse(reducer)?;
+    checked_num_steps(duration, time_step)?;
+    if particles == 0 {
+        return Err(value_error("particles must be positive"));
+    }
+    let process = make_process(process_name, params)?;
+    let values = (0..particles)
+        .into_par_iter()
+        .map(|_| -> XPyResult<f64> {
+            let (_, positions) = process.simulate_path(duration, time_step)?;
+            let terminal = positions.last().copied().unwrap_or(f64::NAN);
+            Ok(match reducer {
+                Reducer::MeanTerminal | Reducer::VarTerminal => terminal,
+                Reducer::MeanMax => positions.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                Reducer::FractionPositiveTerminal => {
+                    if terminal > 0.0 {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                }
+            })
+        })
+        .collect::<XPyResult<Vec<f64>>>()?;
+    let n = particles as f64;
+    if reducer != Reducer::VarTerminal {
+        return Ok(summation::sum(values, true) / n);
+    }
+    let mean = summation::sum(values.clone(), true) / n;
+    let squares = values.into_iter().map(|x| (x - mean).powi(2)).collect();
+    Ok(summation::sum(squares, true) / n)
+}
+
+/// Time `repeats` runs of a built-in process's second raw moment at `duration`.
+///
+/// The process is created as by `make_process(process_name, params)`. Each run simulates
+/// `particles` paths in parallel, keeping only their endpoints (the simulation phase), and
+/// then sums their squares (the reduction phase). Returns a dict with the wall-clock
+/// `mean`, `std` (population), `min` and `max` of a run in seconds, the overall
+/// `simulation_fraction` and `reduction_fraction`, `repeats`, and the last `moment`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn benchmark_simulation<'py>(
+    py: Python<'py>,
+    process_name: &str,
+    params: Option<HashMap<String, f64>>,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+    repeats: usize,
+) -> PyResult<Bound<'py, PyDict>> {
+    checked_num_steps(duration, time_step)?;
+    if particles == 0 || repeats == 0 {
+        return Err(value_error("particles and repeats must be positive").into());
+    }
+    let process = make_process(process_name, params)?;
+    let (mut runs, mut simulation, mut reduction) = (Vec::with_capacity(repeats), 0.0, 0.0);
+    let mut moment = f64::NAN;
+    for _ in 0..repeats {
+        let start = Instant::now();
+        let endpoints = (0..particles)
+            .into_par_iter()
+            .map(|_| -> XPyResult<f64> {
+                let (_, positions) = process.simulate_path(duration, time_step)?;
+                Ok(positions.last().copied().unwrap_or(f64::NAN))
+            })
+            .collect::<XPyResult<Vec<f64>>>()?;
+        let simulated = start.elapsed().as_secs_f64();
+        let squares = endpoints.into_iter().map(|x| x * x).collect();
+        moment = summation::sum(squares, true) / particles as f64;
+        let total = start.elapsed().as_secs_f64();
+        simulation += simulated;
+        reduction += total - simulated;
+        runs.push(total);
+    }
+    let mut timing = CentralMoments::new(2);
+    runs.iter().for_each(|&t| timing.add(t));
+    let elapsed = simulation + reduction;
+    let stats = PyDict::new(py);
+    stats.set_item("mean", timing.mean())?;
+    stats.set_item("std", timing.central_moment(2).sqrt())?;
+    stats.set_item("min", runs.iter().copied().fold(f64::INFINITY, f64::min))?;
+    stats.set_item(
+        "max",
+        runs.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+    )?;
+    stats.set_item("simulation_fraction", simulation / elapsed)?;
+    stats.set_item("reduction_fraction", reduction / elapsed)?;
+    stats.set_item("repeats", repeats)?;
+    stats.set_item("moment", moment)?;
+    Ok(stats)
+}
+
+/// Ensemble average of a Python path functional, `E[path_functional(t, x)]`.
+///
+/// `simulate_fn` must provide `simulate(duration, time_step)` as for `moment`, and
+/// `path_functional(times, positions)` maps one path to a float, and an exception it raises
+/// is propagated unchanged. The paths are requested in batches from a thread pool, but each
+/// `simulate` call re-acquires the GIL, so the simulation is not truly parallel unless
+/// `simulate` releases the GIL itself. `path_functional` also runs under the GIL one path at
+/// a time.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn ensemble_functional(
     py: Python<'_>,
+    simulate_fn: Py<PyAny>,
+    path_functional: Py<PyAny>,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+) -> PyResult<f64> {
+    // 每批并行模拟的轨迹数，限制同时驻留内存的轨迹条数
+    const BATCH: usize = 1024;
+    checked_num_steps(duration, time_step)?;
+    if particles == 0 {
+        return Err(value_error("particles must be positive").into());
+    }
+    let mut values = Vec::with_capacity(particles);
+    for start in (0..particles).step_by(BATCH) {
+        let count = BATCH.min(particles - start);
+        let paths = py.detach(|| {
+            (0..count)
+                .into_par_iter()
+                .map(|_| simulate_path(&simulate_fn, duration, time_step))
+                .collect::<XPyResult<Vec<_>>>()
+        })?;
+        for (times, positions) in paths {
+            let value = path_functional
+                .call1(py, (times.into_pyarray(py), positions.into_pyarray(py)))
+                .and_then(|value| value.extract::<f64>(py))?;
+            values.push(value);
+        }
+    }
+    Ok(summation::sum(values, true) / particles as f64)
+}
+
+/// `simulate_reduce` 在并行循环内计算的统计量
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Reducer {
+    MeanTerminal,
+    VarTerminal,
+    MeanMax,
+    FractionPositiveTerminal,
+}
+
+impl Reducer {
+    fn parse(reducer: &str) -> XPyResult<Self> {
+        match reducer {
+            "mean_terminal" => Ok(Self::MeanTerminal),
+            "var_terminal" => Ok(Self::VarTerminal),
+            "mean_max" => Ok(Self::MeanMax),
+            "fraction_positive_terminal" => Ok(Self::FractionPositiveTerminal),
+            _ => Err(value_error(format!(
+                "reducer must be one of 'mean_terminal', 'var_terminal', 'mean_max', \
+                 'fraction_positive_terminal', got '{reducer}'"
+            ))),
+        }
+    }
+}
+
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (simulate_fn, duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
+pub fn tamsd(
     simulate_fn: Py<PyAny>,
     duration: f64,
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     validate_tamsd_args(duration, delta, time_step, quad_order)?;
-    let simulate = Arc::new(simulate_fn.clone_ref(py));
+    let rule = QuadratureRule::parse(rule)?;
+    let nodes_weights = rule.nodes_weights(0.0, duration - delta, quad_order)?;
+    let (times, positions) = simulate_path(&simulate_fn, duration, time_step)?;
+    path_tamsd(&times, &positions, &nodes_weights, duration, delta)
+}
 
-    let legendre_quad = GaussLegendre::new(
-        NonZero::new(quad_order).ok_or_else(|| value_error("quad_order must be positive"))?,
-    );
-    let nodes_weights_pairs = legendre_quad.into_node_weight_pairs();
-    let nodes_weights = nodes_weights_transform(0.0, duration - delta, &nodes_weights_pairs);
-    let values: XPyResult<Vec<f64>> = nodes_weights
-        .into_par_iter()
-        .map(|(node, weight)| {
-            lagged_square_displacement(&simulate, node + delta, delta, time_step)
-                .map(|value| value * weight)
+/// TAMSD at `time_step` and at `time_step / f` for each `f` in `refinement_factors`.
+///
+/// Each entry is a single-path `tamsd` with Gauss–Legendre quadrature; a sequence that
+/// plateaus indicates that `time_step` is fine enough. All entries use one path simulated at
+/// `time_step / lcm(refinement_factors)`, and the coarser levels subsample it, so the
+/// differences between entries reflect the discretization and not the path-to-path spread.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn tamsd_convergence<'py>(
+    py: Python<'py>,
+    simulate_fn: Py<PyAny>,
+    duration: f64,
+    delta: f64,
+    time_step: f64,
+    refinement_factors: Vec<usize>,
+    quad_order: usize,
+) -> XPyResult<Bound<'py, PyArray<f64, Ix1>>> {
+    validate_tamsd_args(duration, delta, time_step, quad_order)?;
+    if refinement_factors.contains(&0) {
+        return Err(value_error("refinement_factors must be positive"));
+    }
+    // 各层级共用最细网格上的同一条轨迹，较粗的层级由它抽稀得到
+    let finest = refinement_factors
+        .iter()
+        .try_fold(1usize, |lcm, &factor| {
+            lcm.checked_mul(factor / gcd(lcm, factor))
         })
-        .collect();
+        .ok_or_else(|| value_error("the least common multiple of refinement_factors overflows"))?;
+    let fine_step = time_step / finest as f64;
+    checked_num_steps(duration, fine_step)?;
+    let nodes_weights =
+        QuadratureRule::GaussLegendre.nodes_weights(0.0, duration - delta, quad_order)?;
+    let (times, positions) = simulate_path(&simulate_fn, duration, fine_step)?;
 
-    Ok(values?.into_iter().sum::<f64>() / (duration - delta))
+    let result = std::iter::once(1)
+        .chain(refinement_factors.iter().copied())
+        .map(|factor| {
+            let stride = finest / factor;
+            path_tamsd(
+                &thin_path(times.clone(), stride),
+                &thin_path(positions.clone(), stride),
+                &nodes_weights,
+                duration,
+                delta,
+            )
+        })
+        .collect::<XPyResult<Vec<f64>>>()?;
+    Ok(result.into_pyarray(py))
+}
+
+/// 最大公约数
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
 }
 
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (simulate_fn, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn eatamsd(
     py: Python<'_>,
     simulate_fn: Py<PyAny>,
@@ -111,32 +555,258 @@ pub fn eatamsd(
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     validate_tamsd_args(duration, delta, time_step, quad_order)?;
+    let rule = QuadratureRule::parse(rule)?;
     let simulate = Arc::new(simulate_fn.clone_ref(py));
+    let nodes_weights = rule.nodes_weights(0.0, duration - delta, quad_order)?;
 
-    let values: XPyResult<Vec<f64>> = (0..particles)
+    let values: XPyResult<Vec<f64>> = py.detach(|| {
+        (0..particles)
+            .into_par_iter()
+            .map(|_| {
+                let (times, positions) = simulate_path(&simulate, duration, time_step)?;
+                path_tamsd(&times, &positions, &nodes_weights, duration, delta)
+            })
+            .collect()
+    });
+
+    Ok(summation::sum(values?, true) / particles as f64)
+}
+
+/// TAMSD with a selectable quadrature rule for the start-time integral.
+///
+/// `gauss_legendre` defers to the upstream implementation `upstream`; the uniform-grid
+/// rules simulate one path over `duration` with `simulate` and evaluate every node on it.
+pub(crate) fn tamsd_with_rule<U, S>(
+    rule: &str,
+    upstream: U,
+    simulate: S,
+    duration: f64,
+    delta: f64,
+    time_step: f64,
+    quad_order: usize,
+) -> XPyResult<f64>
+where
+    U: FnOnce() -> XResult<f64>,
+    S: Fn(f64) -> XResult<(Vec<f64>, Vec<f64>)> + Sync,
+{
+    let rule = QuadratureRule::parse(rule)?;
+    if rule == QuadratureRule::GaussLegendre {
+        return Ok(upstream()?);
+    }
+    validate_tamsd_args(duration, delta, time_step, quad_order)?;
+    let nodes_weights = rule.nodes_weights(0.0, duration - delta, quad_order)?;
+    single_tamsd(&simulate, &nodes_weights, duration, delta)
+}
+
+/// Ensemble-averaged TAMSD with a selectable quadrature rule.
+///
+/// This is the mean of the per-particle samples of `tamsd_samples_with_rule`, with
+/// `upstream` drawing one single-path TAMSD for `gauss_legendre`.
+pub(crate) fn eatamsd_with_rule<U, S>(
+    rule: &str,
+    upstream: U,
+    simulate: S,
+    duration: f64,
+    delta: f64,
+    particles: usize,
+    time_step: f64,
+    quad_order: usize,
+) -> XPyResult<f64>
+where
+    U: Fn() -> XResult<f64> + Sync,
+    S: Fn(f64) -> XResult<(Vec<f64>, Vec<f64>)> + Sync,
+{
+    if particles == 0 {
+        return Err(value_error("particles must be positive"));
+    }
+    let samples = tamsd_samples_with_rule(
+        rule, upstream, simulate, duration, delta, particles, time_step, quad_order,
+    )?;
+    Ok(summation::sum(samples, true) / particles as f64)
+}
+
+/// Independent TAMSD samples, one per particle, with a selectable quadrature rule.
+///
+/// `gauss_legendre` draws each sample with the upstream single-path TAMSD `upstream`;
+/// the uniform-grid rules simulate one path per sample with `simulate`.
+pub(crate) fn tamsd_samples_with_rule<U, S>(
+    rule: &str,
+    upstream: U,
+    simulate: S,
+    duration: f64,
+    delta: f64,
+    particles: usize,
+    time_step: f64,
+    quad_order: usize,
+) -> XPyResult<Vec<f64>>
+where
+    U: Fn() -> XResult<f64> + Sync,
+    S: Fn(f64) -> XResult<(Vec<f64>, Vec<f64>)> + Sync,
+{
+    let rule = QuadratureRule::parse(rule)?;
+    validate_tamsd_args(duration, delta, time_step, quad_order)?;
+    if rule == QuadratureRule::GaussLegendre {
+        return (0..particles)
+            .into_par_iter()
+            .map(|_| Ok(upstream()?))
+            .collect();
+    }
+    let nodes_weights = rule.nodes_weights(0.0, duration - delta, quad_order)?;
+    (0..particles)
         .into_par_iter()
-        .map(|_| {
-            let legendre_quad = GaussLegendre::new(
-                NonZero::new(quad_order)
-                    .ok_or_else(|| value_error("quad_order must be positive"))?,
-            );
-            let nodes_weights_pairs = legendre_quad.into_node_weight_pairs();
-            let nodes_weights =
-                nodes_weights_transform(0.0, duration - delta, &nodes_weights_pairs);
-            let values: XPyResult<Vec<f64>> = nodes_weights
-                .into_par_iter()
-                .map(|(node, weight)| {
-                    lagged_square_displacement(&simulate, node + delta, delta, time_step)
-                        .map(|value| value * weight)
-                })
-                .collect();
-            Ok(values?.into_iter().sum::<f64>() / (duration - delta))
+        .map(|_| single_tamsd(&simulate, &nodes_weights, duration, delta))
+        .collect()
+}
+
+/// TAMSD of a single trajectory for every lag in `deltas`.
+///
+/// One path over `duration` is simulated with `simulate` and reused for all lags; the
+/// start-time integral uses `rule`, with the path linearly interpolated at each node.
+pub(crate) fn tamsd_curve_with_rule<S>(
+    rule: &str,
+    simulate: S,
+    duration: f64,
+    deltas: &[f64],
+    time_step: f64,
+    quad_order: usize,
+) -> XPyResult<Vec<f64>>
+where
+    S: FnOnce(f64) -> XResult<(Vec<f64>, Vec<f64>)>,
+{
+    let rule = QuadratureRule::parse(rule)?;
+    for &delta in deltas {
+        validate_tamsd_args(duration, delta, time_step, quad_order)?;
+    }
+    let (times, positions) = simulate(duration)?;
+    deltas
+        .par_iter()
+        .map(|&delta| -> XPyResult<f64> {
+            let nodes_weights = rule.nodes_weights(0.0, duration - delta, quad_order)?;
+            path_tamsd(&times, &positions, &nodes_weights, duration, delta)
+        })
+        .collect()
+}
+
+/// 在时间网格 `times` 上对轨迹做线性插值，超出范围时取端点值
+fn interpolate(times: &[f64], positions: &[f64], t: f64) -> f64 {
+    let index = times.partition_point(|&s| s <= t);
+    if index == 0 {
+        return positions[0];
+    }
+    if index == times.len() {
+        return positions[index - 1];
+    }
+    let (t0, t1) = (times[index - 1], times[index]);
+    let (x0, x1) = (positions[index - 1], positions[index]);
+    x0 + (x1 - x0) * (t - t0) / (t1 - t0)
+}
+
+/// 单条 TAMSD：模拟一条长为 `duration` 的轨迹，所有求积节点都在这条轨迹上求值
+fn single_tamsd<S>(
+    simulate: &S,
+    nodes_weights: &[(f64, f64)],
+    duration: f64,
+    delta: f64,
+) -> XPyResult<f64>
+where
+    S: Fn(f64) -> XResult<(Vec<f64>, Vec<f64>)>,
+{
+    let (times, positions) = simulate(duration)?;
+    path_tamsd(&times, &positions, nodes_weights, duration, delta)
+}
+
+/// 给定轨迹上的 TAMSD，节点 `s` 与 `s + delta` 处的位置由线性插值给出
+fn path_tamsd(
+    times: &[f64],
+    positions: &[f64],
+    nodes_weights: &[(f64, f64)],
+    duration: f64,
+    delta: f64,
+) -> XPyResult<f64> {
+    if times.len() != positions.len() || times.len() < 2 {
+        return Err(value_error("simulate returned too few positions"));
+    }
+    let values: Vec<f64> = nodes_weights
+        .iter()
+        .map(|&(node, weight)| {
+            let displacement =
+                interpolate(times, positions, node + delta) - interpolate(times, positions, node);
+            displacement * displacement * weight
         })
         .collect();
+    Ok(summation::sum(values, true) / (duration - delta))
+}
+
+/// 起始时刻积分所用的求积规则
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum QuadratureRule {
+    GaussLegendre,
+    Trapezoid,
+    Simpson,
+}
 
-    Ok(values?.into_iter().sum::<f64>() / particles as f64)
+impl QuadratureRule {
+    pub(crate) fn parse(rule: &str) -> XPyResult<Self> {
+        match rule {
+            "gauss_legendre" => Ok(Self::GaussLegendre),
+            "trapezoid" => Ok(Self::Trapezoid),
+            "simpson" => Ok(Self::Simpson),
+            _ => Err(value_error(format!(
+                "rule must be one of 'gauss_legendre', 'trapezoid', 'simpson', got '{rule}'"
+            ))),
+        }
+    }
+
+    /// `[a, b]` 上的节点与权重；梯形与 Simpson 规则使用 `order` 个等距节点
+    fn nodes_weights(self, a: f64, b: f64, order: usize) -> XPyResult<Vec<(f64, f64)>> {
+        match self {
+            Self::GaussLegendre => {
+                let legendre_quad = GaussLegendre::new(
+                    NonZero::new(order)
+                        .ok_or_else(|| value_error("quad_order must be positive"))?,
+                );
+                let nodes_weights_pairs = legendre_quad.into_node_weight_pairs();
+                Ok(nodes_weights_transform(a, b, &nodes_weights_pairs))
+            }
+            Self::Trapezoid => {
+                if order < 2 {
+                    return Err(value_error(
+                        "quad_order must be at least 2 for the trapezoid rule",
+                    ));
+                }
+                let h = (b - a) / (order - 1) as f64;
+                Ok((0..order)
+                    .map(|i| {
+                        let weight = if i == 0 || i == order - 1 { h / 2.0 } else { h };
+                        (a + i as f64 * h, weight)
+                    })
+                    .collect())
+            }
+            Self::Simpson => {
+                if order < 3 || order.is_multiple_of(2) {
+                    return Err(value_error(
+                        "quad_order must be an odd number of at least 3 for the Simpson rule",
+                    ));
+                }
+                let h = (b - a) / (order - 1) as f64;
+                Ok((0..order)
+                    .map(|i| {
+                        let factor = if i == 0 || i == order - 1 {
+                            1.0
+                        } else if i % 2 == 1 {
+                            4.0
+                        } else {
+                            2.0
+                        };
+                        (a + i as f64 * h, factor * h / 3.0)
+                    })
+                    .collect())
+            }
+        }
+    }
 }
 
 fn raw_moment(
@@ -146,20 +816,33 @@ fn raw_moment(
     duration: f64,
     time_step: f64,
     particles: usize,
-) -> XPyResult<f64> {
+    compensated: bool,
+    skip_nonfinite: bool,
+) -> XPyResult<(f64, usize)> {
+    if order == 0 {
+        return Ok((1.0, 0));
+    }
     let simulate = Arc::new(simulate_fn.clone_ref(py));
 
     let values: XPyResult<Vec<f64>> = (0..particles)
         .into_par_iter()
         .map(|_| {
             let end = endpoint(&simulate, duration, time_step)?;
+            // 负阶矩只对严格为正的过程有定义，`powi` 对非正值会静默给出 inf 或错误符号
+            if order < 0 && end <= 0.0 {
+                return Err(value_error(format!(
+                    "negative order {order} needs strictly positive values, got {end}"
+                )));
+            }
             Ok(if order == 1 { end } else { end.powi(order) })
         })
         .collect();
 
-    Ok(values?.into_iter().sum::<f64>() / particles as f64)
+    Ok(summation::mean(values?, compensated, skip_nonfinite))
 }
 
+/// 单次模拟中用 `CentralMoments` 在线累积中心矩，各线程的累积量最后合并；
+/// 非有限的末点不参与累积，未跳过时结果为 `NaN`
 fn central_moment(
     py: Python<'_>,
     simulate_fn: Py<PyAny>,
@@ -167,44 +850,120 @@ fn central_moment(
     duration: f64,
     time_step: f64,
     particles: usize,
-) -> XPyResult<f64> {
-    let mean = raw_moment(
-        py,
-        simulate_fn.clone_ref(py),
-        1,
-        duration,
-        time_step,
-        particles,
-    )?;
-
+    skip_nonfinite: bool,
+) -> XPyResult<(f64, usize)> {
+    let Ok(order) = usize::try_from(order) else {
+        return Err(value_error(format!(
+            "central moments need a non-negative order, got {order}"
+        )));
+    };
+    if order == 0 {
+        return Ok((1.0, 0));
+    }
     let simulate = Arc::new(simulate_fn.clone_ref(py));
 
-    let values: XPyResult<Vec<f64>> = (0..particles)
+    let (moments, nonfinite) = (0..particles)
         .into_par_iter()
-        .map(|_| {
-            let end = endpoint(&simulate, duration, time_step)?;
-            Ok(if order == 1 {
-                end - mean
-            } else {
-                (end - mean).powi(order)
-            })
-        })
-        .collect();
+        .map(|_| endpoint(&simulate, duration, time_step))
+        .try_fold(
+            || (CentralMoments::new(order), 0),
+            |(mut moments, mut nonfinite), end| {
+                let end = end?;
+                if end.is_finite() {
+                    moments.add(end);
+                } else {
+                    nonfinite += 1;
+                }
+                XPyResult::Ok((moments, nonfinite))
+            },
+        )
+        .try_reduce(
+            || (CentralMoments::new(order), 0),
+            |(left, a), (right, b)| Ok((left.merge(right), a + b)),
+        )?;
 
-    Ok(values?.into_iter().sum::<f64>() / particles as f64)
+    let result = if nonfinite > 0 && !skip_nonfinite {
+        f64::NAN
+    } else {
+        moments.central_moment(order)
+    };
+    Ok((result, nonfinite))
+}
+
+/// 集合平均 MSD 曲线 `<(X(t_k) - X(0))^2>`，各轨迹须长度一致
+fn eamsd_curve(
+    simulate: &Py<PyAny>,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+) -> XPyResult<(Vec<f64>, Vec<f64>)> {
+    // 第一条轨迹同时确定时间网格
+    let (times, first) = simulate_path(simulate, duration, time_step)?;
+    let points = times.len();
+    if points == 0 || first.len() != points {
+        return Err(value_error(
+            "simulate returned no positions or mismatched times and positions",
+        ));
+    }
+    let total = (1..particles)
+        .into_par_iter()
+        .map(|_| simulate_positions(simulate, duration, time_step))
+        .try_fold(
+            || vec![0.0; points],
+            |mut total, x_vec| {
+                let x_vec = x_vec?;
+                if x_vec.len() != points {
+                    return Err(value_error(format!(
+                        "simulate returned paths of different lengths, {points} and {}",
+                        x_vec.len()
+                    )));
+                }
+                let start = x_vec[0];
+                total
+                    .iter_mut()
+                    .zip(&x_vec)
+                    .for_each(|(sum, x)| *sum += (x - start) * (x - start));
+                Ok(total)
+            },
+        )
+        .try_reduce(
+            || vec![0.0; points],
+            |mut left, right| {
+                left.iter_mut().zip(right).for_each(|(l, r)| *l += r);
+                Ok(left)
+            },
+        )?;
+    let msd = total
+        .into_iter()
+        .zip(&first)
+        .map(|(sum, x)| (sum + (x - first[0]) * (x - first[0])) / particles as f64)
+        .collect();
+    Ok((times, msd))
 }
 
 fn simulate_positions(simulate: &Py<PyAny>, duration: f64, time_step: f64) -> XPyResult<Vec<f64>> {
+    simulate_path(simulate, duration, time_step).map(|(_, x)| x)
+}
+
+fn simulate_path(
+    simulate: &Py<PyAny>,
+    duration: f64,
+    time_step: f64,
+) -> XPyResult<(Vec<f64>, Vec<f64>)> {
     Python::attach(|py| {
-        let (_, x) = simulate
+        let (t, x) = simulate
             .call_method1(py, "simulate", (duration, time_step))
             .map_err(|error| value_error(format!("Failed to call simulate method: {error}")))?
             .extract::<PyArrayPair<'_>>(py)
             .map_err(|error| value_error(format!("Failed to extract simulate result: {error}")))?;
 
-        x.to_vec().map_err(|error| {
+        let t = t.to_vec().map_err(|error| {
+            value_error(format!("Failed to convert time array to Vec: {error}"))
+        })?;
+        let x = x.to_vec().map_err(|error| {
             value_error(format!("Failed to convert position array to Vec: {error}"))
-        })
+        })?;
+        Ok((t, x))
     })
 }
 
@@ -215,25 +974,6 @@ fn endpoint(simulate: &Py<PyAny>, duration: f64, time_step: f64) -> XPyResult<f6
         .ok_or_else(|| value_error("simulate returned no positions"))
 }
 
-fn lagged_square_displacement(
-    simulate: &Py<PyAny>,
-    duration: f64,
-    delta: f64,
-    time_step: f64,
-) -> XPyResult<f64> {
-    let x_vec = simulate_positions(simulate, duration, time_step)?;
-    let lag_length = (delta / time_step).ceil() as usize;
-    let lag_index = x_vec.len().checked_sub(lag_length + 1).ok_or_else(|| {
-        value_error("simulate returned too few positions for the requested delta")
-    })?;
-    let end_position = *x_vec
-        .last()
-        .ok_or_else(|| value_error("simulate returned no positions"))?;
-    let lag_position = x_vec[lag_index];
-
-    Ok((end_position - lag_position) * (end_position - lag_position))
-}
-
 fn validate_tamsd_args(
     duration: f64,
     delta: f64,
@@ -0,0 +1,101 @@
+use pyo3::prelude::*;
+#[cfg(feature = "stub_gen")]
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+
+/// Whether the extension was built with the `parquet` feature.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn has_parquet() -> bool {
+    cfg!(feature = "parquet")
+}
+
+#[cfg(feature = "parquet")]
+pub(crate) use parquet_writer::write_ensemble_parquet;
+
+#[cfg(feature = "parquet")]
+mod parquet_writer {
+    use crate::{XPyError, XPyResult};
+    use diffusionx::XResult;
+    use parquet::{
+        data_type::{DataType, DoubleType, Int64Type},
+        file::{
+            properties::WriterProperties,
+            writer::{SerializedFileWriter, SerializedRowGroupWriter},
+        },
+        schema::parser::parse_message_type,
+    };
+    use rayon::prelude::*;
+    use std::{fs::File, iter, sync::Arc};
+
+    /// Number of particles simulated and flushed per row group.
+    const ROW_GROUP_PARTICLES: usize = 256;
+
+    const SCHEMA: &str = "
+        message ensemble {
+            REQUIRED INT64 particle_id;
+            REQUIRED DOUBLE time;
+            REQUIRED DOUBLE position;
+        }
+    ";
+
+    /// 分批并行模拟轨迹并逐个行组写入 Parquet 文件，返回写入的行数
+    pub(crate) fn write_ensemble_parquet<F>(
+        path: &str,
+        particles: usize,
+        simulate: F,
+    ) -> XPyResult<usize>
+    where
+        F: Fn() -> XResult<(Vec<f64>, Vec<f64>)> + Sync,
+    {
+        let schema = Arc::new(parse_message_type(SCHEMA).map_err(io_error)?);
+        let file = File::create(path).map_err(io_error)?;
+        let properties = Arc::new(WriterProperties::builder().build());
+        let mut writer = SerializedFileWriter::new(file, schema, properties).map_err(io_error)?;
+
+        let mut rows = 0;
+        for start in (0..particles).step_by(ROW_GROUP_PARTICLES) {
+            let end = (start + ROW_GROUP_PARTICLES).min(particles);
+            let paths = (start..end)
+                .into_par_iter()
+                .map(|_| simulate())
+                .collect::<XResult<Vec<_>>>()?;
+
+            let mut ids = Vec::new();
+            let mut times = Vec::new();
+            let mut positions = Vec::new();
+            for (id, (t, x)) in (start..end).zip(paths) {
+                ids.extend(iter::repeat_n(id as i64, t.len()));
+                times.extend(t);
+                positions.extend(x);
+            }
+
+            let mut row_group = writer.next_row_group().map_err(io_error)?;
+            write_column::<Int64Type>(&mut row_group, &ids)?;
+            write_column::<DoubleType>(&mut row_group, &times)?;
+            write_column::<DoubleType>(&mut row_group, &positions)?;
+            row_group.close().map_err(io_error)?;
+            rows += ids.len();
+        }
+        writer.close().map_err(io_error)?;
+        Ok(rows)
+    }
+
+    fn write_column<T: DataType>(
+        row_group: &mut SerializedRowGroupWriter<'_, File>,
+        values: &[T::T],
+    ) -> XPyResult<()> {
+        let mut column = row_group
+            .next_column()
+            .map_err(io_error)?
+            .ok_or_else(|| XPyError::IoError("Parquet schema is missing a column".to_string()))?;
+        column
+            .typed::<T>()
+            .write_batch(values, None, None)
+            .map_err(io_error)?;
+        column.close().map_err(io_error)
+    }
+
+    fn io_error<E: std::fmt::Display>(error: E) -> XPyError {
+        XPyError::IoError(error.to_string())
+    }
+}
@@ -1,12 +1,40 @@
 #![allow(clippy::too_many_arguments)]
 
-use numpy::{IntoPyArray, Ix1, PyArray};
-use pyo3::prelude::*;
+use crate::{XPyError, XPyResult};
+use diffusionx::XResult;
+use numpy::{
+    IntoPyArray, Ix1, Ix2, PyArray, PyArrayDescrMethods, PyUntypedArrayMethods, dtype,
+    ndarray::{Array2, Dimension},
+};
+use pyo3::{exceptions::PyRuntimeWarning, prelude::*};
+#[cfg(feature = "stub_gen")]
+use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use rayon::prelude::*;
+use std::{
+    ffi::CString,
+    sync::{
+        LazyLock, Mutex, PoisonError,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
 
 mod continuous;
 pub use continuous::*;
+mod export;
+pub use export::has_parquet;
 mod processes;
 pub use processes::*;
+mod registry;
+pub(crate) use registry::Kind;
+pub use registry::{
+    Process, make_process, process_central_moment, process_fpt, process_fpt_central_moment,
+    process_fpt_raw_moment, process_occupation_time, process_occupation_time_central_moment,
+    process_occupation_time_raw_moment, process_raw_moment,
+};
+pub(crate) mod summation;
+mod units;
+pub(crate) use units::DiffusiveUnits;
+pub use units::UnitArray;
 
 /// 封装从Python调用函数的辅助方法，处理错误情况
 pub(crate) fn call_py_func(func: &Py<PyAny>, args: (f64, f64)) -> f64 {
@@ -22,6 +50,104 @@ pub(crate) fn call_py_func(func: &Py<PyAny>, args: (f64, f64)) -> f64 {
     }
 }
 
+/// 调用单参数 Python 函数 `t -> f64`，异常原样向上传递
+pub(crate) fn call_py_func1(py: Python<'_>, func: &Py<PyAny>, arg: f64) -> PyResult<f64> {
+    func.call1(py, (arg,))?.extract::<f64>(py)
+}
+
+/// 上游模拟器的系数闭包无法返回错误：`call` 出错时记下第一个 Python 异常并返回 NaN，
+/// 模拟结束后由 `check` 原样抛出
+#[derive(Default)]
+pub(crate) struct PyErrSlot(Mutex<Option<PyErr>>);
+
+impl PyErrSlot {
+    /// 调用双参数 Python 函数 `(x, t) -> f64`
+    pub(crate) fn call(&self, func: &Py<PyAny>, args: (f64, f64)) -> f64 {
+        let result =
+            Python::attach(|py| func.call1(py, args).and_then(|result| result.extract(py)));
+        result.unwrap_or_else(|error| {
+            self.0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get_or_insert(error);
+            f64::NAN
+        })
+    }
+
+    /// 若回调中出现过异常则返回第一个异常
+    pub(crate) fn check(&self) -> PyResult<()> {
+        let error = self.0.lock().unwrap_or_else(PoisonError::into_inner).take();
+        error.map_or(Ok(()), Err)
+    }
+}
+
+/// Default cap on the number of grid points of a single simulated path.
+const DEFAULT_MAX_POINTS: usize = 100_000_000;
+
+/// 单条轨迹网格点数上限，可由环境变量 `DIFFUSIONX_MAX_POINTS` 或 `set_max_points` 设置
+static MAX_POINTS: LazyLock<AtomicUsize> = LazyLock::new(|| {
+    let max_points = std::env::var("DIFFUSIONX_MAX_POINTS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(DEFAULT_MAX_POINTS);
+    AtomicUsize::new(max_points)
+});
+
+/// Set the maximum number of grid points a single simulated path may have.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn set_max_points(max_points: usize) -> XPyResult<()> {
+    if max_points < 2 {
+        return Err(XPyError::ValueError(format!(
+            "max_points must be at least 2, got {max_points}"
+        )));
+    }
+    MAX_POINTS.store(max_points, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Get the maximum number of grid points a single simulated path may have.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn get_max_points() -> usize {
+    MAX_POINTS.load(Ordering::Relaxed)
+}
+
+/// 校验 `duration` 与 `time_step`，返回步数 `ceil(duration / time_step)`；
+/// 若网格点数溢出或超过上限则返回错误，避免静默分配巨量内存
+pub(crate) fn checked_num_steps(duration: f64, time_step: f64) -> XPyResult<usize> {
+    if !(time_step > 0.0 && time_step.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "time_step must be positive and finite, got {time_step}"
+        )));
+    }
+    if !(duration > 0.0 && duration.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "duration must be positive and finite, got {duration}"
+        )));
+    }
+    let steps = (duration / time_step).ceil();
+    let max_points = get_max_points();
+    if !steps.is_finite() || steps >= max_points as f64 {
+        return Err(XPyError::ValueError(format!(
+            "duration / time_step = {steps:e} steps exceeds the limit of {max_points} grid points; \
+             increase time_step or raise the limit with set_max_points"
+        )));
+    }
+    Ok(steps as usize)
+}
+
+/// Get the number of grid points of a path simulated with `duration` and `time_step`.
+///
+/// The grid is `0, time_step, 2 * time_step, ...` with a final, possibly shorter, step
+/// ending exactly at `duration`, i.e. `ceil(duration / time_step) + 1` points. This is the
+/// `endpoint="extend"` policy of the simulators that accept one.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn num_grid_points(duration: f64, time_step: f64) -> XPyResult<usize> {
+    Ok(checked_num_steps(duration, time_step)? + 1)
+}
+
 pub(crate) type PyArrayPair<'py> = (Bound<'py, PyArray<f64, Ix1>>, Bound<'py, PyArray<f64, Ix1>>);
 
 pub(crate) fn vec_to_pyarray(py: Python, time: Vec<f64>, position: Vec<f64>) -> PyArrayPair {
@@ -30,3 +156,347 @@ pub(crate) fn vec_to_pyarray(py: Python, time: Vec<f64>, position: Vec<f64>) ->
 
     (time_array, position_array)
 }
+
+/// 将按行排列的 `values` 转为 `rows × columns` 的 NumPy 数组；`from_shape_vec` 按 C 顺序
+/// 解释数据，因此结果总是 C 连续的 `float64` 数组
+pub(crate) fn vec_to_pyarray2(
+    py: Python<'_>,
+    rows: usize,
+    columns: usize,
+    values: Vec<f64>,
+) -> XPyResult<Bound<'_, PyArray<f64, Ix2>>> {
+    let array = Array2::from_shape_vec((rows, columns), values)
+        .map_err(|e| XPyError::ValueError(e.to_string()))?;
+    Ok(array.into_pyarray(py))
+}
+
+/// 校验返回给 Python 的数组是 C 连续的 `float64` 数组
+pub(crate) fn checked_output<D: Dimension>(array: &Bound<'_, PyArray<f64, D>>) -> XPyResult<()> {
+    let descr = array.dtype();
+    if !array.is_c_contiguous() || !descr.is_equiv_to(&dtype::<f64>(array.py())) {
+        return Err(XPyError::ValueError(format!(
+            "output array must be C-contiguous float64, got dtype {descr} with strides {:?}",
+            array.strides()
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) type PyArrayTriple<'py> = (
+    Bound<'py, PyArray<f64, Ix1>>,
+    Bound<'py, PyArray<f64, Ix1>>,
+    Bound<'py, PyArray<f64, Ix1>>,
+);
+
+/// 计算轨迹的累积最大值 M(t) = max_{s<=t} X(s)
+pub(crate) fn running_max(positions: &[f64]) -> Vec<f64> {
+    positions
+        .iter()
+        .scan(f64::NEG_INFINITY, |max, &x| {
+            *max = max.max(x);
+            Some(*max)
+        })
+        .collect()
+}
+
+/// 当 `time_step` 不整除 `duration` 时末端的处理方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Endpoint {
+    /// 在 `duration` 之前的最后一个完整步处停止
+    Clip,
+    /// 追加一个缩短的末步，恰好落在 `duration`
+    Extend,
+}
+
+impl Endpoint {
+    pub(crate) fn parse(endpoint: &str) -> XPyResult<Self> {
+        match endpoint {
+            "clip" => Ok(Self::Clip),
+            "extend" => Ok(Self::Extend),
+            _ => Err(XPyError::ValueError(format!(
+                "endpoint must be 'clip' or 'extend', got '{endpoint}'"
+            ))),
+        }
+    }
+}
+
+/// 按末端策略模拟：先用 `simulate` 模拟全部完整步，时间对齐到 `k * time_step`；
+/// `Extend` 再用 `advance(x, h)` 从末位置 `x` 真实模拟一段长为 `h = duration - k * time_step`
+/// 的缩短末步，取其末点并落在 `duration`
+pub(crate) fn simulate_with_endpoint<S, A>(
+    simulate: S,
+    advance: A,
+    duration: f64,
+    time_step: f64,
+    endpoint: Endpoint,
+) -> XPyResult<(Vec<f64>, Vec<f64>)>
+where
+    S: FnOnce(f64) -> XResult<(Vec<f64>, Vec<f64>)>,
+    A: FnOnce(f64, f64) -> XResult<(Vec<f64>, Vec<f64>)>,
+{
+    checked_num_steps(duration, time_step)?;
+    let ratio = duration / time_step;
+    // 容忍浮点误差：如 1.0 / 0.1 = 9.999...
+    let divides = (ratio - ratio.round()).abs() <= 1e-9 * ratio.max(1.0);
+    let full_steps = if divides {
+        ratio.round() as usize
+    } else {
+        ratio.floor() as usize
+    };
+    if endpoint == Endpoint::Clip && full_steps == 0 {
+        return Err(XPyError::ValueError(format!(
+            "time_step {time_step} exceeds duration {duration}, no full step to clip to"
+        )));
+    }
+    // 没有完整步时只需要起点
+    let full_end = full_steps as f64 * time_step;
+    let (mut times, mut positions) = simulate(if full_steps == 0 { duration } else { full_end })?;
+    times.truncate(full_steps + 1);
+    positions.truncate(full_steps + 1);
+    for (k, t) in times.iter_mut().enumerate() {
+        *t = k as f64 * time_step;
+    }
+    if endpoint == Endpoint::Extend {
+        if divides {
+            // 只修正整除时 `k * time_step` 与 `duration` 的舍入差
+            if let Some(last) = times.last_mut() {
+                *last = duration;
+            }
+        } else {
+            let last = *positions
+                .last()
+                .ok_or_else(|| XPyError::ValueError("simulation returned no positions".into()))?;
+            let remainder = duration - full_end;
+            let (_, tail) = advance(last, remainder)?;
+            let end = *tail
+                .last()
+                .ok_or_else(|| XPyError::ValueError("simulation returned no positions".into()))?;
+            times.push(duration);
+            positions.push(end);
+        }
+    }
+    Ok((times, positions))
+}
+
+/// 校验尺度类参数为正且有限，错误信息给出参数名与取值
+pub(crate) fn checked_positive(name: &str, value: f64) -> XPyResult<f64> {
+    if !(value > 0.0 && value.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "{name} must be positive, got {value}"
+        )));
+    }
+    Ok(value)
+}
+
+/// 校验参数为有限值，错误信息给出参数名与取值
+pub(crate) fn checked_finite(name: &str, value: f64) -> XPyResult<f64> {
+    if !value.is_finite() {
+        return Err(XPyError::ValueError(format!(
+            "{name} must be finite, got {value}"
+        )));
+    }
+    Ok(value)
+}
+
+/// 校验时间轴起点 `t0` 为有限值
+pub(crate) fn checked_t0(t0: f64) -> XPyResult<f64> {
+    if !t0.is_finite() {
+        return Err(XPyError::ValueError(format!("t0 must be finite, got {t0}")));
+    }
+    Ok(t0)
+}
+
+/// 校验抽稀间隔 `thin >= 1`
+pub(crate) fn checked_thin(thin: usize) -> XPyResult<usize> {
+    if thin == 0 {
+        return Err(XPyError::ValueError("thin must be at least 1".to_string()));
+    }
+    Ok(thin)
+}
+
+/// 校验原点矩的阶数：负阶（逆矩）只对严格为正的过程有定义；
+/// 0 阶矩恒为 1，返回 `Some(1.0)` 以跳过模拟
+pub(crate) fn trivial_raw_moment(order: i32, positive: bool) -> XPyResult<Option<f64>> {
+    if order < 0 && !positive {
+        return Err(XPyError::ValueError(format!(
+            "negative order {order} is only supported for strictly positive processes"
+        )));
+    }
+    Ok((order == 0).then_some(1.0))
+}
+
+/// 校验中心矩的阶数非负；0 阶中心矩恒为 1，返回 `Some(1.0)` 以跳过模拟
+pub(crate) fn trivial_central_moment(order: i32) -> XPyResult<Option<f64>> {
+    if order < 0 {
+        return Err(XPyError::ValueError(format!(
+            "central moments need a non-negative order, got {order}"
+        )));
+    }
+    Ok((order == 0).then_some(1.0))
+}
+
+/// 每隔 `thin` 个点保留一个，并总是保留首末两点
+pub(crate) fn thin_path(values: Vec<f64>, thin: usize) -> Vec<f64> {
+    if thin <= 1 || values.len() <= 2 {
+        return values;
+    }
+    let last = values.len() - 1;
+    values
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| i % thin == 0 || *i == last)
+        .map(|(_, value)| value)
+        .collect()
+}
+
+/// 将时间轴整体平移 `t0`
+pub(crate) fn shift_times(mut times: Vec<f64>, t0: f64) -> Vec<f64> {
+    if t0 != 0.0 {
+        times.iter_mut().for_each(|t| *t += t0);
+    }
+    times
+}
+
+/// 校验区域 `(a, b)`：端点不能为 NaN 且 `a < b`，允许无穷端点表示半无限区域
+pub(crate) fn checked_domain(domain: (f64, f64)) -> XPyResult<(f64, f64)> {
+    let (a, b) = domain;
+    if !(a < b) {
+        return Err(XPyError::ValueError(format!(
+            "domain must satisfy domain[0] < domain[1] without NaN, got ({a}, {b})"
+        )));
+    }
+    Ok(domain)
+}
+
+/// 首达时间的区域校验：在 `checked_domain` 基础上要求起点位于开区间内
+pub(crate) fn checked_fpt_domain(start_position: f64, domain: (f64, f64)) -> XPyResult<(f64, f64)> {
+    let (a, b) = checked_domain(domain)?;
+    if !(a < start_position && start_position < b) {
+        return Err(XPyError::ValueError(format!(
+            "start_position {start_position} must lie inside the domain ({a}, {b})"
+        )));
+    }
+    Ok((a, b))
+}
+
+/// 首次离开开区间 `(a, b)` 的下标（含该点），未离开时返回 `None`
+pub(crate) fn first_exit_index(positions: &[f64], domain: (f64, f64)) -> Option<usize> {
+    let (a, b) = domain;
+    positions.iter().position(|&x| !(a < x && x < b))
+}
+
+/// 统计单条轨迹在由 `edges` 划分的各区间内的停留时间（左端点规则，最后一个区间包含右端点）
+pub(crate) fn occupation_profile(times: &[f64], positions: &[f64], edges: &[f64]) -> Vec<f64> {
+    let bins = edges.len().saturating_sub(1);
+    let mut profile = vec![0.0; bins];
+    if bins == 0 {
+        return profile;
+    }
+    let last = edges[bins];
+    for (t, &x) in times.windows(2).zip(positions) {
+        if x < edges[0] || x > last || x.is_nan() {
+            continue;
+        }
+        let k = edges
+            .partition_point(|&e| e <= x)
+            .saturating_sub(1)
+            .min(bins - 1);
+        profile[k] += t[1] - t[0];
+    }
+    profile
+}
+
+/// 统计单条轨迹在各窗口 `[c - half_width, c + half_width]` 内的停留时间（左端点规则）；
+/// `order` 为按中心升序排列的下标，窗口可以重叠
+pub(crate) fn occupation_windows(
+    times: &[f64],
+    positions: &[f64],
+    centers: &[f64],
+    order: &[usize],
+    half_width: f64,
+) -> Vec<f64> {
+    let mut occupation = vec![0.0; centers.len()];
+    for (t, &x) in times.windows(2).zip(positions) {
+        if x.is_nan() {
+            continue;
+        }
+        // 包含 x 的窗口中心位于 [x - half_width, x + half_width]
+        let first = order.partition_point(|&k| centers[k] < x - half_width);
+        let last = order.partition_point(|&k| centers[k] <= x + half_width);
+        for &k in &order[first..last] {
+            occupation[k] += t[1] - t[0];
+        }
+    }
+    occupation
+}
+
+/// 并行模拟 `particles` 条轨迹并收集各自的终点值
+pub(crate) fn par_endpoints<F>(particles: usize, simulate: F) -> XPyResult<Vec<f64>>
+where
+    F: Fn() -> XResult<(Vec<f64>, Vec<f64>)> + Sync,
+{
+    (0..particles)
+        .into_par_iter()
+        .map(|_| -> XPyResult<f64> {
+            let (_, positions) = simulate()?;
+            Ok(positions.last().copied().unwrap_or(f64::NAN))
+        })
+        .collect()
+}
+
+/// 并行模拟 `particles` 条轨迹，以补偿求和计算终点的 `order` 阶原点矩；
+/// 结果不是有限值时发出 `RuntimeWarning`
+pub(crate) fn endpoint_raw_moment<F>(particles: usize, order: i32, simulate: F) -> PyResult<f64>
+where
+    F: Fn() -> XResult<(Vec<f64>, Vec<f64>)> + Sync,
+{
+    if particles == 0 {
+        return Err(XPyError::ValueError("particles must be positive".to_string()).into());
+    }
+    let endpoints = par_endpoints(particles, simulate)?;
+    let values = endpoints.into_iter().map(|x| x.powi(order)).collect();
+    let (result, _) = summation::mean(values, true, false);
+    warn_nonfinite_moment(order, result)?;
+    Ok(result)
+}
+
+/// 矩的估计溢出为 `±inf`（或正负溢出相抵为 `NaN`）时向 Python 发出 `RuntimeWarning`
+pub(crate) fn warn_nonfinite_moment(order: i32, value: f64) -> PyResult<()> {
+    if value.is_finite() {
+        return Ok(());
+    }
+    let message = CString::new(format!(
+        "moment of order {order} overflowed the float64 range, got {value}"
+    ))?;
+    Python::attach(|py| {
+        let category = py.get_type::<PyRuntimeWarning>();
+        PyErr::warn(py, &category, &message, 1)
+    })
+}
+
+/// 单条轨迹的速度（离散增量）自相关 `C(k) = mean_i v_i v_{i+k}`，`k = 0..=max_lag`
+pub(crate) fn velocity_autocorrelation(
+    times: &[f64],
+    positions: &[f64],
+    max_lag: usize,
+) -> Vec<f64> {
+    let velocities: Vec<f64> = times
+        .windows(2)
+        .zip(positions.windows(2))
+        .map(|(t, x)| (x[1] - x[0]) / (t[1] - t[0]))
+        .collect();
+    (0..=max_lag)
+        .map(|lag| {
+            let pairs = velocities.len().saturating_sub(lag);
+            if pairs == 0 {
+                return f64::NAN;
+            }
+            let total: f64 = velocities
+                .iter()
+                .zip(&velocities[lag..])
+                .map(|(v, w)| v * w)
+                .sum();
+            total / pairs as f64
+        })
+        .collect()
+}
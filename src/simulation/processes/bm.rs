@@ -1,27 +1,747 @@
+#[cfg(feature = "parquet")]
+use crate::simulation::export::write_ensemble_parquet;
 use crate::{
-    XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    XPyError, XPyResult,
+    distribution::normal_quantile,
+    random::{fill_standard_normals, sobol_standard_normals},
+    simulation::{
+        DiffusiveUnits, Endpoint, Kind, PyArrayPair, PyArrayTriple, UnitArray, call_py_func1,
+        checked_domain, checked_finite, checked_fpt_domain, checked_num_steps, checked_output,
+        checked_positive, checked_t0, checked_thin,
+        continuous::{
+            eatamsd_with_rule, tamsd_curve_with_rule, tamsd_samples_with_rule, tamsd_with_rule,
+        },
+        first_exit_index, occupation_profile, occupation_windows, par_endpoints, running_max,
+        shift_times, simulate_with_endpoint, summation, thin_path, trivial_raw_moment,
+        vec_to_pyarray, vec_to_pyarray2,
+    },
 };
-use diffusionx::simulation::{continuous::Bm, prelude::*};
-use pyo3::prelude::*;
+use diffusionx::{
+    random::normal,
+    simulation::{continuous::Bm, prelude::*},
+};
+use numpy::{IntoPyArray, Ix1, Ix2, PyArray, PyReadonlyArray1};
+use pyo3::{prelude::*, types::PyDict};
 #[cfg(feature = "stub_gen")]
-use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pyfunction, gen_stub_pymethods};
+use rayon::prelude::*;
 
 /// Simulate Brownian motion.
+///
+/// If `time_step` does not divide `duration`, `endpoint="extend"` ends with a shorter step
+/// landing exactly at `duration`, and `endpoint="clip"` stops at the last full step.
+///
+/// The returned times start at `t0` instead of `0`; the path itself is unchanged.
+/// With `thin > 1` only every `thin`-th point, plus the last one, is returned; the path is
+/// still simulated at `time_step`.
+///
+/// The arrays are always C-contiguous `float64`; `validate_output` checks this before
+/// returning, as a debugging aid.
+///
+/// With `return_units`, the times and positions are returned as `UnitArray`s. The units
+/// follow from `diffusion_unit`, the unit `L^2/T` of `diffusion_coefficient`: the times are
+/// in `T` and the positions in `L`, e.g. seconds and metres for the default `"m^2/s"`.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, diffusion_coefficient, duration, time_step, return_units = false, endpoint = "extend", t0 = 0.0, validate_output = false, thin = 1, diffusion_unit = "m^2/s"))]
 pub fn bm_simulate(
     py: Python<'_>,
     start_position: f64,
     diffusion_coefficient: f64,
     duration: f64,
     time_step: f64,
+    return_units: bool,
+    endpoint: &str,
+    t0: f64,
+    validate_output: bool,
+    thin: usize,
+    diffusion_unit: &str,
+) -> PyResult<Bound<'_, PyAny>> {
+    let units = if return_units {
+        Some(DiffusiveUnits::parse(diffusion_unit)?)
+    } else {
+        None
+    };
+    let (times, positions) = simulate_bm(
+        start_position,
+        diffusion_coefficient,
+        duration,
+        time_step,
+        endpoint,
+        t0,
+        thin,
+    )?;
+    let (times, positions) = vec_to_pyarray(py, times, positions);
+    if validate_output {
+        checked_output(&times)?;
+        checked_output(&positions)?;
+    }
+    match units {
+        Some(units) => {
+            let times = UnitArray::new(times, &units.time);
+            let positions = UnitArray::new(positions, &units.length);
+            Ok((times, positions).into_pyobject(py)?.into_any())
+        }
+        None => Ok((times, positions).into_pyobject(py)?.into_any()),
+    }
+}
+
+/// 模拟布朗运动：按 `endpoint` 处理末步，平移时间并抽稀
+fn simulate_bm(
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    endpoint: &str,
+    t0: f64,
+    thin: usize,
+) -> XPyResult<(Vec<f64>, Vec<f64>)> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    let endpoint = Endpoint::parse(endpoint)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let (times, positions) = simulate_with_endpoint(
+        |end| bm.simulate(end, time_step),
+        |x, h| Bm::new(x, diffusion_coefficient)?.simulate(h, h),
+        duration,
+        time_step,
+        endpoint,
+    )?;
+    Ok((
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
+}
+
+/// Simulate `particles` Brownian motion paths into one flat, particle-major array.
+///
+/// Returns the array of length `particles * points` and `points`, the number of grid
+/// points per path, so `reshape(particles, points)` is a view without copies. All
+/// increments are drawn in one vectorized pass and then summed per path in parallel.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_simulate_batch_flat(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+) -> XPyResult<(Bound<'_, PyArray<f64, Ix1>>, usize)> {
+    let num_steps = checked_num_steps(duration, time_step)?;
+    checked_bm(start_position, diffusion_coefficient)?;
+    if particles == 0 {
+        return Err(XPyError::ValueError(
+            "particles must be positive".to_string(),
+        ));
+    }
+    let points = num_steps + 1;
+    let len = particles.checked_mul(points).ok_or_else(|| {
+        XPyError::ValueError(format!(
+            "particles * points = {particles} * {points} overflows"
+        ))
+    })?;
+    let mut flat = vec![0.0; len];
+    fill_standard_normals(&mut flat, None);
+    // 每行首元素置为起点，其余为增量的标准正态数，逐行做前缀和
+    let scale = (2.0 * diffusion_coefficient * time_step).sqrt();
+    let last_step = duration - (num_steps - 1) as f64 * time_step;
+    let last_scale = (2.0 * diffusion_coefficient * last_step).sqrt();
+    flat.par_chunks_mut(points).for_each(|path| {
+        let mut position = start_position;
+        path[0] = position;
+        for (k, x) in path.iter_mut().enumerate().skip(1) {
+            position += if k == num_steps { last_scale } else { scale } * *x;
+            *x = position;
+        }
+    });
+    Ok((flat.into_pyarray(py), points))
+}
+
+/// Simulate Brownian motion and return the Gaussian increments that produced the path.
+///
+/// `positions[i + 1] = positions[i] + increments[i]`, so `increments` has one entry fewer
+/// than `times`. A given `seed` makes the output reproducible, independent of the number
+/// of threads.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (start_position, diffusion_coefficient, duration, time_step, seed = None))]
+pub fn bm_simulate_with_increments(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    seed: Option<u64>,
+) -> XPyResult<PyArrayTriple<'_>> {
+    checked_bm(start_position, diffusion_coefficient)?;
+    let (times, increments) = seeded_increments(diffusion_coefficient, duration, time_step, seed)?;
+    let positions = accumulate(start_position, &increments);
+    Ok((
+        times.into_pyarray(py),
+        positions.into_pyarray(py),
+        increments.into_pyarray(py),
+    ))
+}
+
+/// Continue a Brownian motion path from `last_position` at time `t0` for `additional_duration`.
+///
+/// The returned path starts with the point `(t0, last_position)`, so drop it when appending
+/// to the previous segment. Its increments are independent of that segment, so the joined
+/// path is again Brownian motion; carry the RNG state with `rng_get_state` and
+/// `rng_set_state`, or pass `seed`, to make the continuation reproducible.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (last_position, diffusion_coefficient, additional_duration, time_step, t0, seed = None))]
+pub fn bm_simulate_continue(
+    py: Python<'_>,
+    last_position: f64,
+    diffusion_coefficient: f64,
+    additional_duration: f64,
+    time_step: f64,
+    t0: f64,
+    seed: Option<u64>,
 ) -> XPyResult<PyArrayPair<'_>> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
-    let (times, positions) = bm.simulate(duration, time_step)?;
+    checked_t0(t0)?;
+    checked_bm(last_position, diffusion_coefficient)?;
+    let (times, increments) =
+        seeded_increments(diffusion_coefficient, additional_duration, time_step, seed)?;
+    let positions = accumulate(last_position, &increments);
+    Ok(vec_to_pyarray(py, shift_times(times, t0), positions))
+}
+
+/// 生成从 0 到 `duration` 的时间网格及对应的布朗运动增量，末步可能短于 `time_step`；
+/// 给定 `seed` 时结果与线程数无关
+fn seeded_increments(
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    seed: Option<u64>,
+) -> XPyResult<(Vec<f64>, Vec<f64>)> {
+    let num_steps = checked_num_steps(duration, time_step)?;
+    let mut increments = vec![0.0; num_steps];
+    fill_standard_normals(&mut increments, seed);
+    let scale = (2.0 * diffusion_coefficient * time_step).sqrt();
+    let last_step = duration - (num_steps - 1) as f64 * time_step;
+    increments[..num_steps - 1]
+        .iter_mut()
+        .for_each(|x| *x *= scale);
+    increments[num_steps - 1] *= (2.0 * diffusion_coefficient * last_step).sqrt();
+    let times: Vec<f64> = (0..num_steps)
+        .map(|k| k as f64 * time_step)
+        .chain(std::iter::once(duration))
+        .collect();
+    Ok((times, increments))
+}
+
+/// 从 `start` 出发累加增量，结果比 `increments` 多一个点
+fn accumulate(start: f64, increments: &[f64]) -> Vec<f64> {
+    std::iter::once(start)
+        .chain(increments.iter().scan(start, |x, dx| {
+            *x += dx;
+            Some(*x)
+        }))
+        .collect()
+}
+
+/// Iterator over `particles` independent Brownian motion paths, created by `bm_ensemble_iter`.
+///
+/// Each path is simulated lazily when requested, so the iterator can drive a progress bar.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyclass)]
+#[pyclass(module = "diffusionx._core")]
+pub struct BmEnsembleIterator {
+    bm: Bm,
+    duration: f64,
+    time_step: f64,
+    remaining: usize,
+}
+
+#[cfg_attr(feature = "stub_gen", gen_stub_pymethods)]
+#[pymethods]
+impl BmEnsembleIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__<'py>(&mut self, py: Python<'py>) -> XPyResult<Option<PyArrayPair<'py>>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        let (times, positions) = self.bm.simulate(self.duration, self.time_step)?;
+        self.remaining -= 1;
+        Ok(Some(vec_to_pyarray(py, times, positions)))
+    }
+
+    /// Number of paths not yet yielded.
+    fn __len__(&self) -> usize {
+        self.remaining
+    }
+}
+
+/// Iterate over `particles` independent Brownian motion paths, yielding `(times, positions)`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_ensemble_iter(
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+) -> XPyResult<BmEnsembleIterator> {
+    checked_num_steps(duration, time_step)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    Ok(BmEnsembleIterator {
+        bm,
+        duration,
+        time_step,
+        remaining: particles,
+    })
+}
+
+/// Get the terminal value `X(duration)` of Brownian motion.
+///
+/// The value is sampled exactly as `start_position + sqrt(2 D duration) * N(0, 1)`,
+/// so `time_step` is only validated.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_terminal(
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    checked_bm(start_position, diffusion_coefficient)?;
+    let scale = (2.0 * diffusion_coefficient * duration).sqrt();
+    Ok(start_position + scale * normal::standard_rand())
+}
+
+/// Get the terminal values `X(duration)` of `particles` independent Brownian motions.
+///
+/// The values are sampled exactly, see `bm_terminal`. With `qmc`, the Gaussian draws come
+/// from a scrambled Sobol sequence, which lowers the error of smooth functionals of them.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (start_position, diffusion_coefficient, duration, time_step, particles, qmc = false))]
+pub fn bm_terminals(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+    qmc: bool,
+) -> XPyResult<Bound<'_, PyArray<f64, Ix1>>> {
+    checked_num_steps(duration, time_step)?;
+    checked_bm(start_position, diffusion_coefficient)?;
+    let scale = (2.0 * diffusion_coefficient * duration).sqrt();
+    let mut result = if qmc {
+        sobol_standard_normals(particles)
+    } else {
+        normal::standard_rands(particles)
+    };
+    result
+        .par_iter_mut()
+        .for_each(|x| *x = start_position + scale * *x);
+    Ok(result.into_pyarray(py))
+}
+
+/// Simulate Brownian motion absorbed at the boundary of `domain`.
+///
+/// The path is truncated at the first point outside the open interval `domain`,
+/// which is included; it runs the whole `duration` if no crossing occurs.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_simulate_absorbing(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    domain: (f64, f64),
+) -> XPyResult<PyArrayPair<'_>> {
+    checked_num_steps(duration, time_step)?;
+    checked_fpt_domain(start_position, domain)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let (mut times, mut positions) = bm.simulate(duration, time_step)?;
+    if let Some(index) = first_exit_index(&positions, domain) {
+        times.truncate(index + 1);
+        positions.truncate(index + 1);
+    }
     Ok(vec_to_pyarray(py, times, positions))
 }
 
+/// Simulate Brownian motion absorbed at `lower` or `upper` and report which barrier was hit.
+///
+/// The path is truncated as in `bm_simulate_absorbing` with `domain = (lower, upper)`.
+/// The outcome is `-1` if the path was absorbed at `lower`, `1` at `upper`, and `0` if it
+/// survived to `duration`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_simulate_double_barrier(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    lower: f64,
+    upper: f64,
+) -> XPyResult<(
+    Bound<'_, PyArray<f64, Ix1>>,
+    Bound<'_, PyArray<f64, Ix1>>,
+    i32,
+)> {
+    checked_num_steps(duration, time_step)?;
+    let domain = checked_fpt_domain(start_position, (lower, upper))?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let (mut times, mut positions) = bm.simulate(duration, time_step)?;
+    let outcome = match first_exit_index(&positions, domain) {
+        Some(index) => {
+            times.truncate(index + 1);
+            positions.truncate(index + 1);
+            if positions[index] <= lower { -1 } else { 1 }
+        }
+        None => 0,
+    };
+    let (times, positions) = vec_to_pyarray(py, times, positions);
+    Ok((times, positions, outcome))
+}
+
+/// Get the empirical survival probability `S(t) = P(T > t)` of Brownian motion in `domain`.
+///
+/// `T` is the first grid time at which a path is outside the open interval `domain`;
+/// `S` is evaluated on the simulation grid, so `S(0) = 1`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_survival_curve(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    domain: (f64, f64),
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+) -> XPyResult<PyArrayPair<'_>> {
+    let num_steps = checked_num_steps(duration, time_step)?;
+    checked_fpt_domain(start_position, domain)?;
+    if particles == 0 {
+        return Err(XPyError::ValueError(
+            "particles must be positive".to_string(),
+        ));
+    }
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let points = num_steps + 1;
+    // exits[k] 为在第 k 个网格点首次离开的轨迹数
+    let exits = (0..particles)
+        .into_par_iter()
+        .map(|_| -> XPyResult<Option<usize>> {
+            let (_, positions) = bm.simulate(duration, time_step)?;
+            Ok(first_exit_index(&positions, domain))
+        })
+        .try_fold(
+            || vec![0usize; points],
+            |mut exits, index| {
+                if let Some(k) = index? {
+                    exits[k.min(points - 1)] += 1;
+                }
+                Ok::<_, XPyError>(exits)
+            },
+        )
+        .try_reduce(
+            || vec![0usize; points],
+            |mut left, right| {
+                left.iter_mut().zip(right).for_each(|(l, r)| *l += r);
+                Ok(left)
+            },
+        )?;
+    let times: Vec<f64> = (0..points)
+        .map(|k| (k as f64 * time_step).min(duration))
+        .collect();
+    let mut alive = particles;
+    let survival: Vec<f64> = exits
+        .into_iter()
+        .map(|count| {
+            alive -= count;
+            alive as f64 / particles as f64
+        })
+        .collect();
+    Ok(vec_to_pyarray(py, times, survival))
+}
+
+/// Simulate `particles` Brownian motion paths and return the one that leaves `domain` first.
+///
+/// The result is the `(times, positions, fpt)` of the path with the smallest first exit
+/// time on the simulation grid, or `None` if no path leaves the open interval `domain`
+/// within `duration`. Only the current best path is kept while the others are discarded.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_first_crossing_path(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    domain: (f64, f64),
+    particles: usize,
+) -> XPyResult<
+    Option<(
+        Bound<'_, PyArray<f64, Ix1>>,
+        Bound<'_, PyArray<f64, Ix1>>,
+        f64,
+    )>,
+> {
+    checked_num_steps(duration, time_step)?;
+    checked_fpt_domain(start_position, domain)?;
+    if particles == 0 {
+        return Err(XPyError::ValueError(
+            "particles must be positive".to_string(),
+        ));
+    }
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    // 候选为 (离开下标, 时间, 位置)，归约时只保留下标最小者
+    let best = (0..particles)
+        .into_par_iter()
+        .map(|_| -> XPyResult<Option<(usize, Vec<f64>, Vec<f64>)>> {
+            let (times, positions) = bm.simulate(duration, time_step)?;
+            Ok(first_exit_index(&positions, domain).map(|index| (index, times, positions)))
+        })
+        .try_reduce(
+            || None,
+            |left, right| {
+                Ok(match (left, right) {
+                    (Some(l), Some(r)) => Some(if r.0 < l.0 { r } else { l }),
+                    (l, r) => l.or(r),
+                })
+            },
+        )?;
+    Ok(best.map(|(index, times, positions)| {
+        let fpt = times[index];
+        let (times, positions) = vec_to_pyarray(py, times, positions);
+        (times, positions, fpt)
+    }))
+}
+
+/// Simulate Brownian motion together with its running maximum.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (start_position, diffusion_coefficient, duration, time_step, t0 = 0.0, thin = 1))]
+pub fn bm_running_max_simulate(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    t0: f64,
+    thin: usize,
+) -> XPyResult<PyArrayTriple<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let (times, positions) = bm.simulate(duration, time_step)?;
+    let maximum = running_max(&positions);
+    Ok((
+        shift_times(thin_path(times, thin), t0).into_pyarray(py),
+        thin_path(positions, thin).into_pyarray(py),
+        thin_path(maximum, thin).into_pyarray(py),
+    ))
+}
+
+/// Times and directions of the crossings of `level` along one Brownian path.
+///
+/// A point is above the level when `x >= level`; a crossing is recorded whenever consecutive
+/// grid points lie on different sides, with direction `+1` upwards and `-1` downwards, so
+/// the directions alternate. The crossing time is linearly interpolated between the two
+/// grid points and shifted by `t0`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (start_position, diffusion_coefficient, duration, time_step, level, t0 = 0.0))]
+pub fn bm_crossing_events(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    level: f64,
+    t0: f64,
+) -> XPyResult<(Bound<'_, PyArray<f64, Ix1>>, Bound<'_, PyArray<i8, Ix1>>)> {
+    checked_t0(t0)?;
+    checked_finite("level", level)?;
+    checked_num_steps(duration, time_step)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let (times, positions) = bm.simulate(duration, time_step)?;
+    let (crossing_times, directions): (Vec<f64>, Vec<i8>) = times
+        .windows(2)
+        .zip(positions.windows(2))
+        .filter_map(|(t, x)| {
+            let direction = match (x[0] >= level, x[1] >= level) {
+                (false, true) => 1,
+                (true, false) => -1,
+                _ => return None,
+            };
+            let fraction = (level - x[0]) / (x[1] - x[0]);
+            Some((t[0] + fraction * (t[1] - t[0]), direction))
+        })
+        .unzip();
+    Ok((
+        shift_times(crossing_times, t0).into_pyarray(py),
+        directions.into_pyarray(py),
+    ))
+}
+
+/// Ensemble statistics of the global maximum of Brownian motion and the time it occurs.
+///
+/// Returns a dict with `mean_max` and `mean_argmax_time`; with `return_arrays`, also the
+/// per-path `max` and `argmax_time` arrays. Ties keep the earliest grid time. By the
+/// arcsine law, `argmax_time / duration` has density `1 / (pi sqrt(u (1 - u)))`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (start_position, diffusion_coefficient, duration, time_step, particles, return_arrays = false))]
+pub fn bm_extreme_stats(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+    return_arrays: bool,
+) -> PyResult<Bound<'_, PyDict>> {
+    checked_num_steps(duration, time_step)?;
+    if particles == 0 {
+        return Err(XPyError::ValueError("particles must be positive".to_string()).into());
+    }
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let (maxima, argmax_times): (Vec<f64>, Vec<f64>) = (0..particles)
+        .into_par_iter()
+        .map(|_| -> XPyResult<(f64, f64)> {
+            let (times, positions) = bm.simulate(duration, time_step)?;
+            // 严格大于才更新，并列时保留最早的时刻
+            let (index, max) = positions.iter().copied().enumerate().fold(
+                (0, f64::NEG_INFINITY),
+                |(best, max), (i, x)| {
+                    if x > max { (i, x) } else { (best, max) }
+                },
+            );
+            Ok((max, times[index]))
+        })
+        .collect::<XPyResult<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+    let stats = PyDict::new(py);
+    stats.set_item(
+        "mean_max",
+        summation::sum(maxima.clone(), true) / particles as f64,
+    )?;
+    stats.set_item(
+        "mean_argmax_time",
+        summation::sum(argmax_times.clone(), true) / particles as f64,
+    )?;
+    if return_arrays {
+        stats.set_item("max", maxima.into_pyarray(py))?;
+        stats.set_item("argmax_time", argmax_times.into_pyarray(py))?;
+    }
+    Ok(stats)
+}
+
+/// Simulate Brownian motion and return `{"time": ..., "position": ...}`, ready for
+/// `pandas.DataFrame`. `t0` and `thin` are as in `bm_simulate`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (start_position, diffusion_coefficient, duration, time_step, t0 = 0.0, thin = 1))]
+pub fn bm_simulate_dict(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    t0: f64,
+    thin: usize,
+) -> PyResult<Bound<'_, PyDict>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let (times, positions) = bm.simulate(duration, time_step).map_err(XPyError::from)?;
+    let result = PyDict::new(py);
+    result.set_item(
+        "time",
+        shift_times(thin_path(times, thin), t0).into_pyarray(py),
+    )?;
+    result.set_item("position", thin_path(positions, thin).into_pyarray(py))?;
+    Ok(result)
+}
+
+/// Simulate an ensemble of Brownian motion paths in long format.
+///
+/// Returns `{"time": ..., "particle": ..., "position": ...}` with one row per particle and
+/// grid point, ordered by particle and then time, so `pandas.DataFrame(result)` has
+/// `particles * points` rows. `t0` and `thin` apply to every path as in `bm_simulate`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (start_position, diffusion_coefficient, duration, time_step, particles, t0 = 0.0, thin = 1))]
+pub fn bm_simulate_ensemble_dict(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+    t0: f64,
+    thin: usize,
+) -> PyResult<Bound<'_, PyDict>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
+    if particles == 0 {
+        return Err(XPyError::ValueError("particles must be positive".to_string()).into());
+    }
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let paths = (0..particles)
+        .into_par_iter()
+        .map(|_| -> XPyResult<_> {
+            let (times, positions) = bm.simulate(duration, time_step)?;
+            Ok((
+                shift_times(thin_path(times, thin), t0),
+                thin_path(positions, thin),
+            ))
+        })
+        .collect::<XPyResult<Vec<_>>>()?;
+    let rows = paths.iter().map(|(times, _)| times.len()).sum();
+    let mut time = Vec::with_capacity(rows);
+    let mut particle = Vec::with_capacity(rows);
+    let mut position = Vec::with_capacity(rows);
+    for (id, (times, positions)) in paths.into_iter().enumerate() {
+        particle.extend(std::iter::repeat_n(id, times.len()));
+        time.extend(times);
+        position.extend(positions);
+    }
+    let result = PyDict::new(py);
+    result.set_item("time", time.into_pyarray(py))?;
+    result.set_item("particle", particle.into_pyarray(py))?;
+    result.set_item("position", position.into_pyarray(py))?;
+    Ok(result)
+}
+
+/// Simulate an ensemble of Brownian motion paths and stream them to a Parquet file.
+///
+/// Rows are `(particle_id, time, position)`. Paths are flushed in row groups, so peak memory
+/// does not grow with `particles`. Returns the number of rows written.
+#[cfg(feature = "parquet")]
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_simulate_ensemble_to_parquet(
+    path: &str,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+) -> XPyResult<usize> {
+    checked_num_steps(duration, time_step)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    write_ensemble_parquet(path, particles, || bm.simulate(duration, time_step))
+}
+
 /// Get the raw moment of Brownian motion.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
@@ -32,9 +752,101 @@ pub fn bm_raw_moment(
     time_step: f64,
     order: i32,
     particles: usize,
+) -> PyResult<f64> {
+    let kind = Kind::Bm(checked_bm(start_position, diffusion_coefficient)?);
+    kind.raw_moment(duration, time_step, order, particles, false)
+}
+
+/// Get the raw moment of Brownian motion together with the number of non-finite contributions.
+///
+/// With `skip_nonfinite`, particles whose contribution is `inf` or `NaN` are excluded from the
+/// average; they are counted either way.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (start_position, diffusion_coefficient, duration, time_step, order, particles, skip_nonfinite = false))]
+pub fn bm_raw_moment_diagnostic(
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    order: i32,
+    particles: usize,
+    skip_nonfinite: bool,
+) -> XPyResult<(f64, usize)> {
+    checked_num_steps(duration, time_step)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let endpoints = par_endpoints(particles, || bm.simulate(duration, time_step))?;
+    let values = endpoints.into_iter().map(|x| x.powi(order)).collect();
+    Ok(summation::mean(values, true, skip_nonfinite))
+}
+
+/// Get the raw moment of Brownian motion with a CLT confidence interval.
+///
+/// Returns `(estimate, lower, upper)`, where the interval is
+/// `estimate ± z * s / sqrt(particles)` with `s` the sample standard deviation.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_raw_moment_ci(
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    order: i32,
+    particles: usize,
+    confidence: f64,
+) -> XPyResult<(f64, f64, f64)> {
+    checked_num_steps(duration, time_step)?;
+    if !(confidence > 0.0 && confidence < 1.0) {
+        return Err(XPyError::ValueError(format!(
+            "confidence must be in (0, 1), got {confidence}"
+        )));
+    }
+    if particles < 2 {
+        return Err(XPyError::ValueError(format!(
+            "particles must be at least 2 to estimate a variance, got {particles}"
+        )));
+    }
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let endpoints = par_endpoints(particles, || bm.simulate(duration, time_step))?;
+    let values: Vec<f64> = endpoints.into_iter().map(|x| x.powi(order)).collect();
+    let (estimate, _) = summation::mean(values.clone(), true, false);
+    let squares = values.into_iter().map(|v| (v - estimate).powi(2)).collect();
+    let variance = summation::sum(squares, true) / (particles - 1) as f64;
+    let z = normal_quantile(0.5 + confidence / 2.0);
+    let half_width = z * (variance / particles as f64).sqrt();
+    Ok((estimate, estimate - half_width, estimate + half_width))
+}
+
+/// Get the moment `E[(X(duration) - center)^order]` of Brownian motion about a fixed `center`.
+///
+/// `center = 0` gives the raw moment and `center = start_position` the displacement
+/// moment; unlike `bm_central_moment`, the center is not estimated from the ensemble.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_moment_about(
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    order: i32,
+    particles: usize,
+    center: f64,
 ) -> XPyResult<f64> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
-    let result = bm.raw_moment(duration, order, particles, time_step)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    if !center.is_finite() {
+        return Err(XPyError::ValueError(format!(
+            "center must be finite, got {center}"
+        )));
+    }
+    if let Some(result) = trivial_raw_moment(order, false)? {
+        return Ok(result);
+    }
+    let endpoints = par_endpoints(particles, || bm.simulate(duration, time_step))?;
+    let values = endpoints
+        .into_iter()
+        .map(|x| (x - center).powi(order))
+        .collect();
+    let (result, _) = summation::mean(values, true, false);
     Ok(result)
 }
 
@@ -49,9 +861,8 @@ pub fn bm_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
-    let result = bm.central_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Bm(checked_bm(start_position, diffusion_coefficient)?);
+    kind.central_moment(duration, time_step, order, particles)
 }
 
 /// Get the first passage time of Brownian motion.
@@ -64,9 +875,88 @@ pub fn bm_fpt(
     domain: (f64, f64),
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
-    let result = bm.fpt(domain, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Bm(checked_bm(start_position, diffusion_coefficient)?);
+    kind.fpt(start_position, time_step, domain, max_duration)
+}
+
+/// Get the first passage time of Brownian motion through time-dependent boundaries.
+///
+/// `lower_func` and `upper_func` map `t` to the boundaries and are evaluated at every
+/// step; exceptions raised by them are propagated.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_fpt_moving(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    time_step: f64,
+    lower_func: Py<PyAny>,
+    upper_func: Py<PyAny>,
+    max_duration: f64,
+) -> PyResult<Option<f64>> {
+    let steps = checked_num_steps(max_duration, time_step)?;
+    checked_bm(start_position, diffusion_coefficient)?;
+    let crossed = |t: f64, x: f64| -> PyResult<bool> {
+        let lower = call_py_func1(py, &lower_func, t)?;
+        let upper = call_py_func1(py, &upper_func, t)?;
+        Ok(x <= lower || x >= upper)
+    };
+    if crossed(0.0, start_position)? {
+        return Err(XPyError::ValueError(format!(
+            "start_position {start_position} must lie inside the boundaries at t = 0"
+        ))
+        .into());
+    }
+    let (mut t, mut x) = (0.0, start_position);
+    for i in 1..=steps {
+        let next = (i as f64 * time_step).min(max_duration);
+        x += (2.0 * diffusion_coefficient * (next - t)).sqrt() * normal::standard_rand();
+        t = next;
+        if crossed(t, x)? {
+            return Ok(Some(t));
+        }
+    }
+    Ok(None)
+}
+
+/// Simulate Brownian motion until `predicate(t, x)` is truthy or `max_duration` is reached.
+///
+/// The predicate is called after every step, not at the start. Returns the path up to and
+/// including the stopping point and whether the predicate stopped it; exceptions raised by
+/// the predicate are propagated.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_simulate_until<'py>(
+    py: Python<'py>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    time_step: f64,
+    max_duration: f64,
+    predicate: Py<PyAny>,
+) -> PyResult<(
+    Bound<'py, PyArray<f64, Ix1>>,
+    Bound<'py, PyArray<f64, Ix1>>,
+    bool,
+)> {
+    let steps = checked_num_steps(max_duration, time_step)?;
+    checked_bm(start_position, diffusion_coefficient)?;
+    let predicate = predicate.bind(py);
+    let (mut times, mut positions) = (vec![0.0], vec![start_position]);
+    let (mut t, mut x) = (0.0, start_position);
+    let mut stopped = false;
+    for i in 1..=steps {
+        let next = (i as f64 * time_step).min(max_duration);
+        x += (2.0 * diffusion_coefficient * (next - t)).sqrt() * normal::standard_rand();
+        t = next;
+        times.push(t);
+        positions.push(x);
+        if predicate.call1((t, x))?.is_truthy()? {
+            stopped = true;
+            break;
+        }
+    }
+    let (times, positions) = vec_to_pyarray(py, times, positions);
+    Ok((times, positions, stopped))
 }
 
 /// Get the raw moment of the first passage time of Brownian motion.
@@ -81,10 +971,42 @@ pub fn bm_fpt_raw_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
+    let kind = Kind::Bm(checked_bm(start_position, diffusion_coefficient)?);
+    kind.fpt_raw_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
+}
+
+/// Get the raw moment of the first passage time of Brownian motion with the leading
+/// time-step bias removed.
+///
+/// A path sampled every `dt` misses excursions across the boundary between grid points,
+/// so the discrete first passage time is late by `O(sqrt(dt))`. The estimator is run at
+/// `time_step` and `time_step / 4`; quartering the step halves `sqrt(dt)`, so
+/// `2 M(time_step / 4) - M(time_step)` cancels the leading term. The two runs use
+/// independent paths, so the result has a larger variance than either of them.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_fpt_raw_moment_corrected(
+    start_position: f64,
+    diffusion_coefficient: f64,
+    domain: (f64, f64),
+    order: i32,
+    particles: usize,
+    max_duration: f64,
+    time_step: f64,
+) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
     let fpt = FirstPassageTime::new(&bm, domain)?;
-    let result = fpt.raw_moment(order, particles, max_duration, time_step)?;
-    Ok(result)
+    let coarse = fpt.raw_moment(order, particles, max_duration, time_step)?;
+    let fine = fpt.raw_moment(order, particles, max_duration, time_step / 4.0)?;
+    Ok(coarse.zip(fine).map(|(coarse, fine)| 2.0 * fine - coarse))
 }
 
 /// Get the central moment of the first passage time of Brownian motion.
@@ -99,13 +1021,76 @@ pub fn bm_fpt_central_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
-    let fpt = FirstPassageTime::new(&bm, domain)?;
-    let result = fpt.central_moment(order, particles, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Bm(checked_bm(start_position, diffusion_coefficient)?);
+    kind.fpt_central_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
+}
+
+/// Mean first passage time out of `target_domain` together with the mean time spent in
+/// `trap_domain` before that passage, both taken from the same paths.
+///
+/// Each path is simulated step by step until it leaves the open `target_domain`; a step
+/// adds to the trap occupation when its left point lies in the closed `trap_domain`, as in
+/// `bm_occupation_time_curve`. Returns `None` if some path has not left `target_domain`
+/// by `max_duration`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_fpt_and_occupation(
+    start_position: f64,
+    diffusion_coefficient: f64,
+    time_step: f64,
+    target_domain: (f64, f64),
+    trap_domain: (f64, f64),
+    max_duration: f64,
+    particles: usize,
+) -> XPyResult<Option<(f64, f64)>> {
+    let steps = checked_num_steps(max_duration, time_step)?;
+    let (a, b) = checked_fpt_domain(start_position, target_domain)?;
+    let (lower, upper) = checked_domain(trap_domain)?;
+    if particles == 0 {
+        return Err(XPyError::ValueError(
+            "particles must be positive".to_string(),
+        ));
+    }
+    checked_bm(start_position, diffusion_coefficient)?;
+    let samples = (0..particles)
+        .into_par_iter()
+        .map(|_| {
+            let (mut t, mut x, mut trapped) = (0.0, start_position, 0.0);
+            for i in 1..=steps {
+                let next = (i as f64 * time_step).min(max_duration);
+                if lower <= x && x <= upper {
+                    trapped += next - t;
+                }
+                x += (2.0 * diffusion_coefficient * (next - t)).sqrt() * normal::standard_rand();
+                t = next;
+                if !(a < x && x < b) {
+                    return Some((t, trapped));
+                }
+            }
+            None
+        })
+        .collect::<Option<Vec<(f64, f64)>>>();
+    Ok(samples.map(|samples| {
+        let (fpts, occupations): (Vec<f64>, Vec<f64>) = samples.into_iter().unzip();
+        let n = particles as f64;
+        (
+            summation::sum(fpts, true) / n,
+            summation::sum(occupations, true) / n,
+        )
+    }))
 }
 
 /// Get the occupation time of Brownian motion.
+///
+/// Each step counts fully when its left point lies in the closed `domain`, the same rule
+/// as `bm_occupation_time_curve`, whose last value this is.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
 pub fn bm_occupation_time(
@@ -115,11 +1100,252 @@ pub fn bm_occupation_time(
     domain: (f64, f64),
     duration: f64,
 ) -> XPyResult<f64> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
-    let result = bm.occupation_time(domain, duration, time_step)?;
+    checked_num_steps(duration, time_step)?;
+    let domain = checked_domain(domain)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let (times, positions) = bm.simulate(duration, time_step)?;
+    let result = occupation_curve(&times, &positions, domain)
+        .last()
+        .copied()
+        .unwrap_or(0.0);
     Ok(result)
 }
 
+/// Get the cumulative occupation time `∫_0^t 1{X(s) ∈ domain} ds` along one Brownian path.
+///
+/// Each step counts fully when its left point lies in the closed `domain`, so the curve is
+/// nondecreasing, starts at 0 and ends at the total occupation time of the path.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_occupation_time_curve(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    domain: (f64, f64),
+    duration: f64,
+    time_step: f64,
+) -> XPyResult<PyArrayPair<'_>> {
+    checked_num_steps(duration, time_step)?;
+    let domain = checked_domain(domain)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let (times, positions) = bm.simulate(duration, time_step)?;
+    let occupation = occupation_curve(&times, &positions, domain);
+    Ok(vec_to_pyarray(py, times, occupation))
+}
+
+/// 沿一条路径累计落在闭区间 `[a, b]` 内的时间：每一步的左端点在区间内则整步计入，
+/// 返回与 `times` 等长、从 0 开始的累计曲线
+fn occupation_curve(times: &[f64], positions: &[f64], (a, b): (f64, f64)) -> Vec<f64> {
+    std::iter::once(0.0)
+        .chain(times.windows(2).zip(positions).scan(0.0, |total, (t, &x)| {
+            if a <= x && x <= b {
+                *total += t[1] - t[0];
+            }
+            Some(*total)
+        }))
+        .collect()
+}
+
+/// Get the ensemble-averaged occupation time of Brownian motion in each bin defined by `edges`.
+///
+/// `edges` must be a strictly increasing sequence of at least two finite values.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_local_time_profile<'py>(
+    py: Python<'py>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+    edges: PyReadonlyArray1<'py, f64>,
+) -> XPyResult<Bound<'py, PyArray<f64, Ix1>>> {
+    checked_num_steps(duration, time_step)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    if particles == 0 {
+        return Err(XPyError::ValueError(
+            "particles must be positive".to_string(),
+        ));
+    }
+    let edges = edges.as_array().to_vec();
+    if edges.len() < 2 || edges.iter().any(|e| !e.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "edges must contain at least 2 finite values, got {} values",
+            edges.len()
+        )));
+    }
+    if !edges.windows(2).all(|e| e[0] < e[1]) {
+        return Err(XPyError::ValueError(
+            "edges must be strictly increasing".to_string(),
+        ));
+    }
+    let bins = edges.len() - 1;
+    let total = (0..particles)
+        .into_par_iter()
+        .map(|_| -> XPyResult<Vec<f64>> {
+            let (times, positions) = bm.simulate(duration, time_step)?;
+            Ok(occupation_profile(&times, &positions, &edges))
+        })
+        .try_reduce(
+            || vec![0.0; bins],
+            |mut acc, profile| {
+                acc.iter_mut().zip(profile).for_each(|(a, p)| *a += p);
+                Ok(acc)
+            },
+        )?;
+    let result: Vec<f64> = total.into_iter().map(|t| t / particles as f64).collect();
+    Ok(result.into_pyarray(py))
+}
+
+/// Get the ensemble-averaged occupation time of Brownian motion in each window
+/// `[c - half_width, c + half_width]` for `c` in `centers`.
+///
+/// Every simulated path is tallied into all windows, which may overlap.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_occupation_time_windows<'py>(
+    py: Python<'py>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+    centers: PyReadonlyArray1<'py, f64>,
+    half_width: f64,
+) -> XPyResult<Bound<'py, PyArray<f64, Ix1>>> {
+    checked_num_steps(duration, time_step)?;
+    if !(half_width > 0.0 && half_width.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "half_width must be positive and finite, got {half_width}"
+        )));
+    }
+    let centers = centers.as_array().to_vec();
+    if centers.iter().any(|c| !c.is_finite()) {
+        return Err(XPyError::ValueError("centers must be finite".to_string()));
+    }
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let mut order: Vec<usize> = (0..centers.len()).collect();
+    order.sort_by(|&i, &j| centers[i].total_cmp(&centers[j]));
+    let total = (0..particles)
+        .into_par_iter()
+        .map(|_| -> XPyResult<Vec<f64>> {
+            let (times, positions) = bm.simulate(duration, time_step)?;
+            Ok(occupation_windows(
+                &times, &positions, &centers, &order, half_width,
+            ))
+        })
+        .try_reduce(
+            || vec![0.0; centers.len()],
+            |mut acc, occupation| {
+                acc.iter_mut().zip(occupation).for_each(|(a, o)| *a += o);
+                Ok(acc)
+            },
+        )?;
+    let result: Vec<f64> = total.into_iter().map(|t| t / particles as f64).collect();
+    Ok(result.into_pyarray(py))
+}
+
+/// Get the empirical propagator `P(x, t)` of Brownian motion at several snapshot times.
+///
+/// The ensemble is simulated once up to the largest snapshot time, and each snapshot uses
+/// the position at the grid point nearest to it. Returns the `bins + 1` equally spaced
+/// edges over `range` and a `(len(snapshot_times), bins)` array of densities, normalized
+/// by the number of particles, so mass falling outside `range` is lost.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bm_propagator<'py>(
+    py: Python<'py>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    snapshot_times: Vec<f64>,
+    time_step: f64,
+    particles: usize,
+    bins: usize,
+    range: (f64, f64),
+) -> XPyResult<(Bound<'py, PyArray<f64, Ix1>>, Bound<'py, PyArray<f64, Ix2>>)> {
+    if snapshot_times.is_empty() {
+        return Err(XPyError::ValueError(
+            "snapshot_times must not be empty".to_string(),
+        ));
+    }
+    if let Some(t) = snapshot_times
+        .iter()
+        .find(|t| !(**t > 0.0 && t.is_finite()))
+    {
+        return Err(XPyError::ValueError(format!(
+            "snapshot_times must be positive and finite, got {t}"
+        )));
+    }
+    if particles == 0 || bins == 0 {
+        return Err(XPyError::ValueError(format!(
+            "particles and bins must be positive, got {particles} and {bins}"
+        )));
+    }
+    let (low, high) = range;
+    if !(low < high && low.is_finite() && high.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "range must be finite with low < high, got ({low}, {high})"
+        )));
+    }
+    let duration = snapshot_times.iter().copied().fold(0.0, f64::max);
+    checked_num_steps(duration, time_step)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let width = (high - low) / bins as f64;
+    let snapshots = snapshot_times.len();
+    let counts = (0..particles)
+        .into_par_iter()
+        .try_fold(
+            || vec![0.0; snapshots * bins],
+            |mut acc, _| -> XPyResult<Vec<f64>> {
+                let (times, positions) = bm.simulate(duration, time_step)?;
+                for (row, &t) in snapshot_times.iter().enumerate() {
+                    let x = positions[nearest_index(&times, t)];
+                    if !(low <= x && x <= high) {
+                        continue;
+                    }
+                    let k = (((x - low) / width) as usize).min(bins - 1);
+                    acc[row * bins + k] += 1.0;
+                }
+                Ok(acc)
+            },
+        )
+        .try_reduce(
+            || vec![0.0; snapshots * bins],
+            |mut acc, counts| {
+                acc.iter_mut().zip(counts).for_each(|(a, c)| *a += c);
+                Ok(acc)
+            },
+        )?;
+    let norm = particles as f64 * width;
+    let density: Vec<f64> = counts.into_iter().map(|c| c / norm).collect();
+    let edges: Vec<f64> = (0..=bins)
+        .map(|k| {
+            if k == bins {
+                high
+            } else {
+                low + k as f64 * width
+            }
+        })
+        .collect();
+    Ok((
+        edges.into_pyarray(py),
+        vec_to_pyarray2(py, snapshots, bins, density)?,
+    ))
+}
+
+/// 升序网格 `times` 中距 `t` 最近的下标
+fn nearest_index(times: &[f64], t: f64) -> usize {
+    let k = times.partition_point(|&s| s < t);
+    if k == times.len() {
+        return k - 1;
+    }
+    if k > 0 && t - times[k - 1] <= times[k] - t {
+        k - 1
+    } else {
+        k
+    }
+}
+
 /// Get the raw moment of the occupation time of Brownian motion.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
@@ -132,10 +1358,8 @@ pub fn bm_occupation_time_raw_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
-    let oc = OccupationTime::new(&bm, domain, duration)?;
-    let result = oc.raw_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Bm(checked_bm(start_position, diffusion_coefficient)?);
+    kind.occupation_time_raw_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the central moment of the occupation time of Brownian motion.
@@ -150,15 +1374,14 @@ pub fn bm_occupation_time_central_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
-    let oc = OccupationTime::new(&bm, domain, duration)?;
-    let result = oc.central_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Bm(checked_bm(start_position, diffusion_coefficient)?);
+    kind.occupation_time_central_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the time-averaged mean square displacement of Brownian motion.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, diffusion_coefficient, duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn bm_tamsd(
     start_position: f64,
     diffusion_coefficient: f64,
@@ -166,15 +1389,55 @@ pub fn bm_tamsd(
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
-    let result = bm.tamsd(duration, delta, time_step, quad_order)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let result = tamsd_with_rule(
+        rule,
+        || bm.tamsd(duration, delta, time_step, quad_order),
+        |t| bm.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
+/// Get the time-averaged mean square displacement of Brownian motion for every lag in `deltas`.
+///
+/// A single path is simulated and reused for all lags, which are evaluated by quadrature
+/// over the linearly interpolated path.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (start_position, diffusion_coefficient, duration, deltas, time_step, quad_order, rule = "gauss_legendre"))]
+pub fn bm_tamsd_curve(
+    py: Python<'_>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    deltas: Vec<f64>,
+    time_step: f64,
+    quad_order: usize,
+    rule: &str,
+) -> XPyResult<Bound<'_, PyArray<f64, Ix1>>> {
+    checked_num_steps(duration, time_step)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let result = tamsd_curve_with_rule(
+        rule,
+        |t| bm.simulate(t, time_step),
+        duration,
+        &deltas,
+        time_step,
+        quad_order,
+    )?;
+    Ok(result.into_pyarray(py))
+}
+
 /// Get the ensemble average of the time-averaged mean square displacement of Brownian motion.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, diffusion_coefficient, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn bm_eatamsd(
     start_position: f64,
     diffusion_coefficient: f64,
@@ -183,12 +1446,54 @@ pub fn bm_eatamsd(
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
-    let result = bm.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || bm.tamsd(duration, delta, time_step, quad_order),
+        |t| bm.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
+/// Get the time-averaged mean square displacement of each of `particles` Brownian motion paths.
+///
+/// This is the per-particle distribution averaged by `bm_eatamsd`, e.g. for the
+/// ergodicity breaking parameter.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (start_position, diffusion_coefficient, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
+pub fn bm_eatamsd_samples<'py>(
+    py: Python<'py>,
+    start_position: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    delta: f64,
+    particles: usize,
+    time_step: f64,
+    quad_order: usize,
+    rule: &str,
+) -> XPyResult<Bound<'py, PyArray<f64, Ix1>>> {
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
+    let result = tamsd_samples_with_rule(
+        rule,
+        || bm.tamsd(duration, delta, time_step, quad_order),
+        |t| bm.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
+    Ok(result.into_pyarray(py))
+}
+
 /// Get the mean of Brownian motion.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
@@ -199,7 +1504,7 @@ pub fn bm_mean(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
     let result = bm.mean(duration, particles, time_step)?;
     Ok(result)
 }
@@ -214,7 +1519,7 @@ pub fn bm_msd(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
     let result = bm.msd(duration, particles, time_step)?;
     Ok(result)
 }
@@ -230,7 +1535,8 @@ pub fn bm_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
+    checked_num_steps(duration, time_step)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
     let result = bm.frac_raw_moment(duration, order, particles, time_step)?;
     Ok(result)
 }
@@ -246,7 +1552,36 @@ pub fn bm_frac_central_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
-    let bm = Bm::new(start_position, diffusion_coefficient)?;
+    checked_num_steps(duration, time_step)?;
+    let bm = checked_bm(start_position, diffusion_coefficient)?;
     let result = bm.frac_central_moment(duration, order, particles, time_step)?;
     Ok(result)
 }
+
+/// 构造布朗运动，逐项校验 `start_position` 有限、`diffusion_coefficient` 为正
+pub(crate) fn checked_bm(start_position: f64, diffusion_coefficient: f64) -> XPyResult<Bm> {
+    checked_finite("start_position", start_position)?;
+    checked_positive("diffusion_coefficient", diffusion_coefficient)?;
+    Ok(Bm::new(start_position, diffusion_coefficient)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn occupation_curve_uses_left_points() {
+        let times = [0.0, 0.5, 1.0, 1.25];
+        let positions = [0.0, 2.0, 0.5, 3.0];
+        let curve = occupation_curve(&times, &positions, (0.0, 1.0));
+        assert_eq!(curve, vec![0.0, 0.5, 0.5, 0.75]);
+        // 曲线终值即 `bm_occupation_time` 的左端点求和
+        let total: f64 = times
+            .windows(2)
+            .zip(&positions)
+            .filter(|&(_, &x)| (0.0..=1.0).contains(&x))
+            .map(|(t, _)| t[1] - t[0])
+            .sum();
+        assert_eq!(curve.last().copied(), Some(total));
+    }
+}
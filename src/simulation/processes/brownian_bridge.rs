@@ -1,6 +1,10 @@
 use crate::{
-    XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    XPyError, XPyResult,
+    simulation::{
+        Kind, PyArrayPair, checked_domain, checked_num_steps, checked_t0, checked_thin,
+        continuous::{eatamsd_with_rule, tamsd_with_rule},
+        shift_times, thin_path, vec_to_pyarray,
+    },
 };
 use diffusionx::simulation::{continuous::BrownianBridge, prelude::*};
 use pyo3::prelude::*;
@@ -11,24 +15,66 @@ use pyo3_stub_gen::derive::gen_stub_pyfunction;
 /// Simulate Brownian bridge.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
-pub fn bb_simulate(py: Python<'_>, duration: f64, time_step: f64) -> XPyResult<PyArrayPair<'_>> {
+#[pyo3(signature = (duration, time_step, t0 = 0.0, thin = 1))]
+pub fn bb_simulate(
+    py: Python<'_>,
+    duration: f64,
+    time_step: f64,
+    t0: f64,
+    thin: usize,
+) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
     let bb = BrownianBridge::new();
     let (times, positions) = bb.simulate(duration, time_step)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
 }
 
-/// Get the raw moment of Brownian bridge.
+/// Simulate Brownian bridge pinned at `start_value` at `t = 0` and `end_value` at `t = duration`.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
-pub fn bb_raw_moment(
+pub fn bb_simulate_endpoints(
+    py: Python<'_>,
+    start_value: f64,
+    end_value: f64,
     duration: f64,
     time_step: f64,
-    order: i32,
-    particles: usize,
-) -> XPyResult<f64> {
+) -> XPyResult<PyArrayPair<'_>> {
+    checked_num_steps(duration, time_step)?;
+    if !start_value.is_finite() || !end_value.is_finite() {
+        return Err(XPyError::ValueError(format!(
+            "start_value and end_value must be finite, got {start_value} and {end_value}"
+        )));
+    }
     let bb = BrownianBridge::new();
-    let result = bb.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    // 零到零的桥 W(t) - (t/T)W(T) 加上端点间的线性插值即得一般端点的桥
+    let (times, mut positions) = bb.simulate(duration, time_step)?;
+    let total = times.last().copied().unwrap_or(duration);
+    let increment = end_value - start_value;
+    positions
+        .iter_mut()
+        .zip(&times)
+        .for_each(|(x, &t)| *x += start_value + t / total * increment);
+    if let Some(first) = positions.first_mut() {
+        *first = start_value;
+    }
+    if let Some(last) = positions.last_mut() {
+        *last = end_value;
+    }
+    Ok(vec_to_pyarray(py, times, positions))
+}
+
+/// Get the raw moment of Brownian bridge.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn bb_raw_moment(duration: f64, time_step: f64, order: i32, particles: usize) -> PyResult<f64> {
+    let kind = Kind::BrownianBridge(BrownianBridge::new());
+    kind.raw_moment(duration, time_step, order, particles, false)
 }
 
 /// Get the central moment of Brownian bridge.
@@ -40,9 +86,8 @@ pub fn bb_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let bb = BrownianBridge::new();
-    let result = bb.central_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::BrownianBridge(BrownianBridge::new());
+    kind.central_moment(duration, time_step, order, particles)
 }
 
 /// Get the fractional raw moment of Brownian bridge.
@@ -54,6 +99,7 @@ pub fn bb_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let bb = BrownianBridge::new();
     let result = bb.frac_raw_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -68,6 +114,7 @@ pub fn bb_frac_central_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let bb = BrownianBridge::new();
     let result = bb.frac_central_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -77,6 +124,7 @@ pub fn bb_frac_central_moment(
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
 pub fn bb_fpt(time_step: f64, domain: (f64, f64), max_duration: f64) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let bb = BrownianBridge::new();
     let result = bb.fpt(domain, max_duration, time_step)?;
     Ok(result)
@@ -92,6 +140,7 @@ pub fn bb_fpt_raw_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let bb = BrownianBridge::new();
     let fpt = FirstPassageTime::new(&bb, domain)?;
     let result = fpt.raw_moment(order, particles, max_duration, time_step)?;
@@ -108,6 +157,7 @@ pub fn bb_fpt_central_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let bb = BrownianBridge::new();
     let fpt = FirstPassageTime::new(&bb, domain)?;
     let result = fpt.central_moment(order, particles, max_duration, time_step)?;
@@ -118,9 +168,8 @@ pub fn bb_fpt_central_moment(
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
 pub fn bb_occupation_time(domain: (f64, f64), time_step: f64, duration: f64) -> XPyResult<f64> {
-    let bb = BrownianBridge::new();
-    let result = bb.occupation_time(domain, duration, time_step)?;
-    Ok(result)
+    let kind = Kind::BrownianBridge(BrownianBridge::new());
+    kind.occupation_time(time_step, domain, duration)
 }
 
 /// Get the raw moment of the occupation time of Brownian bridge.
@@ -133,10 +182,8 @@ pub fn bb_occupation_time_raw_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let bb = BrownianBridge::new();
-    let oc = OccupationTime::new(&bb, domain, duration)?;
-    let result = oc.raw_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::BrownianBridge(BrownianBridge::new());
+    kind.occupation_time_raw_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the central moment of the occupation time of Brownian bridge.
@@ -149,33 +196,57 @@ pub fn bb_occupation_time_central_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let bb = BrownianBridge::new();
-    let oc = OccupationTime::new(&bb, domain, duration)?;
-    let result = oc.central_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::BrownianBridge(BrownianBridge::new());
+    kind.occupation_time_central_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the time-averaged mean square displacement of Brownian bridge.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
-pub fn bb_tamsd(duration: f64, delta: f64, time_step: f64, quad_order: usize) -> XPyResult<f64> {
+#[pyo3(signature = (duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
+pub fn bb_tamsd(
+    duration: f64,
+    delta: f64,
+    time_step: f64,
+    quad_order: usize,
+    rule: &str,
+) -> XPyResult<f64> {
     let bb = BrownianBridge::new();
-    let result = bb.tamsd(duration, delta, time_step, quad_order)?;
+    let result = tamsd_with_rule(
+        rule,
+        || bb.tamsd(duration, delta, time_step, quad_order),
+        |t| bb.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the ensemble average of the time-averaged mean square displacement of Brownian bridge.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn bb_eatamsd(
     duration: f64,
     delta: f64,
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let bb = BrownianBridge::new();
-    let result = bb.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || bb.tamsd(duration, delta, time_step, quad_order),
+        |t| bb.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
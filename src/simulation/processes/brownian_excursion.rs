@@ -1,6 +1,10 @@
 use crate::{
     XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    simulation::{
+        Kind, PyArrayPair, checked_domain, checked_num_steps, checked_t0, checked_thin,
+        continuous::{eatamsd_with_rule, tamsd_with_rule},
+        shift_times, thin_path, vec_to_pyarray,
+    },
 };
 use diffusionx::simulation::{continuous::BrownianExcursion, prelude::*};
 use pyo3::prelude::*;
@@ -10,24 +14,32 @@ use pyo3_stub_gen::derive::gen_stub_pyfunction;
 /// Simulate Brownian excursion.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
-pub fn be_simulate(py: Python<'_>, duration: f64, time_step: f64) -> XPyResult<PyArrayPair<'_>> {
+#[pyo3(signature = (duration, time_step, t0 = 0.0, thin = 1))]
+pub fn be_simulate(
+    py: Python<'_>,
+    duration: f64,
+    time_step: f64,
+    t0: f64,
+    thin: usize,
+) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
     let be = BrownianExcursion::new();
     let (times, positions) = be.simulate(duration, time_step)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
 }
 
 /// Get the raw moment of Brownian excursion.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
-pub fn be_raw_moment(
-    duration: f64,
-    time_step: f64,
-    order: i32,
-    particles: usize,
-) -> XPyResult<f64> {
-    let be = BrownianExcursion::new();
-    let result = be.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+pub fn be_raw_moment(duration: f64, time_step: f64, order: i32, particles: usize) -> PyResult<f64> {
+    let kind = Kind::BrownianExcursion(BrownianExcursion::new());
+    kind.raw_moment(duration, time_step, order, particles, false)
 }
 
 /// Get the central moment of Brownian excursion.
@@ -39,9 +51,8 @@ pub fn be_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let be = BrownianExcursion::new();
-    let result = be.central_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::BrownianExcursion(BrownianExcursion::new());
+    kind.central_moment(duration, time_step, order, particles)
 }
 
 /// Get the fractional raw moment of Brownian excursion.
@@ -53,6 +64,7 @@ pub fn be_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let be = BrownianExcursion::new();
     let result = be.frac_raw_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -67,6 +79,7 @@ pub fn be_frac_central_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let be = BrownianExcursion::new();
     let result = be.frac_central_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -76,6 +89,7 @@ pub fn be_frac_central_moment(
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
 pub fn be_fpt(time_step: f64, domain: (f64, f64)) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let be = BrownianExcursion::new();
     let result = be.fpt(domain, time_step)?;
     Ok(result)
@@ -90,6 +104,7 @@ pub fn be_fpt_raw_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let be = BrownianExcursion::new();
     let fpt = FirstPassageTime::new(&be, domain)?;
     let result = fpt.raw_moment(order, particles, 1.0, time_step)?;
@@ -105,6 +120,7 @@ pub fn be_fpt_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let be = BrownianExcursion::new();
     let fpt = FirstPassageTime::new(&be, domain)?;
     let result = fpt.central_moment(order, particles, 1.0, time_step)?;
@@ -115,9 +131,8 @@ pub fn be_fpt_central_moment(
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
 pub fn be_occupation_time(domain: (f64, f64), time_step: f64, duration: f64) -> XPyResult<f64> {
-    let be = BrownianExcursion::new();
-    let result = be.occupation_time(domain, duration, time_step)?;
-    Ok(result)
+    let kind = Kind::BrownianExcursion(BrownianExcursion::new());
+    kind.occupation_time(time_step, domain, duration)
 }
 
 /// Get the raw moment of the occupation time of Brownian excursion.
@@ -130,10 +145,8 @@ pub fn be_occupation_time_raw_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let be = BrownianExcursion::new();
-    let oc = OccupationTime::new(&be, domain, duration)?;
-    let result = oc.raw_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::BrownianExcursion(BrownianExcursion::new());
+    kind.occupation_time_raw_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the central moment of the occupation time of Brownian excursion.
@@ -146,33 +159,57 @@ pub fn be_occupation_time_central_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let be = BrownianExcursion::new();
-    let oc = OccupationTime::new(&be, domain, duration)?;
-    let result = oc.central_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::BrownianExcursion(BrownianExcursion::new());
+    kind.occupation_time_central_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the time-averaged mean square displacement of Brownian excursion.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
-pub fn be_tamsd(duration: f64, delta: f64, time_step: f64, quad_order: usize) -> XPyResult<f64> {
+#[pyo3(signature = (duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
+pub fn be_tamsd(
+    duration: f64,
+    delta: f64,
+    time_step: f64,
+    quad_order: usize,
+    rule: &str,
+) -> XPyResult<f64> {
     let be = BrownianExcursion::new();
-    let result = be.tamsd(duration, delta, time_step, quad_order)?;
+    let result = tamsd_with_rule(
+        rule,
+        || be.tamsd(duration, delta, time_step, quad_order),
+        |t| be.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the ensemble average of the time-averaged mean square displacement of Brownian excursion.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn be_eatamsd(
     duration: f64,
     delta: f64,
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let be = BrownianExcursion::new();
-    let result = be.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || be.tamsd(duration, delta, time_step, quad_order),
+        |t| be.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
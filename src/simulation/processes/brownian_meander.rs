@@ -1,6 +1,10 @@
 use crate::{
     XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    simulation::{
+        Kind, PyArrayPair, checked_domain, checked_num_steps, checked_t0, checked_thin,
+        continuous::{eatamsd_with_rule, tamsd_with_rule},
+        shift_times, thin_path, vec_to_pyarray,
+    },
 };
 use diffusionx::simulation::{continuous::BrownianMeander, prelude::*};
 use pyo3::prelude::*;
@@ -10,14 +14,24 @@ use pyo3_stub_gen::derive::gen_stub_pyfunction;
 /// Simulate Brownian meander.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (duration, time_step, t0 = 0.0, thin = 1))]
 pub fn meander_simulate(
     py: Python<'_>,
     duration: f64,
     time_step: f64,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
     let bm = BrownianMeander::new();
     let (times, positions) = bm.simulate(duration, time_step)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
 }
 
 /// Get the raw moment of Brownian meander.
@@ -28,10 +42,9 @@ pub fn meander_raw_moment(
     time_step: f64,
     order: i32,
     particles: usize,
-) -> XPyResult<f64> {
-    let bm = BrownianMeander::new();
-    let result = bm.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+) -> PyResult<f64> {
+    let kind = Kind::BrownianMeander(BrownianMeander::new());
+    kind.raw_moment(duration, time_step, order, particles, false)
 }
 
 /// Get the central moment of Brownian meander.
@@ -43,9 +56,8 @@ pub fn meander_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let bm = BrownianMeander::new();
-    let result = bm.central_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::BrownianMeander(BrownianMeander::new());
+    kind.central_moment(duration, time_step, order, particles)
 }
 
 /// Get the fractional raw moment of Brownian meander.
@@ -57,6 +69,7 @@ pub fn meander_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let bm = BrownianMeander::new();
     let result = bm.frac_raw_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -71,6 +84,7 @@ pub fn meander_frac_central_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let bm = BrownianMeander::new();
     let result = bm.frac_central_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -80,6 +94,7 @@ pub fn meander_frac_central_moment(
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
 pub fn meander_fpt(time_step: f64, domain: (f64, f64)) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let bm = BrownianMeander::new();
     let result = bm.fpt(domain, time_step)?;
     Ok(result)
@@ -94,6 +109,7 @@ pub fn meander_fpt_raw_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let bm = BrownianMeander::new();
     let fpt = FirstPassageTime::new(&bm, domain)?;
     let result = fpt.raw_moment(order, particles, 1.0, time_step)?;
@@ -109,6 +125,7 @@ pub fn meander_fpt_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let bm = BrownianMeander::new();
     let fpt = FirstPassageTime::new(&bm, domain)?;
     let result = fpt.central_moment(order, particles, 1.0, time_step)?;
@@ -123,9 +140,8 @@ pub fn meander_occupation_time(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let bm = BrownianMeander::new();
-    let result = bm.occupation_time(domain, duration, time_step)?;
-    Ok(result)
+    let kind = Kind::BrownianMeander(BrownianMeander::new());
+    kind.occupation_time(time_step, domain, duration)
 }
 
 /// Get the raw moment of the occupation time of Brownian meander.
@@ -138,10 +154,8 @@ pub fn meander_occupation_time_raw_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let bm = BrownianMeander::new();
-    let oc = OccupationTime::new(&bm, domain, duration)?;
-    let result = oc.raw_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::BrownianMeander(BrownianMeander::new());
+    kind.occupation_time_raw_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the central moment of the occupation time of Brownian meander.
@@ -154,38 +168,57 @@ pub fn meander_occupation_time_central_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let bm = BrownianMeander::new();
-    let oc = OccupationTime::new(&bm, domain, duration)?;
-    let result = oc.central_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::BrownianMeander(BrownianMeander::new());
+    kind.occupation_time_central_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the time-averaged mean square displacement of Brownian meander.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn meander_tamsd(
     duration: f64,
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let bm = BrownianMeander::new();
-    let result = bm.tamsd(duration, delta, time_step, quad_order)?;
+    let result = tamsd_with_rule(
+        rule,
+        || bm.tamsd(duration, delta, time_step, quad_order),
+        |t| bm.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the ensemble average of the time-averaged mean square displacement of Brownian meander.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn meander_eatamsd(
     duration: f64,
     delta: f64,
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let bm = BrownianMeander::new();
-    let result = bm.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || bm.tamsd(duration, delta, time_step, quad_order),
+        |t| bm.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
@@ -1,6 +1,10 @@
 use crate::{
     XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    simulation::{
+        Kind, PyArrayPair, checked_num_steps, checked_t0, checked_thin,
+        continuous::{eatamsd_with_rule, tamsd_with_rule},
+        shift_times, thin_path, vec_to_pyarray,
+    },
 };
 use diffusionx::simulation::{
     continuous::{AsymmetricCauchy, Cauchy},
@@ -13,15 +17,25 @@ use pyo3_stub_gen::derive::gen_stub_pyfunction;
 /// Simulate Cauchy process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, duration, time_step, t0 = 0.0, thin = 1))]
 pub fn cauchy_simulate(
     py: Python<'_>,
     start_position: f64,
     duration: f64,
     time_step: f64,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
     let cauchy = Cauchy::new(start_position);
     let (times, positions) = cauchy.simulate(duration, time_step)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
 }
 
 /// Get the raw moment of Cauchy process.
@@ -33,10 +47,9 @@ pub fn cauchy_raw_moment(
     time_step: f64,
     order: i32,
     particles: usize,
-) -> XPyResult<f64> {
-    let cauchy = Cauchy::new(start_position);
-    let result = cauchy.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+) -> PyResult<f64> {
+    let kind = Kind::Cauchy(Cauchy::new(start_position));
+    kind.raw_moment(duration, time_step, order, particles, false)
 }
 
 /// Get the central moment of Cauchy process.
@@ -49,9 +62,8 @@ pub fn cauchy_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let cauchy = Cauchy::new(start_position);
-    let result = cauchy.central_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Cauchy(Cauchy::new(start_position));
+    kind.central_moment(duration, time_step, order, particles)
 }
 
 /// Get the fractional raw moment of Cauchy process.
@@ -64,6 +76,7 @@ pub fn cauchy_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let cauchy = Cauchy::new(start_position);
     let result = cauchy.frac_raw_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -79,6 +92,7 @@ pub fn cauchy_frac_central_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let cauchy = Cauchy::new(start_position);
     let result = cauchy.frac_central_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -93,9 +107,8 @@ pub fn cauchy_fpt(
     domain: (f64, f64),
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let cauchy = Cauchy::new(start_position);
-    let result = cauchy.fpt(domain, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Cauchy(Cauchy::new(start_position));
+    kind.fpt(start_position, time_step, domain, max_duration)
 }
 
 /// Get the raw moment of the first passage time of Cauchy process.
@@ -109,10 +122,15 @@ pub fn cauchy_fpt_raw_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let cauchy = Cauchy::new(start_position);
-    let fpt = FirstPassageTime::new(&cauchy, domain)?;
-    let result = fpt.raw_moment(order, particles, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Cauchy(Cauchy::new(start_position));
+    kind.fpt_raw_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
 }
 
 /// Get the central moment of the first passage time of Cauchy process.
@@ -126,10 +144,15 @@ pub fn cauchy_fpt_central_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let cauchy = Cauchy::new(start_position);
-    let fpt = FirstPassageTime::new(&cauchy, domain)?;
-    let result = fpt.central_moment(order, particles, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Cauchy(Cauchy::new(start_position));
+    kind.fpt_central_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
 }
 
 /// Get the occupation time of Cauchy process.
@@ -141,9 +164,8 @@ pub fn cauchy_occupation_time(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let cauchy = Cauchy::new(start_position);
-    let result = cauchy.occupation_time(domain, duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Cauchy(Cauchy::new(start_position));
+    kind.occupation_time(time_step, domain, duration)
 }
 
 /// Get the raw moment of the occupation time of Cauchy process.
@@ -157,10 +179,8 @@ pub fn cauchy_occupation_time_raw_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let cauchy = Cauchy::new(start_position);
-    let oc = OccupationTime::new(&cauchy, domain, duration)?;
-    let result = oc.raw_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Cauchy(Cauchy::new(start_position));
+    kind.occupation_time_raw_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the central moment of the occupation time of Cauchy process.
@@ -174,30 +194,39 @@ pub fn cauchy_occupation_time_central_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let cauchy = Cauchy::new(start_position);
-    let oc = OccupationTime::new(&cauchy, domain, duration)?;
-    let result = oc.central_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Cauchy(Cauchy::new(start_position));
+    kind.occupation_time_central_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the time-averaged mean square displacement of Cauchy process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn cauchy_tamsd(
     start_position: f64,
     duration: f64,
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let cauchy = Cauchy::new(start_position);
-    let result = cauchy.tamsd(duration, delta, time_step, quad_order)?;
+    let result = tamsd_with_rule(
+        rule,
+        || cauchy.tamsd(duration, delta, time_step, quad_order),
+        |t| cauchy.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the ensemble average of the time-averaged mean square displacement of Cauchy process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn cauchy_eatamsd(
     start_position: f64,
     duration: f64,
@@ -205,25 +234,45 @@ pub fn cauchy_eatamsd(
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let cauchy = Cauchy::new(start_position);
-    let result = cauchy.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || cauchy.tamsd(duration, delta, time_step, quad_order),
+        |t| cauchy.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Simulate asymmetric Cauchy process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, beta, duration, time_step, t0 = 0.0, thin = 1))]
 pub fn asymmetric_cauchy_simulate(
     py: Python<'_>,
     start_position: f64,
     beta: f64,
     duration: f64,
     time_step: f64,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
     let cauchy = AsymmetricCauchy::new(start_position, beta)?;
     let (times, positions) = cauchy.simulate(duration, time_step)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
 }
 
 /// Get the raw moment of asymmetric Cauchy process.
@@ -236,10 +285,9 @@ pub fn asymmetric_cauchy_raw_moment(
     time_step: f64,
     order: i32,
     particles: usize,
-) -> XPyResult<f64> {
-    let cauchy = AsymmetricCauchy::new(start_position, beta)?;
-    let result = cauchy.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+) -> PyResult<f64> {
+    let kind = Kind::AsymmetricCauchy(AsymmetricCauchy::new(start_position, beta)?);
+    kind.raw_moment(duration, time_step, order, particles, false)
 }
 
 /// Get the central moment of asymmetric Cauchy process.
@@ -253,9 +301,8 @@ pub fn asymmetric_cauchy_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let cauchy = AsymmetricCauchy::new(start_position, beta)?;
-    let result = cauchy.central_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricCauchy(AsymmetricCauchy::new(start_position, beta)?);
+    kind.central_moment(duration, time_step, order, particles)
 }
 
 /// Get the fractional raw moment of asymmetric Cauchy process.
@@ -269,6 +316,7 @@ pub fn asymmetric_cauchy_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let cauchy = AsymmetricCauchy::new(start_position, beta)?;
     let result = cauchy.frac_raw_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -285,6 +333,7 @@ pub fn asymmetric_cauchy_frac_central_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let cauchy = AsymmetricCauchy::new(start_position, beta)?;
     let result = cauchy.frac_central_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -300,9 +349,8 @@ pub fn asymmetric_cauchy_fpt(
     domain: (f64, f64),
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let cauchy = AsymmetricCauchy::new(start_position, beta)?;
-    let result = cauchy.fpt(domain, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricCauchy(AsymmetricCauchy::new(start_position, beta)?);
+    kind.fpt(start_position, time_step, domain, max_duration)
 }
 
 /// Get the raw moment of the first passage time of asymmetric Cauchy process.
@@ -317,10 +365,15 @@ pub fn asymmetric_cauchy_fpt_raw_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let cauchy = AsymmetricCauchy::new(start_position, beta)?;
-    let fpt = FirstPassageTime::new(&cauchy, domain)?;
-    let result = fpt.raw_moment(order, particles, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricCauchy(AsymmetricCauchy::new(start_position, beta)?);
+    kind.fpt_raw_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
 }
 
 /// Get the central moment of the first passage time of asymmetric Cauchy process.
@@ -335,10 +388,15 @@ pub fn asymmetric_cauchy_fpt_central_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let cauchy = AsymmetricCauchy::new(start_position, beta)?;
-    let fpt = FirstPassageTime::new(&cauchy, domain)?;
-    let result = fpt.central_moment(order, particles, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricCauchy(AsymmetricCauchy::new(start_position, beta)?);
+    kind.fpt_central_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
 }
 
 /// Get the occupation time of asymmetric Cauchy process.
@@ -351,9 +409,8 @@ pub fn asymmetric_cauchy_occupation_time(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let cauchy = AsymmetricCauchy::new(start_position, beta)?;
-    let result = cauchy.occupation_time(domain, duration, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricCauchy(AsymmetricCauchy::new(start_position, beta)?);
+    kind.occupation_time(time_step, domain, duration)
 }
 
 /// Get the raw moment of the occupation time of asymmetric Cauchy process.
@@ -368,10 +425,8 @@ pub fn asymmetric_cauchy_occupation_time_raw_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let cauchy = AsymmetricCauchy::new(start_position, beta)?;
-    let oc = OccupationTime::new(&cauchy, domain, duration)?;
-    let result = oc.raw_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricCauchy(AsymmetricCauchy::new(start_position, beta)?);
+    kind.occupation_time_raw_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the central moment of the occupation time of asymmetric Cauchy process.
@@ -386,15 +441,14 @@ pub fn asymmetric_cauchy_occupation_time_central_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let cauchy = AsymmetricCauchy::new(start_position, beta)?;
-    let oc = OccupationTime::new(&cauchy, domain, duration)?;
-    let result = oc.central_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricCauchy(AsymmetricCauchy::new(start_position, beta)?);
+    kind.occupation_time_central_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the time-averaged mean square displacement of asymmetric Cauchy process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, beta, duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn asymmetric_cauchy_tamsd(
     start_position: f64,
     beta: f64,
@@ -402,15 +456,25 @@ pub fn asymmetric_cauchy_tamsd(
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let cauchy = AsymmetricCauchy::new(start_position, beta)?;
-    let result = cauchy.tamsd(duration, delta, time_step, quad_order)?;
+    let result = tamsd_with_rule(
+        rule,
+        || cauchy.tamsd(duration, delta, time_step, quad_order),
+        |t| cauchy.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the ensemble average of the time-averaged mean square displacement of asymmetric Cauchy process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, beta, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn asymmetric_cauchy_eatamsd(
     start_position: f64,
     beta: f64,
@@ -419,8 +483,18 @@ pub fn asymmetric_cauchy_eatamsd(
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let cauchy = AsymmetricCauchy::new(start_position, beta)?;
-    let result = cauchy.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || cauchy.tamsd(duration, delta, time_step, quad_order),
+        |t| cauchy.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
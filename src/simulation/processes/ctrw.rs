@@ -1,11 +1,18 @@
 use crate::{
-    XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    XPyError, XPyResult,
+    random::{StableSampler, stream_rng, weibull},
+    simulation::{
+        PyArrayPair, checked_domain, checked_fpt_domain, checked_t0, checked_thin,
+        endpoint_raw_moment, get_max_points, par_endpoints, shift_times, summation::NeumaierSum,
+        thin_path, trivial_central_moment, trivial_raw_moment, vec_to_pyarray,
+    },
 };
 use diffusionx::simulation::{point::CTRW, prelude::*};
+use numpy::{IntoPyArray, Ix1, PyArray};
 use pyo3::prelude::*;
 #[cfg(feature = "stub_gen")]
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use rand::distr::Distribution;
 
 /// Simulate CTRW.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
@@ -37,6 +44,75 @@ pub fn ctrw_simulate_step(
     Ok(vec_to_pyarray(py, times, positions))
 }
 
+/// Simulate a CTRW with Weibull waiting times of shape `shape` and scale `scale`.
+///
+/// Jumps are symmetric stable `S_jump_beta(1, 0, 0)`, so `jump_beta = 2` gives `N(0, 2)` jumps.
+/// As with `ctrw_simulate_duration`, the path holds one point per jump plus a final point at
+/// `duration`. The times start at `t0`, and `thin` keeps every `thin`-th point and the last.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (shape, scale, jump_beta, start_position, duration, t0 = 0.0, thin = 1))]
+pub fn ctrw_weibull_simulate(
+    py: Python<'_>,
+    shape: f64,
+    scale: f64,
+    jump_beta: f64,
+    start_position: f64,
+    duration: f64,
+    t0: f64,
+    thin: usize,
+) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    let waiting = weibull(shape, scale)?;
+    if !(jump_beta > 0.0 && jump_beta <= 2.0) {
+        return Err(XPyError::ValueError(format!(
+            "jump_beta must be in (0, 2], got {jump_beta}"
+        )));
+    }
+    if !(duration > 0.0 && duration.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "duration must be positive and finite, got {duration}"
+        )));
+    }
+    let jump = StableSampler::new(jump_beta, 0.0, 1.0, 0.0)?;
+    let max_points = get_max_points();
+    let mut rng = stream_rng();
+    let mut times = vec![0.0];
+    loop {
+        let t = times[times.len() - 1] + waiting.sample(&mut rng);
+        if t >= duration {
+            break;
+        }
+        // 等待时间极短时跳跃次数没有上界，与网格模拟器共用点数上限
+        if times.len() + 1 >= max_points {
+            return Err(XPyError::ValueError(format!(
+                "the path exceeds the limit of {max_points} points before reaching duration \
+                 {duration}; increase scale or raise the limit with set_max_points"
+            )));
+        }
+        times.push(t);
+    }
+    times.push(duration);
+    // 跳长与等待时间取自同一个流；最后一个点只是把路径延伸到 `duration`
+    let jumps: Vec<f64> = (0..times.len() - 2)
+        .map(|_| jump.sample(&mut rng))
+        .collect();
+    let mut positions = Vec::with_capacity(times.len());
+    positions.push(start_position);
+    let mut x = start_position;
+    for jump in jumps {
+        x += jump;
+        positions.push(x);
+    }
+    positions.push(x);
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
+}
+
 /// Get the raw moment of CTRW.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
@@ -47,10 +123,58 @@ pub fn ctrw_raw_moment(
     duration: f64,
     order: i32,
     particles: usize,
-) -> XPyResult<f64> {
+) -> PyResult<f64> {
     let ctrw = CTRW::new(alpha, beta, start_position)?;
-    let result = ctrw.raw_moment(duration, order, particles)?;
-    Ok(result)
+    if let Some(result) = trivial_raw_moment(order, false)? {
+        return Ok(result);
+    }
+    endpoint_raw_moment(particles, order, || ctrw.simulate_with_duration(duration))
+}
+
+/// Get the raw moment of CTRW estimated from the first `c` paths, for each `c` in `checkpoints`.
+///
+/// `particles` paths are simulated once, so the entries show how the Monte Carlo estimate
+/// converges; the last one uses all paths if it equals `particles`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn ctrw_raw_moment_running(
+    py: Python<'_>,
+    alpha: f64,
+    beta: f64,
+    start_position: f64,
+    duration: f64,
+    order: i32,
+    particles: usize,
+    checkpoints: Vec<usize>,
+) -> XPyResult<Bound<'_, PyArray<f64, Ix1>>> {
+    if checkpoints.first().is_none_or(|&c| c == 0)
+        || checkpoints.windows(2).any(|pair| pair[0] >= pair[1])
+    {
+        return Err(XPyError::ValueError(
+            "checkpoints must be a non-empty, strictly increasing sequence of positive counts"
+                .to_string(),
+        ));
+    }
+    if let Some(&last) = checkpoints.last().filter(|&&last| last > particles) {
+        return Err(XPyError::ValueError(format!(
+            "the last checkpoint {last} exceeds particles = {particles}"
+        )));
+    }
+    let ctrw = CTRW::new(alpha, beta, start_position)?;
+    let endpoints = par_endpoints(particles, || ctrw.simulate_with_duration(duration))?;
+    let mut total = NeumaierSum::default();
+    let mut used = 0;
+    let result: Vec<f64> = checkpoints
+        .iter()
+        .map(|&count| {
+            endpoints[used..count]
+                .iter()
+                .for_each(|x| total.add(x.powi(order)));
+            used = count;
+            total.total() / count as f64
+        })
+        .collect();
+    Ok(result.into_pyarray(py))
 }
 
 /// Get the central moment of CTRW.
@@ -65,6 +189,9 @@ pub fn ctrw_central_moment(
     particles: usize,
 ) -> XPyResult<f64> {
     let ctrw = CTRW::new(alpha, beta, start_position)?;
+    if let Some(result) = trivial_central_moment(order)? {
+        return Ok(result);
+    }
     let result = ctrw.central_moment(duration, order, particles)?;
     Ok(result)
 }
@@ -111,6 +238,7 @@ pub fn ctrw_fpt(
     domain: (f64, f64),
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let ctrw = CTRW::new(alpha, beta, start_position)?;
     let result = ctrw.fpt(domain, max_duration)?;
     Ok(result)
@@ -128,6 +256,7 @@ pub fn ctrw_fpt_raw_moment(
     particles: usize,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let ctrw = CTRW::new(alpha, beta, start_position)?;
     let fpt = FirstPassageTime::new(&ctrw, domain)?;
     let result = fpt.raw_moment_p(order, particles, max_duration)?;
@@ -146,6 +275,7 @@ pub fn ctrw_fpt_central_moment(
     particles: usize,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let ctrw = CTRW::new(alpha, beta, start_position)?;
     let fpt = FirstPassageTime::new(&ctrw, domain)?;
     let result = fpt.central_moment_p(order, particles, max_duration)?;
@@ -162,6 +292,7 @@ pub fn ctrw_occupation_time(
     domain: (f64, f64),
     duration: f64,
 ) -> XPyResult<f64> {
+    checked_domain(domain)?;
     let ctrw = CTRW::new(alpha, beta, start_position)?;
     let result = ctrw.occupation_time(domain, duration)?;
     Ok(result)
@@ -179,6 +310,7 @@ pub fn ctrw_occupation_time_raw_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_domain(domain)?;
     let ctrw = CTRW::new(alpha, beta, start_position)?;
     let oc = OccupationTime::new(&ctrw, domain, duration)?;
     let result = oc.raw_moment_p(order, particles)?;
@@ -197,6 +329,7 @@ pub fn ctrw_occupation_time_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_domain(domain)?;
     let ctrw = CTRW::new(alpha, beta, start_position)?;
     let oc = OccupationTime::new(&ctrw, domain, duration)?;
     let result = oc.central_moment_p(order, particles)?;
@@ -1,25 +1,237 @@
 use crate::{
-    XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    XPyError, XPyResult,
+    random::fill_standard_normals,
+    simulation::{
+        PyArrayPair, checked_domain, checked_fpt_domain, checked_num_steps, checked_t0,
+        checked_thin,
+        continuous::{eatamsd_with_rule, tamsd_with_rule},
+        endpoint_raw_moment, shift_times, thin_path, trivial_central_moment, trivial_raw_moment,
+        vec_to_pyarray, velocity_autocorrelation,
+    },
 };
 use diffusionx::simulation::{continuous::FBm, prelude::*};
+use numpy::{IntoPyArray, Ix1, PyArray};
 use pyo3::prelude::*;
 #[cfg(feature = "stub_gen")]
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use rayon::prelude::*;
+use realfft::{RealFftPlanner, num_complex::Complex};
 
 /// Simulate FBm.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, hurst_exponent, duration, time_step, t0 = 0.0, thin = 1))]
 pub fn fbm_simulate(
     py: Python<'_>,
     start_position: f64,
     hurst_exponent: f64,
     duration: f64,
     time_step: f64,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
     let fbm = FBm::new(start_position, hurst_exponent)?;
     let (times, positions) = fbm.simulate(duration, time_step)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
+}
+
+/// Simulate FBm reflected at `lower` and `upper`.
+///
+/// The unconstrained FBm path `X` is folded pointwise into `[lower, upper]`: with
+/// `L = upper - lower` and `r = (X - lower) mod 2L`, the reflected position is
+/// `lower + r` if `r <= L` and `lower + 2L - r` otherwise. This is the iterated mirror
+/// image at both walls, so the increments keep their long-range correlations away from the
+/// walls; it is not the Skorokhod (minimal pushing) reflection, which differs from folding
+/// for `H != 0.5`. `t0` and `thin` are as in `fbm_simulate`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (start_position, hurst_exponent, duration, time_step, lower, upper, t0 = 0.0, thin = 1))]
+pub fn reflected_fbm_simulate(
+    py: Python<'_>,
+    start_position: f64,
+    hurst_exponent: f64,
+    duration: f64,
+    time_step: f64,
+    lower: f64,
+    upper: f64,
+    t0: f64,
+    thin: usize,
+) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    if !(hurst_exponent > 0.0 && hurst_exponent < 1.0) {
+        return Err(XPyError::ValueError(format!(
+            "hurst_exponent must be in (0, 1), got {hurst_exponent}"
+        )));
+    }
+    if !(lower.is_finite() && upper.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "lower and upper must be finite, got {lower} and {upper}"
+        )));
+    }
+    checked_fpt_domain(start_position, (lower, upper))?;
+    checked_num_steps(duration, time_step)?;
+    let fbm = FBm::new(start_position, hurst_exponent)?;
+    let (times, mut positions) = fbm.simulate(duration, time_step)?;
+    let width = upper - lower;
+    positions.iter_mut().for_each(|x| {
+        let r = (*x - lower).rem_euclid(2.0 * width);
+        *x = lower + if r <= width { r } else { 2.0 * width - r };
+    });
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
+}
+
+/// Simulate `n` samples of fractional Gaussian noise, the increments of FBm over steps of
+/// `time_step`.
+///
+/// The samples are drawn exactly by circulant embedding (Davies–Harte), with covariance
+/// `time_step^(2H) (|k + 1|^(2H) - 2 |k|^(2H) + |k - 1|^(2H)) / 2` at lag `k`, so no path
+/// has to be differenced. A given `seed` makes the output reproducible.
+///
+/// The noise has no time axis, so there is no `t0`. With `thin > 1` the output is the
+/// increments of the thinned FBm grid, i.e. the sums of `thin` consecutive samples (the
+/// last sum may be shorter), which matches `thin` of the path simulators.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (hurst_exponent, n, time_step, seed = None, thin = 1))]
+pub fn fgn_simulate(
+    py: Python<'_>,
+    hurst_exponent: f64,
+    n: usize,
+    time_step: f64,
+    seed: Option<u64>,
+    thin: usize,
+) -> XPyResult<Bound<'_, PyArray<f64, Ix1>>> {
+    checked_thin(thin)?;
+    if !(hurst_exponent > 0.0 && hurst_exponent < 1.0) {
+        return Err(XPyError::ValueError(format!(
+            "hurst_exponent must be in (0, 1), got {hurst_exponent}"
+        )));
+    }
+    if n == 0 {
+        return Err(XPyError::ValueError("n must be positive".to_string()));
+    }
+    if !(time_step > 0.0 && time_step.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "time_step must be positive and finite, got {time_step}"
+        )));
+    }
+    let mut noise = davies_harte_fgn(hurst_exponent, n, seed)?;
+    let scale = time_step.powf(hurst_exponent);
+    noise.iter_mut().for_each(|x| *x *= scale);
+    if thin > 1 {
+        // 对累积路径抽稀后再差分，使增量与路径模拟器的 `thin` 一致
+        let path: Vec<f64> = std::iter::once(0.0)
+            .chain(noise.iter().scan(0.0, |x, dx| {
+                *x += dx;
+                Some(*x)
+            }))
+            .collect();
+        noise = thin_path(path, thin)
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect();
+    }
+    Ok(noise.into_pyarray(py))
+}
+
+/// Davies–Harte 循环嵌入法生成单位步长的 fGn：`2n` 阶循环矩阵的特征值由实 FFT 给出，
+/// 以特征值加权的 Hermitian 复高斯向量经逆 FFT 后，前 `n` 个分量即为所求
+fn davies_harte_fgn(hurst_exponent: f64, n: usize, seed: Option<u64>) -> XPyResult<Vec<f64>> {
+    let m = 2 * n;
+    let two_h = 2.0 * hurst_exponent;
+    let autocovariance =
+        |k: f64| 0.5 * ((k + 1.0).powf(two_h) - 2.0 * k.powf(two_h) + (k - 1.0).abs().powf(two_h));
+    let mut row: Vec<f64> = (0..m)
+        .map(|j| autocovariance(j.min(m - j) as f64))
+        .collect();
+    let mut planner = RealFftPlanner::<f64>::new();
+    let mut eigenvalues = vec![Complex::default(); n + 1];
+    planner
+        .plan_fft_forward(m)
+        .process(&mut row, &mut eigenvalues)
+        .map_err(fft_error)?;
+    // 两端频率各用一个正态数，其余频率的实部与虚部各用一个
+    let mut normals = vec![0.0; m];
+    fill_standard_normals(&mut normals, seed);
+    let mut spectrum: Vec<Complex<f64>> = eigenvalues
+        .iter()
+        .enumerate()
+        .map(|(k, lambda)| {
+            // 舍入误差可能使特征值略小于零
+            let weight = (lambda.re.max(0.0) / m as f64).sqrt();
+            match k {
+                0 => Complex::new(weight * normals[0], 0.0),
+                k if k == n => Complex::new(weight * normals[1], 0.0),
+                k => {
+                    Complex::new(normals[2 * k], normals[2 * k + 1])
+                        * (weight * std::f64::consts::FRAC_1_SQRT_2)
+                }
+            }
+        })
+        .collect();
+    let mut noise = vec![0.0; m];
+    planner
+        .plan_fft_inverse(m)
+        .process(&mut spectrum, &mut noise)
+        .map_err(fft_error)?;
+    noise.truncate(n);
+    Ok(noise)
+}
+
+fn fft_error(error: realfft::FftError) -> XPyError {
+    XPyError::ValueError(error.to_string())
+}
+
+/// Get the ensemble-averaged velocity autocorrelation function of FBm.
+///
+/// Velocities are the discrete increments divided by the time step; returns the lags in time
+/// units and `C(tau) = E[v(t) v(t + tau)]` for `0..=max_lag` steps.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn fbm_vacf(
+    py: Python<'_>,
+    start_position: f64,
+    hurst_exponent: f64,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+    max_lag: usize,
+) -> XPyResult<PyArrayPair<'_>> {
+    checked_num_steps(duration, time_step)?;
+    let fbm = FBm::new(start_position, hurst_exponent)?;
+    if particles == 0 {
+        return Err(XPyError::ValueError(
+            "particles must be positive".to_string(),
+        ));
+    }
+    let total = (0..particles)
+        .into_par_iter()
+        .map(|_| -> XPyResult<Vec<f64>> {
+            let (times, positions) = fbm.simulate(duration, time_step)?;
+            Ok(velocity_autocorrelation(&times, &positions, max_lag))
+        })
+        .try_reduce(
+            || vec![0.0; max_lag + 1],
+            |mut acc, vacf| {
+                acc.iter_mut().zip(vacf).for_each(|(a, c)| *a += c);
+                Ok(acc)
+            },
+        )?;
+    let lags = (0..=max_lag).map(|lag| lag as f64 * time_step).collect();
+    let vacf = total.into_iter().map(|c| c / particles as f64).collect();
+    Ok(vec_to_pyarray(py, lags, vacf))
 }
 
 /// Get the raw moment of FBm.
@@ -32,10 +244,13 @@ pub fn fbm_raw_moment(
     time_step: f64,
     order: i32,
     particles: usize,
-) -> XPyResult<f64> {
+) -> PyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let fbm = FBm::new(start_position, hurst_exponent)?;
-    let result = fbm.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    if let Some(result) = trivial_raw_moment(order, false)? {
+        return Ok(result);
+    }
+    endpoint_raw_moment(particles, order, || fbm.simulate(duration, time_step))
 }
 
 /// Get the central moment of FBm.
@@ -49,7 +264,11 @@ pub fn fbm_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let fbm = FBm::new(start_position, hurst_exponent)?;
+    if let Some(result) = trivial_central_moment(order)? {
+        return Ok(result);
+    }
     let result = fbm.central_moment(duration, order, particles, time_step)?;
     Ok(result)
 }
@@ -65,6 +284,7 @@ pub fn fbm_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let fbm = FBm::new(start_position, hurst_exponent)?;
     let result = fbm.frac_raw_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -81,6 +301,7 @@ pub fn fbm_frac_central_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let fbm = FBm::new(start_position, hurst_exponent)?;
     let result = fbm.frac_central_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -96,6 +317,7 @@ pub fn fbm_fpt(
     domain: (f64, f64),
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let fbm = FBm::new(start_position, hurst_exponent)?;
     let result = fbm.fpt(domain, max_duration, time_step)?;
     Ok(result)
@@ -113,6 +335,7 @@ pub fn fbm_fpt_raw_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let fbm = FBm::new(start_position, hurst_exponent)?;
     let fpt = FirstPassageTime::new(&fbm, domain)?;
     let result = fpt.raw_moment(order, particles, max_duration, time_step)?;
@@ -131,6 +354,7 @@ pub fn fbm_fpt_central_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let fbm = FBm::new(start_position, hurst_exponent)?;
     let fpt = FirstPassageTime::new(&fbm, domain)?;
     let result = fpt.central_moment(order, particles, max_duration, time_step)?;
@@ -147,6 +371,7 @@ pub fn fbm_occupation_time(
     domain: (f64, f64),
     duration: f64,
 ) -> XPyResult<f64> {
+    checked_domain(domain)?;
     let fbm = FBm::new(start_position, hurst_exponent)?;
     let result = fbm.occupation_time(domain, duration, time_step)?;
     Ok(result)
@@ -164,6 +389,8 @@ pub fn fbm_occupation_time_raw_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    checked_domain(domain)?;
     let fbm = FBm::new(start_position, hurst_exponent)?;
     let oc = OccupationTime::new(&fbm, domain, duration)?;
     let result = oc.raw_moment(order, particles, time_step)?;
@@ -182,6 +409,8 @@ pub fn fbm_occupation_time_central_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    checked_domain(domain)?;
     let fbm = FBm::new(start_position, hurst_exponent)?;
     let oc = OccupationTime::new(&fbm, domain, duration)?;
     let result = oc.central_moment(order, particles, time_step)?;
@@ -191,6 +420,7 @@ pub fn fbm_occupation_time_central_moment(
 /// Get the time-averaged mean squared displacement of FBm.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, hurst_exponent, duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn fbm_tamsd(
     start_position: f64,
     hurst_exponent: f64,
@@ -198,15 +428,25 @@ pub fn fbm_tamsd(
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let fbm = FBm::new(start_position, hurst_exponent)?;
-    let result = fbm.tamsd(duration, delta, time_step, quad_order)?;
+    let result = tamsd_with_rule(
+        rule,
+        || fbm.tamsd(duration, delta, time_step, quad_order),
+        |t| fbm.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the effective time-averaged mean squared displacement of FBm.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, hurst_exponent, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn fbm_eatamsd(
     start_position: f64,
     hurst_exponent: f64,
@@ -215,9 +455,19 @@ pub fn fbm_eatamsd(
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let fbm = FBm::new(start_position, hurst_exponent)?;
-    let result = fbm.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || fbm.tamsd(duration, delta, time_step, quad_order),
+        |t| fbm.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
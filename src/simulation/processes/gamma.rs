@@ -1,6 +1,11 @@
 use crate::{
     XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    simulation::{
+        Kind, PyArrayPair, checked_domain, checked_num_steps, checked_positive, checked_t0,
+        checked_thin,
+        continuous::{eatamsd_with_rule, tamsd_with_rule},
+        shift_times, thin_path, vec_to_pyarray,
+    },
 };
 use diffusionx::simulation::{continuous::Gamma, prelude::*};
 use pyo3::prelude::*;
@@ -10,19 +15,31 @@ use pyo3_stub_gen::derive::gen_stub_pyfunction;
 /// Simulate Gamma.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (shape, rate, duration, time_step, t0 = 0.0, thin = 1))]
 pub fn gamma_simulate(
     py: Python<'_>,
     shape: f64,
     rate: f64,
     duration: f64,
     time_step: f64,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
-    let gamma = Gamma::new(shape, rate)?;
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
+    let gamma = checked_gamma(shape, rate)?;
     let (times, positions) = gamma.simulate(duration, time_step)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
 }
 
 /// Get the raw moment of Gamma.
+///
+/// A negative `order` gives the inverse moment `E[X^order]`.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
 pub fn gamma_raw_moment(
@@ -32,10 +49,9 @@ pub fn gamma_raw_moment(
     time_step: f64,
     order: i32,
     particles: usize,
-) -> XPyResult<f64> {
-    let gamma = Gamma::new(shape, rate)?;
-    let result = gamma.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+) -> PyResult<f64> {
+    let kind = Kind::Gamma(checked_gamma(shape, rate)?);
+    kind.raw_moment(duration, time_step, order, particles, true)
 }
 
 /// Get the central moment of Gamma.
@@ -49,9 +65,8 @@ pub fn gamma_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let gamma = Gamma::new(shape, rate)?;
-    let result = gamma.central_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Gamma(checked_gamma(shape, rate)?);
+    kind.central_moment(duration, time_step, order, particles)
 }
 
 /// Get the fractional raw moment of Gamma.
@@ -65,7 +80,8 @@ pub fn gamma_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
-    let gamma = Gamma::new(shape, rate)?;
+    checked_num_steps(duration, time_step)?;
+    let gamma = checked_gamma(shape, rate)?;
     let result = gamma.frac_raw_moment(duration, order, particles, time_step)?;
     Ok(result)
 }
@@ -81,7 +97,8 @@ pub fn gamma_frac_central_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
-    let gamma = Gamma::new(shape, rate)?;
+    checked_num_steps(duration, time_step)?;
+    let gamma = checked_gamma(shape, rate)?;
     let result = gamma.frac_central_moment(duration, order, particles, time_step)?;
     Ok(result)
 }
@@ -96,7 +113,8 @@ pub fn gamma_fpt(
     domain: (f64, f64),
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let gamma = Gamma::new(shape, rate)?;
+    checked_domain(domain)?;
+    let gamma = checked_gamma(shape, rate)?;
     let result = gamma.fpt(domain, max_duration, time_step)?;
     Ok(result)
 }
@@ -113,7 +131,8 @@ pub fn gamma_fpt_raw_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let gamma = Gamma::new(shape, rate)?;
+    checked_domain(domain)?;
+    let gamma = checked_gamma(shape, rate)?;
     let fpt = FirstPassageTime::new(&gamma, domain)?;
     let result = fpt.raw_moment(order, particles, max_duration, time_step)?;
     Ok(result)
@@ -131,7 +150,8 @@ pub fn gamma_fpt_central_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let gamma = Gamma::new(shape, rate)?;
+    checked_domain(domain)?;
+    let gamma = checked_gamma(shape, rate)?;
     let fpt = FirstPassageTime::new(&gamma, domain)?;
     let result = fpt.central_moment(order, particles, max_duration, time_step)?;
     Ok(result)
@@ -147,9 +167,8 @@ pub fn gamma_occupation_time(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let gamma = Gamma::new(shape, rate)?;
-    let result = gamma.occupation_time(domain, duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Gamma(checked_gamma(shape, rate)?);
+    kind.occupation_time(time_step, domain, duration)
 }
 
 /// Get the raw moment of the occupation time of Gamma.
@@ -164,10 +183,8 @@ pub fn gamma_occupation_time_raw_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let gamma = Gamma::new(shape, rate)?;
-    let oc = OccupationTime::new(&gamma, domain, duration)?;
-    let result = oc.raw_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Gamma(checked_gamma(shape, rate)?);
+    kind.occupation_time_raw_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the central moment of the occupation time of Gamma.
@@ -182,15 +199,14 @@ pub fn gamma_occupation_time_central_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let gamma = Gamma::new(shape, rate)?;
-    let oc = OccupationTime::new(&gamma, domain, duration)?;
-    let result = oc.central_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Gamma(checked_gamma(shape, rate)?);
+    kind.occupation_time_central_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the time-averaged mean squared displacement of Gamma.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (shape, rate, duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn gamma_tamsd(
     shape: f64,
     rate: f64,
@@ -198,15 +214,25 @@ pub fn gamma_tamsd(
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
-    let gamma = Gamma::new(shape, rate)?;
-    let result = gamma.tamsd(duration, delta, time_step, quad_order)?;
+    let gamma = checked_gamma(shape, rate)?;
+    let result = tamsd_with_rule(
+        rule,
+        || gamma.tamsd(duration, delta, time_step, quad_order),
+        |t| gamma.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the effective time-averaged mean squared displacement of Gamma.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (shape, rate, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn gamma_eatamsd(
     shape: f64,
     rate: f64,
@@ -215,9 +241,19 @@ pub fn gamma_eatamsd(
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
-    let gamma = Gamma::new(shape, rate)?;
-    let result = gamma.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let gamma = checked_gamma(shape, rate)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || gamma.tamsd(duration, delta, time_step, quad_order),
+        |t| gamma.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
@@ -231,7 +267,7 @@ pub fn gamma_mean(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
-    let gamma = Gamma::new(shape, rate)?;
+    let gamma = checked_gamma(shape, rate)?;
     let result = gamma.mean(duration, particles, time_step)?;
     Ok(result)
 }
@@ -246,7 +282,14 @@ pub fn gamma_msd(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
-    let gamma = Gamma::new(shape, rate)?;
+    let gamma = checked_gamma(shape, rate)?;
     let result = gamma.msd(duration, particles, time_step)?;
     Ok(result)
 }
+
+/// 构造 Gamma 过程，逐项校验 `shape`、`rate` 为正
+pub(crate) fn checked_gamma(shape: f64, rate: f64) -> XPyResult<Gamma> {
+    checked_positive("shape", shape)?;
+    checked_positive("rate", rate)?;
+    Ok(Gamma::new(shape, rate)?)
+}
@@ -1,15 +1,34 @@
 use crate::{
-    XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    XPyError, XPyResult,
+    random::sobol_standard_normals,
+    simulation::{
+        Endpoint, PyArrayPair, PyArrayTriple, checked_domain, checked_finite, checked_fpt_domain,
+        checked_num_steps, checked_positive, checked_t0, checked_thin,
+        continuous::{eatamsd_with_rule, tamsd_with_rule},
+        endpoint_raw_moment, running_max, shift_times, simulate_with_endpoint, summation,
+        thin_path, trivial_central_moment, trivial_raw_moment, vec_to_pyarray,
+        warn_nonfinite_moment,
+    },
 };
-use diffusionx::simulation::{continuous::GeometricBm, prelude::*};
+use diffusionx::{
+    random::normal,
+    simulation::{continuous::GeometricBm, prelude::*},
+};
+use numpy::IntoPyArray;
 use pyo3::prelude::*;
 #[cfg(feature = "stub_gen")]
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use rayon::prelude::*;
 
 /// Simulate Geometric Brownian Motion.
+///
+/// See `bm_simulate` for `endpoint`. With `exact`, every step applies the exact log-normal
+/// transition `S exp((mu - sigma^2 / 2) dt + sigma sqrt(dt) Z)`, which keeps the path
+/// strictly positive and has no discretization error for any `time_step`; it is the
+/// recommended choice. Otherwise the upstream Euler scheme is used.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, mu, sigma, duration, time_step, endpoint = "extend", exact = false, t0 = 0.0, thin = 1))]
 pub fn gb_simulate(
     py: Python<'_>,
     start_position: f64,
@@ -17,15 +36,113 @@ pub fn gb_simulate(
     sigma: f64,
     duration: f64,
     time_step: f64,
+    endpoint: &str,
+    exact: bool,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    let endpoint = Endpoint::parse(endpoint)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
+    if exact && start_position <= 0.0 {
+        return Err(XPyError::ValueError(format!(
+            "exact simulation needs a positive start_position, got {start_position}"
+        )));
+    }
+    let (times, positions) = simulate_with_endpoint(
+        |end| {
+            if exact {
+                Ok(exact_path(start_position, mu, sigma, end, time_step))
+            } else {
+                gb.simulate(end, time_step)
+            }
+        },
+        |x, h| {
+            if exact {
+                Ok(exact_path(x, mu, sigma, h, h))
+            } else {
+                GeometricBm::new(x, mu, sigma)?.simulate(h, h)
+            }
+        },
+        duration,
+        time_step,
+        endpoint,
+    )?;
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
+}
+
+/// 以精确的对数正态转移在 `0, dt, 2dt, ..., duration` 网格上模拟（末步可能较短）
+fn exact_path(
+    start_position: f64,
+    mu: f64,
+    sigma: f64,
+    duration: f64,
+    time_step: f64,
+) -> (Vec<f64>, Vec<f64>) {
+    let steps = (duration / time_step).ceil() as usize;
+    let times: Vec<f64> = (0..=steps)
+        .map(|k| (k as f64 * time_step).min(duration))
+        .collect();
+    let noise = normal::standard_rands(steps);
+    let mut positions = Vec::with_capacity(steps + 1);
+    let mut log_value = start_position.ln();
+    positions.push(start_position);
+    for (t, z) in times.windows(2).zip(noise) {
+        let h = t[1] - t[0];
+        log_value += (mu - 0.5 * sigma * sigma) * h + sigma * h.sqrt() * z;
+        positions.push(log_value.exp());
+    }
+    (times, positions)
+}
+
+/// Simulate Geometric Brownian Motion together with its running maximum.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (start_position, mu, sigma, duration, time_step, t0 = 0.0, thin = 1))]
+pub fn gb_running_max_simulate(
+    py: Python<'_>,
+    start_position: f64,
+    mu: f64,
+    sigma: f64,
+    duration: f64,
+    time_step: f64,
+    t0: f64,
+    thin: usize,
+) -> XPyResult<PyArrayTriple<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
     let (times, positions) = gb.simulate(duration, time_step)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    let maximum = running_max(&positions);
+    Ok((
+        shift_times(thin_path(times, thin), t0).into_pyarray(py),
+        thin_path(positions, thin).into_pyarray(py),
+        thin_path(maximum, thin).into_pyarray(py),
+    ))
 }
 
 /// Get the raw moment of Geometric Brownian Motion.
+///
+/// A negative `order` gives the inverse moment `E[X^order]`, which needs a positive
+/// `start_position`.
+///
+/// With `control_variate`, the terminal value is used as a control variate against its
+/// mean under the simulated Euler scheme, `start_position * prod(1 + mu * dt_k)` over the
+/// steps of the grid. This mean is exact for the scheme, so the estimator targets the same
+/// discretized moment as the plain one, with a smaller variance.
+///
+/// With `qmc`, the terminal values are sampled exactly from the log-normal law using a
+/// scrambled Sobol sequence instead of simulated paths, so `time_step` is only validated.
+/// Quasi-Monte Carlo only helps for smooth functionals of few Gaussian draws such as this one.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, mu, sigma, duration, time_step, order, particles, control_variate = false, qmc = false))]
 pub fn gb_raw_moment(
     start_position: f64,
     mu: f64,
@@ -34,10 +151,118 @@ pub fn gb_raw_moment(
     time_step: f64,
     order: i32,
     particles: usize,
+    control_variate: bool,
+    qmc: bool,
+) -> PyResult<f64> {
+    let gb = checked_gb(start_position, mu, sigma)?;
+    if let Some(result) = trivial_raw_moment(order, start_position > 0.0)? {
+        return Ok(result);
+    }
+    if qmc {
+        if control_variate {
+            return Err(XPyError::ValueError(
+                "control_variate and qmc cannot be combined".to_string(),
+            )
+            .into());
+        }
+        checked_num_steps(duration, time_step)?;
+        if particles == 0 {
+            return Err(XPyError::ValueError("particles must be positive".to_string()).into());
+        }
+        let drift = (mu - 0.5 * sigma * sigma) * duration;
+        let scale = sigma * duration.sqrt();
+        let values: Vec<f64> = sobol_standard_normals(particles)
+            .into_par_iter()
+            .map(|z| (start_position * (drift + scale * z).exp()).powi(order))
+            .collect();
+        let result = summation::sum(values, true) / particles as f64;
+        warn_nonfinite_moment(order, result)?;
+        return Ok(result);
+    }
+    if control_variate {
+        let result = control_variate_raw_moment(
+            &gb,
+            start_position,
+            mu,
+            duration,
+            time_step,
+            order,
+            particles,
+        )?;
+        warn_nonfinite_moment(order, result)?;
+        return Ok(result);
+    }
+    endpoint_raw_moment(particles, order, || gb.simulate(duration, time_step))
+}
+
+/// 以终点值为控制变量（均值取 Euler 格式下的精确期望）估计原点矩，最优系数 `Cov(X^k, X) / Var(X)` 由独立的试验样本估计
+fn control_variate_raw_moment(
+    gb: &GeometricBm,
+    start_position: f64,
+    mu: f64,
+    duration: f64,
+    time_step: f64,
+    order: i32,
+    particles: usize,
 ) -> XPyResult<f64> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
-    let result = gb.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let pilot = terminal_values(
+        gb,
+        start_position,
+        mu,
+        duration,
+        time_step,
+        (particles / 10).max(2),
+    )?;
+    let coefficient = {
+        let n = pilot.len() as f64;
+        let controls: Vec<f64> = pilot.iter().map(|&(_, x)| x).collect();
+        let targets: Vec<f64> = controls.iter().map(|x| x.powi(order)).collect();
+        let control_mean = summation::sum(controls.clone(), true) / n;
+        let target_mean = summation::sum(targets.clone(), true) / n;
+        let covariance: Vec<f64> = controls
+            .iter()
+            .zip(&targets)
+            .map(|(x, y)| (x - control_mean) * (y - target_mean))
+            .collect();
+        let variance: Vec<f64> = controls
+            .iter()
+            .map(|x| (x - control_mean).powi(2))
+            .collect();
+        let variance = summation::sum(variance, true);
+        if variance > 0.0 {
+            summation::sum(covariance, true) / variance
+        } else {
+            0.0
+        }
+    };
+
+    let samples = terminal_values(gb, start_position, mu, duration, time_step, particles)?;
+    let adjusted: Vec<f64> = samples
+        .into_iter()
+        .map(|(mean, x)| x.powi(order) - coefficient * (x - mean))
+        .collect();
+    Ok(summation::sum(adjusted, true) / particles as f64)
+}
+
+/// 并行模拟 `particles` 条轨迹，返回每条轨迹终点值在 Euler 格式下的精确期望与终点值；
+/// Euler 格式每步乘以 `1 + μΔt + σΔW`，故期望为 `x₀ ∏(1 + μΔt_k)`，用实际网格计算
+fn terminal_values(
+    gb: &GeometricBm,
+    start_position: f64,
+    mu: f64,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+) -> XPyResult<Vec<(f64, f64)>> {
+    (0..particles)
+        .into_par_iter()
+        .map(|_| -> XPyResult<(f64, f64)> {
+            let (times, positions) = gb.simulate(duration, time_step)?;
+            let growth: f64 = times.windows(2).map(|w| 1.0 + mu * (w[1] - w[0])).product();
+            let x = positions.last().copied().unwrap_or(f64::NAN);
+            Ok((start_position * growth, x))
+        })
+        .collect()
 }
 
 /// Get the central moment of Geometric Brownian Motion.
@@ -52,7 +277,11 @@ pub fn gb_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
+    checked_num_steps(duration, time_step)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
+    if let Some(result) = trivial_central_moment(order)? {
+        return Ok(result);
+    }
     let result = gb.central_moment(duration, order, particles, time_step)?;
     Ok(result)
 }
@@ -69,7 +298,8 @@ pub fn gb_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
+    checked_num_steps(duration, time_step)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
     let result = gb.frac_raw_moment(duration, order, particles, time_step)?;
     Ok(result)
 }
@@ -86,7 +316,8 @@ pub fn gb_frac_central_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
+    checked_num_steps(duration, time_step)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
     let result = gb.frac_central_moment(duration, order, particles, time_step)?;
     Ok(result)
 }
@@ -102,7 +333,8 @@ pub fn gb_fpt(
     domain: (f64, f64),
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
+    checked_fpt_domain(start_position, domain)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
     let result = gb.fpt(domain, max_duration, time_step)?;
     Ok(result)
 }
@@ -120,7 +352,8 @@ pub fn gb_fpt_raw_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
+    checked_fpt_domain(start_position, domain)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
     let fpt = FirstPassageTime::new(&gb, domain)?;
     let result = fpt.raw_moment(order, particles, max_duration, time_step)?;
     Ok(result)
@@ -139,7 +372,8 @@ pub fn gb_fpt_central_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
+    checked_fpt_domain(start_position, domain)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
     let fpt = FirstPassageTime::new(&gb, domain)?;
     let result = fpt.central_moment(order, particles, max_duration, time_step)?;
     Ok(result)
@@ -156,7 +390,8 @@ pub fn gb_occupation_time(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
+    checked_domain(domain)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
     let result = gb.occupation_time(domain, duration, time_step)?;
     Ok(result)
 }
@@ -174,7 +409,9 @@ pub fn gb_occupation_time_raw_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
+    checked_num_steps(duration, time_step)?;
+    checked_domain(domain)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
     let oc = OccupationTime::new(&gb, domain, duration)?;
     let result = oc.raw_moment(order, particles, time_step)?;
     Ok(result)
@@ -193,7 +430,9 @@ pub fn gb_occupation_time_central_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
+    checked_num_steps(duration, time_step)?;
+    checked_domain(domain)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
     let oc = OccupationTime::new(&gb, domain, duration)?;
     let result = oc.central_moment(order, particles, time_step)?;
     Ok(result)
@@ -202,6 +441,7 @@ pub fn gb_occupation_time_central_moment(
 /// Get the time-averaged mean squared displacement of Geometric Brownian Motion.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, mu, sigma, duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn gb_tamsd(
     start_position: f64,
     mu: f64,
@@ -210,15 +450,25 @@ pub fn gb_tamsd(
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
-    let result = gb.tamsd(duration, delta, time_step, quad_order)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
+    let result = tamsd_with_rule(
+        rule,
+        || gb.tamsd(duration, delta, time_step, quad_order),
+        |t| gb.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the effective time-averaged mean squared displacement of Geometric Brownian Motion.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, mu, sigma, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn gb_eatamsd(
     start_position: f64,
     mu: f64,
@@ -228,9 +478,19 @@ pub fn gb_eatamsd(
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
-    let result = gb.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || gb.tamsd(duration, delta, time_step, quad_order),
+        |t| gb.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
@@ -245,7 +505,7 @@ pub fn gb_mean(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
     let result = gb.mean(duration, particles, time_step)?;
     Ok(result)
 }
@@ -261,7 +521,15 @@ pub fn gb_msd(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
-    let gb = GeometricBm::new(start_position, mu, sigma)?;
+    let gb = checked_gb(start_position, mu, sigma)?;
     let result = gb.msd(duration, particles, time_step)?;
     Ok(result)
 }
+
+/// 构造几何布朗运动，逐项校验 `start_position`、`mu` 有限、`sigma` 为正
+pub(crate) fn checked_gb(start_position: f64, mu: f64, sigma: f64) -> XPyResult<GeometricBm> {
+    checked_finite("start_position", start_position)?;
+    checked_finite("mu", mu)?;
+    checked_positive("sigma", sigma)?;
+    Ok(GeometricBm::new(start_position, mu, sigma)?)
+}
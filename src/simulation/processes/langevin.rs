@@ -1,18 +1,37 @@
 use crate::{
-    XPyResult,
-    simulation::{PyArrayPair, call_py_func, vec_to_pyarray},
+    XPyError, XPyResult,
+    random::fill_standard_normals,
+    simulation::{
+        PyArrayPair, PyArrayTriple, PyErrSlot, call_py_func, checked_domain, checked_fpt_domain,
+        checked_num_steps, checked_t0, checked_thin,
+        continuous::{eatamsd_with_rule, tamsd_with_rule},
+        endpoint_raw_moment, shift_times,
+        summation::{self, CentralMoments},
+        thin_path, trivial_central_moment, trivial_raw_moment, vec_to_pyarray,
+    },
 };
 use diffusionx::simulation::{
     continuous::{GeneralizedLangevin, Langevin, SubordinatedLangevin},
     prelude::*,
 };
+use numpy::{IntoPyArray, PyReadonlyArray1, ToPyArray};
 use pyo3::prelude::*;
 #[cfg(feature = "stub_gen")]
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use rayon::prelude::*;
 
 /// Simulate Langevin process.
+///
+/// The time axis starts at `t0`: the returned times and the time argument passed to
+/// `drift_func` and `diffusion_func` are both shifted by `t0`.
+///
+/// `interpretation` is `"ito"` or `"stratonovich"`. A Stratonovich equation is integrated
+/// as the equivalent Itô equation with the drift `f + g g' / 2`, where `g'(x, t)` is given
+/// by `diffusion_prime_func`; for additive noise (`g' = 0`) the two coincide. The same
+/// arguments apply to the Langevin moment functions.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, duration, time_step, t0 = 0.0, thin = 1, interpretation = "ito", diffusion_prime_func = None))]
 pub fn langevin_simulate(
     py: Python<'_>,
     drift_func: Py<PyAny>,
@@ -20,23 +39,240 @@ pub fn langevin_simulate(
     start_position: f64,
     duration: f64,
     time_step: f64,
-) -> XPyResult<PyArrayPair<'_>> {
+    t0: f64,
+    thin: usize,
+    interpretation: &str,
+    diffusion_prime_func: Option<Py<PyAny>>,
+) -> PyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
+    let errors = PyErrSlot::default();
     let langevin = {
-        let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
+        let drift = ito_drift(
+            &drift_func,
+            &diffusion_func,
+            diffusion_prime_func.as_ref(),
+            interpretation,
+            t0,
+            &errors,
+        )?;
+
+        let diffusion = |x: f64, t: f64| -> f64 { errors.call(&diffusion_func, (x, t0 + t)) };
+
+        Langevin::new(drift, diffusion, start_position).map_err(XPyError::from)?
+    };
 
-        let diffusion = |x: f64, t: f64| -> f64 { call_py_func(&diffusion_func, (x, t)) };
+    let path = langevin.simulate(duration, time_step);
+    errors.check()?;
+    let (times, positions) = path.map_err(XPyError::from)?;
+
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
+}
+
+/// Euler–Maruyama 使用的 Itô 漂移，时间参数平移 `t0`。Stratonovich 方程
+/// `dX = f dt + g ∘ dW` 等价于 Itô 方程 `dX = (f + g g' / 2) dt + g dW`，此时加上该伪漂移；
+/// Python 异常记入 `errors`
+fn ito_drift<'a>(
+    drift_func: &'a Py<PyAny>,
+    diffusion_func: &'a Py<PyAny>,
+    diffusion_prime_func: Option<&'a Py<PyAny>>,
+    interpretation: &str,
+    t0: f64,
+    errors: &'a PyErrSlot,
+) -> XPyResult<impl Fn(f64, f64) -> f64 + Send + Sync + Copy + 'a> {
+    let correction = stratonovich_prime(interpretation, diffusion_prime_func)?;
+    Ok(move |x: f64, t: f64| -> f64 {
+        let args = (x, t0 + t);
+        let drift = errors.call(drift_func, args);
+        match correction {
+            Some(prime) => {
+                drift + 0.5 * errors.call(diffusion_func, args) * errors.call(prime, args)
+            }
+            None => drift,
+        }
+    })
+}
+
+/// 校验 `interpretation`；Stratonovich 解释下返回伪漂移所需的 `diffusion_prime_func`
+fn stratonovich_prime<'a>(
+    interpretation: &str,
+    diffusion_prime_func: Option<&'a Py<PyAny>>,
+) -> XPyResult<Option<&'a Py<PyAny>>> {
+    match interpretation {
+        "ito" => Ok(None),
+        "stratonovich" => diffusion_prime_func.map(Some).ok_or_else(|| {
+            XPyError::ValueError(
+                "the Stratonovich interpretation requires diffusion_prime_func".to_string(),
+            )
+        }),
+        _ => Err(XPyError::ValueError(format!(
+            "interpretation must be 'ito' or 'stratonovich', got '{interpretation}'"
+        ))),
+    }
+}
+
+/// Simulate Langevin process together with the work done by the drift along the path.
+///
+/// The work is accumulated in the Stratonovich (midpoint) sense,
+/// `W_{i+1} = W_i + f((x_i + x_{i+1}) / 2, (t_i + t_{i+1}) / 2) (x_{i+1} - x_i)`, with `W_0 = 0`.
+/// Errors raised by `drift_func` or `diffusion_func`, during the simulation or while
+/// computing the work, are propagated unchanged.
+///
+/// As in `langevin_simulate`, the time axis starts at `t0` and `thin` keeps every
+/// `thin`-th point of all three arrays; the work is accumulated on the full grid.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, duration, time_step, t0 = 0.0, thin = 1))]
+pub fn langevin_simulate_with_work(
+    py: Python<'_>,
+    drift_func: Py<PyAny>,
+    diffusion_func: Py<PyAny>,
+    start_position: f64,
+    duration: f64,
+    time_step: f64,
+    t0: f64,
+    thin: usize,
+) -> PyResult<PyArrayTriple<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
+    let errors = PyErrSlot::default();
+    let langevin = {
+        let drift = |x: f64, t: f64| -> f64 { errors.call(&drift_func, (x, t0 + t)) };
+
+        let diffusion = |x: f64, t: f64| -> f64 { errors.call(&diffusion_func, (x, t0 + t)) };
+
+        Langevin::new(drift, diffusion, start_position).map_err(XPyError::from)?
+    };
+
+    let path = langevin.simulate(duration, time_step);
+    errors.check()?;
+    let (times, positions) = path.map_err(XPyError::from)?;
+
+    let mut work = Vec::with_capacity(positions.len());
+    work.push(0.0);
+    for (t, x) in times.windows(2).zip(positions.windows(2)) {
+        let midpoint = ((x[0] + x[1]) / 2.0, t0 + (t[0] + t[1]) / 2.0);
+        let force = drift_func
+            .call1(py, midpoint)
+            .and_then(|result| result.extract::<f64>(py))?;
+        let total = work.last().copied().unwrap_or(0.0);
+        work.push(total + force * (x[1] - x[0]));
+    }
+
+    Ok((
+        shift_times(thin_path(times, thin), t0).into_pyarray(py),
+        thin_path(positions, thin).into_pyarray(py),
+        thin_path(work, thin).into_pyarray(py),
+    ))
+}
+
+/// Simulate Langevin process with position-dependent drift and diffusion given as tables.
+///
+/// The coefficients are linearly interpolated over `x_grid`. Outside the grid they are
+/// clamped to the boundary values if `clamp` is true; otherwise leaving the grid is an error.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (x_grid, drift_table, diffusion_table, start_position, duration, time_step, clamp = true))]
+pub fn langevin_simulate_tabulated<'py>(
+    py: Python<'py>,
+    x_grid: PyReadonlyArray1<'py, f64>,
+    drift_table: PyReadonlyArray1<'py, f64>,
+    diffusion_table: PyReadonlyArray1<'py, f64>,
+    start_position: f64,
+    duration: f64,
+    time_step: f64,
+    clamp: bool,
+) -> XPyResult<PyArrayPair<'py>> {
+    checked_num_steps(duration, time_step)?;
+    let grid = x_grid
+        .as_slice()
+        .map_err(|e| XPyError::ValueError(e.to_string()))?;
+    let drift_table = LookupTable::new(grid, drift_table.as_array().to_vec(), "drift_table")?;
+    let diffusion_table =
+        LookupTable::new(grid, diffusion_table.as_array().to_vec(), "diffusion_table")?;
+    let langevin = {
+        let drift = |x: f64, _t: f64| -> f64 { drift_table.interpolate(x, clamp) };
+
+        let diffusion = |x: f64, _t: f64| -> f64 { diffusion_table.interpolate(x, clamp) };
 
         Langevin::new(drift, diffusion, start_position)?
     };
 
     let (times, positions) = langevin.simulate(duration, time_step)?;
+    if !clamp {
+        // 末点不再参与系数求值，仅检查其之前的点
+        let (lower, upper) = (grid[0], grid[grid.len() - 1]);
+        let inner = &positions[..positions.len().saturating_sub(1)];
+        if let Some(x) = inner.iter().find(|&&x| !(lower..=upper).contains(&x)) {
+            return Err(XPyError::ValueError(format!(
+                "the path reached {x}, outside the tabulated range [{lower}, {upper}]"
+            )));
+        }
+    }
 
     Ok(vec_to_pyarray(py, times, positions))
 }
 
+/// 按位置制表的系数，在 `grid` 上线性插值
+struct LookupTable<'a> {
+    grid: &'a [f64],
+    values: Vec<f64>,
+}
+
+impl<'a> LookupTable<'a> {
+    fn new(grid: &'a [f64], values: Vec<f64>, name: &str) -> XPyResult<Self> {
+        if grid.len() < 2 {
+            return Err(XPyError::ValueError(format!(
+                "x_grid must have at least two entries, got {}",
+                grid.len()
+            )));
+        }
+        if !grid.windows(2).all(|w| w[0] < w[1]) {
+            return Err(XPyError::ValueError(
+                "x_grid must be strictly increasing".to_string(),
+            ));
+        }
+        if values.len() != grid.len() {
+            return Err(XPyError::ValueError(format!(
+                "{name} must have the same length as x_grid ({}), got {}",
+                grid.len(),
+                values.len()
+            )));
+        }
+        if values.iter().any(|v| !v.is_finite()) {
+            return Err(XPyError::ValueError(format!("{name} must be finite")));
+        }
+        Ok(Self { grid, values })
+    }
+
+    /// 区间外按 `clamp` 取端点值或返回 NaN
+    fn interpolate(&self, x: f64, clamp: bool) -> f64 {
+        let last = self.grid.len() - 1;
+        if x.is_nan() || (!clamp && !(self.grid[0]..=self.grid[last]).contains(&x)) {
+            return f64::NAN;
+        }
+        if x <= self.grid[0] {
+            return self.values[0];
+        }
+        if x >= self.grid[last] {
+            return self.values[last];
+        }
+        let i = self.grid.partition_point(|&g| g <= x) - 1;
+        let w = (x - self.grid[i]) / (self.grid[i + 1] - self.grid[i]);
+        self.values[i] + w * (self.values[i + 1] - self.values[i])
+    }
+}
+
 /// Get the raw moment of Langevin process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, duration, order, particles, time_step, interpretation = "ito", diffusion_prime_func = None))]
 pub fn langevin_raw_moment(
     drift_func: Py<PyAny>,
     diffusion_func: Py<PyAny>,
@@ -45,22 +281,38 @@ pub fn langevin_raw_moment(
     order: i32,
     particles: usize,
     time_step: f64,
-) -> XPyResult<f64> {
+    interpretation: &str,
+    diffusion_prime_func: Option<Py<PyAny>>,
+) -> PyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    let errors = PyErrSlot::default();
     let langevin = {
-        let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
-
-        let diffusion = |x: f64, t: f64| -> f64 { call_py_func(&diffusion_func, (x, t)) };
-
-        Langevin::new(drift, diffusion, start_position)?
+        let drift = ito_drift(
+            &drift_func,
+            &diffusion_func,
+            diffusion_prime_func.as_ref(),
+            interpretation,
+            0.0,
+            &errors,
+        )?;
+
+        let diffusion = |x: f64, t: f64| -> f64 { errors.call(&diffusion_func, (x, t)) };
+
+        Langevin::new(drift, diffusion, start_position).map_err(XPyError::from)?
     };
 
-    let result = langevin.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    if let Some(result) = trivial_raw_moment(order, false)? {
+        return Ok(result);
+    }
+    let result = endpoint_raw_moment(particles, order, || langevin.simulate(duration, time_step));
+    errors.check()?;
+    result
 }
 
 /// Get the central moment of Langevin process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, duration, order, particles, time_step, interpretation = "ito", diffusion_prime_func = None))]
 pub fn langevin_central_moment(
     drift_func: Py<PyAny>,
     diffusion_func: Py<PyAny>,
@@ -69,22 +321,160 @@ pub fn langevin_central_moment(
     order: i32,
     particles: usize,
     time_step: f64,
-) -> XPyResult<f64> {
+    interpretation: &str,
+    diffusion_prime_func: Option<Py<PyAny>>,
+) -> PyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    let errors = PyErrSlot::default();
     let langevin = {
-        let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
+        let drift = ito_drift(
+            &drift_func,
+            &diffusion_func,
+            diffusion_prime_func.as_ref(),
+            interpretation,
+            0.0,
+            &errors,
+        )?;
+
+        let diffusion = |x: f64, t: f64| -> f64 { errors.call(&diffusion_func, (x, t)) };
+
+        Langevin::new(drift, diffusion, start_position).map_err(XPyError::from)?
+    };
 
-        let diffusion = |x: f64, t: f64| -> f64 { call_py_func(&diffusion_func, (x, t)) };
+    if let Some(result) = trivial_central_moment(order)? {
+        return Ok(result);
+    }
+    let result = langevin.central_moment(duration, order, particles, time_step);
+    errors.check()?;
+    Ok(result.map_err(XPyError::from)?)
+}
 
-        Langevin::new(drift, diffusion, start_position)?
+/// Get the raw or central moment of Langevin process with vectorized coefficients.
+///
+/// All particles are advanced together by Euler–Maruyama, and `drift_func(x, t)` and
+/// `diffusion_func(x, t)` are called once per step with the float64 array of all positions
+/// and the current time. Each must return a float64 array of the same length or a scalar,
+/// which is broadcast. This trades one Python call per particle and step for one per step.
+///
+/// `interpretation` and `diffusion_prime_func` are as in `langevin_simulate`; under the
+/// Stratonovich interpretation `diffusion_prime_func` is vectorized in the same way.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, duration, order, particles, time_step, central = false, interpretation = "ito", diffusion_prime_func = None))]
+pub fn langevin_vectorized_moment(
+    py: Python<'_>,
+    drift_func: Py<PyAny>,
+    diffusion_func: Py<PyAny>,
+    start_position: f64,
+    duration: f64,
+    order: i32,
+    particles: usize,
+    time_step: f64,
+    central: bool,
+    interpretation: &str,
+    diffusion_prime_func: Option<Py<PyAny>>,
+) -> PyResult<f64> {
+    let steps = checked_num_steps(duration, time_step)?;
+    let correction = stratonovich_prime(interpretation, diffusion_prime_func.as_ref())?;
+    if particles == 0 {
+        return Err(XPyError::ValueError("particles must be positive".to_string()).into());
+    }
+    let trivial = if central {
+        trivial_central_moment(order)?
+    } else {
+        trivial_raw_moment(order, false)?
     };
+    if let Some(result) = trivial {
+        return Ok(result);
+    }
+    let mut positions = vec![start_position; particles];
+    let mut noise = vec![0.0; particles];
+    let mut t = 0.0;
+    for i in 1..=steps {
+        let next = (i as f64 * time_step).min(duration);
+        let dt = next - t;
+        let drift = evaluate_vectorized(py, &drift_func, &positions, t, "drift_func")?;
+        let diffusion = evaluate_vectorized(py, &diffusion_func, &positions, t, "diffusion_func")?;
+        // Stratonovich 方程按等价的 Itô 方程推进，漂移加上 `g g' / 2`
+        let prime = correction
+            .map(|prime| evaluate_vectorized(py, prime, &positions, t, "diffusion_prime_func"))
+            .transpose()?;
+        fill_standard_normals(&mut noise, None);
+        let scale = dt.sqrt();
+        positions
+            .par_iter_mut()
+            .zip(noise.par_iter())
+            .enumerate()
+            .for_each(|(k, (x, xi))| {
+                let drift = match &prime {
+                    Some(prime) => drift.at(k) + 0.5 * diffusion.at(k) * prime.at(k),
+                    None => drift.at(k),
+                };
+                *x += drift * dt + diffusion.at(k) * scale * xi;
+            });
+        t = next;
+    }
+    let center = if central {
+        summation::mean(positions.clone(), true, false).0
+    } else {
+        0.0
+    };
+    let values = positions
+        .into_iter()
+        .map(|x| (x - center).powi(order))
+        .collect();
+    Ok(summation::mean(values, true, false).0)
+}
 
-    let result = langevin.central_moment(duration, order, particles, time_step)?;
-    Ok(result)
+/// 向量化系数的取值：标量广播到全部粒子，或逐粒子的数组
+enum Coefficient {
+    Scalar(f64),
+    Array(Vec<f64>),
+}
+
+impl Coefficient {
+    fn at(&self, k: usize) -> f64 {
+        match self {
+            Self::Scalar(value) => *value,
+            Self::Array(values) => values[k],
+        }
+    }
+}
+
+/// 以全部位置的数组调用 `func(x, t)`，结果须为标量或等长的 float64 一维数组
+fn evaluate_vectorized(
+    py: Python<'_>,
+    func: &Py<PyAny>,
+    positions: &[f64],
+    t: f64,
+    name: &str,
+) -> PyResult<Coefficient> {
+    let result = func.call1(py, (positions.to_pyarray(py), t))?;
+    if let Ok(value) = result.extract::<f64>(py) {
+        return Ok(Coefficient::Scalar(value));
+    }
+    let values = result
+        .extract::<PyReadonlyArray1<'_, f64>>(py)
+        .map_err(|_| {
+            XPyError::ValueError(format!("{name} must return a float or a 1-D float64 array"))
+        })?
+        .as_array()
+        .to_vec();
+    if values.len() != positions.len() {
+        return Err(XPyError::ValueError(format!(
+            "{name} returned {} values for {} particles",
+            values.len(),
+            positions.len()
+        ))
+        .into());
+    }
+    Ok(Coefficient::Array(values))
 }
 
 /// Get the fractional raw moment of Langevin process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, duration, order, particles, time_step, interpretation = "ito", diffusion_prime_func = None))]
 pub fn langevin_frac_raw_moment(
     drift_func: Py<PyAny>,
     diffusion_func: Py<PyAny>,
@@ -93,22 +483,35 @@ pub fn langevin_frac_raw_moment(
     order: f64,
     particles: usize,
     time_step: f64,
-) -> XPyResult<f64> {
+    interpretation: &str,
+    diffusion_prime_func: Option<Py<PyAny>>,
+) -> PyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    let errors = PyErrSlot::default();
     let langevin = {
-        let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
-
-        let diffusion = |x: f64, t: f64| -> f64 { call_py_func(&diffusion_func, (x, t)) };
-
-        Langevin::new(drift, diffusion, start_position)?
+        let drift = ito_drift(
+            &drift_func,
+            &diffusion_func,
+            diffusion_prime_func.as_ref(),
+            interpretation,
+            0.0,
+            &errors,
+        )?;
+
+        let diffusion = |x: f64, t: f64| -> f64 { errors.call(&diffusion_func, (x, t)) };
+
+        Langevin::new(drift, diffusion, start_position).map_err(XPyError::from)?
     };
 
-    let result = langevin.frac_raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let result = langevin.frac_raw_moment(duration, order, particles, time_step);
+    errors.check()?;
+    Ok(result.map_err(XPyError::from)?)
 }
 
 /// Get the fractional central moment of Langevin process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, duration, order, particles, time_step, interpretation = "ito", diffusion_prime_func = None))]
 pub fn langevin_frac_central_moment(
     drift_func: Py<PyAny>,
     diffusion_func: Py<PyAny>,
@@ -117,17 +520,29 @@ pub fn langevin_frac_central_moment(
     order: f64,
     particles: usize,
     time_step: f64,
-) -> XPyResult<f64> {
+    interpretation: &str,
+    diffusion_prime_func: Option<Py<PyAny>>,
+) -> PyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    let errors = PyErrSlot::default();
     let langevin = {
-        let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
-
-        let diffusion = |x: f64, t: f64| -> f64 { call_py_func(&diffusion_func, (x, t)) };
-
-        Langevin::new(drift, diffusion, start_position)?
+        let drift = ito_drift(
+            &drift_func,
+            &diffusion_func,
+            diffusion_prime_func.as_ref(),
+            interpretation,
+            0.0,
+            &errors,
+        )?;
+
+        let diffusion = |x: f64, t: f64| -> f64 { errors.call(&diffusion_func, (x, t)) };
+
+        Langevin::new(drift, diffusion, start_position).map_err(XPyError::from)?
     };
 
-    let result = langevin.frac_central_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let result = langevin.frac_central_moment(duration, order, particles, time_step);
+    errors.check()?;
+    Ok(result.map_err(XPyError::from)?)
 }
 
 /// Get the first passage time of Langevin process.
@@ -141,6 +556,7 @@ pub fn langevin_fpt(
     max_duration: f64,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -166,6 +582,7 @@ pub fn langevin_fpt_raw_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -191,6 +608,7 @@ pub fn langevin_fpt_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -214,6 +632,7 @@ pub fn langevin_occupation_time(
     duration: f64,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_domain(domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -238,6 +657,8 @@ pub fn langevin_occupation_time_raw_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    checked_domain(domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -263,6 +684,8 @@ pub fn langevin_occupation_time_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    checked_domain(domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -278,6 +701,7 @@ pub fn langevin_occupation_time_central_moment(
 /// Get the time-averaged mean squared displacement of Langevin process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn langevin_tamsd(
     drift_func: Py<PyAny>,
     diffusion_func: Py<PyAny>,
@@ -286,6 +710,7 @@ pub fn langevin_tamsd(
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
@@ -294,13 +719,22 @@ pub fn langevin_tamsd(
 
         Langevin::new(drift, diffusion, start_position)?
     };
-    let result = langevin.tamsd(duration, delta, time_step, quad_order)?;
+    let result = tamsd_with_rule(
+        rule,
+        || langevin.tamsd(duration, delta, time_step, quad_order),
+        |t| langevin.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the effective time-averaged mean squared displacement of Langevin process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn langevin_eatamsd(
     drift_func: Py<PyAny>,
     diffusion_func: Py<PyAny>,
@@ -310,6 +744,7 @@ pub fn langevin_eatamsd(
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
@@ -318,7 +753,16 @@ pub fn langevin_eatamsd(
 
         Langevin::new(drift, diffusion, start_position)?
     };
-    let result = langevin.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || langevin.tamsd(duration, delta, time_step, quad_order),
+        |t| langevin.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
@@ -369,6 +813,7 @@ pub fn langevin_msd(
 /// Py function wrapper for GeneralizedLangevin simulation
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, alpha, duration, time_step, t0 = 0.0, thin = 1))]
 pub fn generalized_langevin_simulate(
     py: Python<'_>,
     drift_func: Py<PyAny>,
@@ -377,18 +822,27 @@ pub fn generalized_langevin_simulate(
     alpha: f64,
     duration: f64,
     time_step: f64,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
     let langevin = {
-        let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
+        let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t0 + t)) };
 
-        let diffusion = |x: f64, t: f64| -> f64 { call_py_func(&diffusion_func, (x, t)) };
+        let diffusion = |x: f64, t: f64| -> f64 { call_py_func(&diffusion_func, (x, t0 + t)) };
 
         GeneralizedLangevin::new(drift, diffusion, start_position, alpha)?
     };
 
     let (times, positions) = langevin.simulate(duration, time_step)?;
 
-    Ok(vec_to_pyarray(py, times, positions))
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
 }
 
 /// Get the raw moment of GeneralizedLangevin process.
@@ -403,7 +857,8 @@ pub fn generalized_langevin_raw_moment(
     order: i32,
     particles: usize,
     time_step: f64,
-) -> XPyResult<f64> {
+) -> PyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -412,8 +867,10 @@ pub fn generalized_langevin_raw_moment(
         GeneralizedLangevin::new(drift, diffusion, start_position, alpha)?
     };
 
-    let result = langevin.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    if let Some(result) = trivial_raw_moment(order, false)? {
+        return Ok(result);
+    }
+    endpoint_raw_moment(particles, order, || langevin.simulate(duration, time_step))
 }
 
 /// Get the central moment of GeneralizedLangevin process.
@@ -429,6 +886,7 @@ pub fn generalized_langevin_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -437,6 +895,9 @@ pub fn generalized_langevin_central_moment(
         GeneralizedLangevin::new(drift, diffusion, start_position, alpha)?
     };
 
+    if let Some(result) = trivial_central_moment(order)? {
+        return Ok(result);
+    }
     let result = langevin.central_moment(duration, order, particles, time_step)?;
     Ok(result)
 }
@@ -454,6 +915,7 @@ pub fn generalized_langevin_frac_raw_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -479,6 +941,7 @@ pub fn generalized_langevin_frac_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -503,6 +966,7 @@ pub fn generalized_langevin_fpt(
     max_duration: f64,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -529,6 +993,7 @@ pub fn generalized_langevin_fpt_raw_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -555,6 +1020,7 @@ pub fn generalized_langevin_fpt_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -579,6 +1045,7 @@ pub fn generalized_langevin_occupation_time(
     duration: f64,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_domain(domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -604,6 +1071,8 @@ pub fn generalized_langevin_occupation_time_raw_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    checked_domain(domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -630,6 +1099,8 @@ pub fn generalized_langevin_occupation_time_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    checked_domain(domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -645,6 +1116,7 @@ pub fn generalized_langevin_occupation_time_central_moment(
 /// Get the time-averaged mean squared displacement of GeneralizedLangevin process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, alpha, duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn generalized_langevin_tamsd(
     drift_func: Py<PyAny>,
     diffusion_func: Py<PyAny>,
@@ -654,6 +1126,7 @@ pub fn generalized_langevin_tamsd(
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
@@ -662,13 +1135,22 @@ pub fn generalized_langevin_tamsd(
 
         GeneralizedLangevin::new(drift, diffusion, start_position, alpha)?
     };
-    let result = langevin.tamsd(duration, delta, time_step, quad_order)?;
+    let result = tamsd_with_rule(
+        rule,
+        || langevin.tamsd(duration, delta, time_step, quad_order),
+        |t| langevin.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the effective time-averaged mean squared displacement of GeneralizedLangevin process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, alpha, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn generalized_langevin_eatamsd(
     drift_func: Py<PyAny>,
     diffusion_func: Py<PyAny>,
@@ -679,6 +1161,7 @@ pub fn generalized_langevin_eatamsd(
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
@@ -687,7 +1170,16 @@ pub fn generalized_langevin_eatamsd(
 
         GeneralizedLangevin::new(drift, diffusion, start_position, alpha)?
     };
-    let result = langevin.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || langevin.tamsd(duration, delta, time_step, quad_order),
+        |t| langevin.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
@@ -740,6 +1232,7 @@ pub fn generalized_langevin_msd(
 /// Simulate SubordinatedLangevin process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, alpha, duration, time_step, t0 = 0.0, thin = 1))]
 pub fn subordinated_langevin_simulate(
     py: Python<'_>,
     drift_func: Py<PyAny>,
@@ -748,18 +1241,27 @@ pub fn subordinated_langevin_simulate(
     alpha: f64,
     duration: f64,
     time_step: f64,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
     let langevin = {
-        let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
+        let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t0 + t)) };
 
-        let diffusion = |x: f64, t: f64| -> f64 { call_py_func(&diffusion_func, (x, t)) };
+        let diffusion = |x: f64, t: f64| -> f64 { call_py_func(&diffusion_func, (x, t0 + t)) };
 
         SubordinatedLangevin::new(drift, diffusion, start_position, alpha)?
     };
 
     let (times, positions) = langevin.simulate(duration, time_step)?;
 
-    Ok(vec_to_pyarray(py, times, positions))
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
 }
 
 /// Get the raw moment of SubordinatedLangevin process.
@@ -774,7 +1276,8 @@ pub fn subordinated_langevin_raw_moment(
     order: i32,
     particles: usize,
     time_step: f64,
-) -> XPyResult<f64> {
+) -> PyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -783,8 +1286,10 @@ pub fn subordinated_langevin_raw_moment(
         SubordinatedLangevin::new(drift, diffusion, start_position, alpha)?
     };
 
-    let result = langevin.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    if let Some(result) = trivial_raw_moment(order, false)? {
+        return Ok(result);
+    }
+    endpoint_raw_moment(particles, order, || langevin.simulate(duration, time_step))
 }
 
 /// Get the central moment of SubordinatedLangevin process.
@@ -800,6 +1305,7 @@ pub fn subordinated_langevin_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -808,6 +1314,9 @@ pub fn subordinated_langevin_central_moment(
         SubordinatedLangevin::new(drift, diffusion, start_position, alpha)?
     };
 
+    if let Some(result) = trivial_central_moment(order)? {
+        return Ok(result);
+    }
     let result = langevin.central_moment(duration, order, particles, time_step)?;
     Ok(result)
 }
@@ -825,6 +1334,7 @@ pub fn subordinated_langevin_frac_raw_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -850,6 +1360,7 @@ pub fn subordinated_langevin_frac_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -874,6 +1385,7 @@ pub fn subordinated_langevin_fpt(
     max_duration: f64,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -900,6 +1412,7 @@ pub fn subordinated_langevin_fpt_raw_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -926,6 +1439,7 @@ pub fn subordinated_langevin_fpt_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -950,6 +1464,7 @@ pub fn subordinated_langevin_occupation_time(
     duration: f64,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_domain(domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -975,6 +1490,8 @@ pub fn subordinated_langevin_occupation_time_raw_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    checked_domain(domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -1001,6 +1518,8 @@ pub fn subordinated_langevin_occupation_time_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    checked_domain(domain)?;
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
 
@@ -1016,6 +1535,7 @@ pub fn subordinated_langevin_occupation_time_central_moment(
 /// Get the time-averaged mean squared displacement of SubordinatedLangevin process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, alpha, duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn subordinated_langevin_tamsd(
     drift_func: Py<PyAny>,
     diffusion_func: Py<PyAny>,
@@ -1025,6 +1545,7 @@ pub fn subordinated_langevin_tamsd(
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
@@ -1033,13 +1554,22 @@ pub fn subordinated_langevin_tamsd(
 
         SubordinatedLangevin::new(drift, diffusion, start_position, alpha)?
     };
-    let result = langevin.tamsd(duration, delta, time_step, quad_order)?;
+    let result = tamsd_with_rule(
+        rule,
+        || langevin.tamsd(duration, delta, time_step, quad_order),
+        |t| langevin.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the effective time-averaged mean squared displacement of SubordinatedLangevin process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (drift_func, diffusion_func, start_position, alpha, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn subordinated_langevin_eatamsd(
     drift_func: Py<PyAny>,
     diffusion_func: Py<PyAny>,
@@ -1050,6 +1580,7 @@ pub fn subordinated_langevin_eatamsd(
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let langevin = {
         let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
@@ -1058,10 +1589,59 @@ pub fn subordinated_langevin_eatamsd(
 
         SubordinatedLangevin::new(drift, diffusion, start_position, alpha)?
     };
-    let result = langevin.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || langevin.tamsd(duration, delta, time_step, quad_order),
+        |t| langevin.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
+/// Get the ergodicity-breaking parameter `Var(TAMSD) / E[TAMSD]^2` of SubordinatedLangevin process.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn subordinated_langevin_eb_parameter(
+    drift_func: Py<PyAny>,
+    diffusion_func: Py<PyAny>,
+    start_position: f64,
+    alpha: f64,
+    duration: f64,
+    delta: f64,
+    particles: usize,
+    time_step: f64,
+    quad_order: usize,
+) -> XPyResult<f64> {
+    if particles < 2 {
+        return Err(XPyError::ValueError(format!(
+            "particles must be at least 2 to estimate a variance, got {particles}"
+        )));
+    }
+    let langevin = {
+        let drift = |x: f64, t: f64| -> f64 { call_py_func(&drift_func, (x, t)) };
+
+        let diffusion = |x: f64, t: f64| -> f64 { call_py_func(&diffusion_func, (x, t)) };
+
+        SubordinatedLangevin::new(drift, diffusion, start_position, alpha)?
+    };
+    // 漂移与扩散系数回调 Python，需持有 GIL，故逐个粒子顺序计算
+    let mut moments = CentralMoments::new(2);
+    for _ in 0..particles {
+        moments.add(langevin.tamsd(duration, delta, time_step, quad_order)?);
+    }
+    let (mean, variance) = (moments.mean(), moments.central_moment(2));
+    if mean == 0.0 {
+        return Err(XPyError::ValueError(
+            "the ensemble mean of the TAMSD is zero, EB is undefined".to_string(),
+        ));
+    }
+    Ok(variance / (mean * mean))
+}
+
 /// Get the mean of SubordinatedLangevin process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
@@ -1,28 +1,146 @@
 use crate::{
-    XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    XPyError, XPyResult,
+    random::{InverseGaussian, StableSampler, stream_rng},
+    simulation::{
+        Kind, PyArrayPair, checked_finite, checked_num_steps, checked_t0, checked_thin,
+        continuous::{eatamsd_with_rule, tamsd_with_rule},
+        par_endpoints, shift_times, summation, thin_path, trivial_raw_moment, vec_to_pyarray,
+        vec_to_pyarray2, warn_nonfinite_moment,
+    },
 };
 use diffusionx::simulation::{
     continuous::{AsymmetricLevy, Levy},
     prelude::*,
 };
+use numpy::{Ix2, PyArray};
 use pyo3::prelude::*;
 #[cfg(feature = "stub_gen")]
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use rand::{Rng, distr::Distribution};
+use rand_distr::StandardNormal;
+use rayon::prelude::*;
 
 /// Simulate Levy process.
+///
+/// The increments over a step `dt` are `dt^(1 / alpha) S_alpha(1, 0, 0)`, drawn from the
+/// crate's random stream with the sampler of `StableGenerator`, so `set_seed` makes the
+/// paths reproducible. For `alpha = 2` the unit-scale stable law is `S_2(1, 0, 0) = N(0, 2)`,
+/// so the increments are `N(0, 2 dt)` and the process is Brownian motion with diffusion
+/// coefficient `D = 1`, i.e. `bm_simulate(start_position, 1.0, ...)`.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, alpha, duration, time_step, t0 = 0.0, thin = 1))]
 pub fn levy_simulate(
     py: Python<'_>,
     start_position: f64,
     alpha: f64,
     duration: f64,
     time_step: f64,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
-    let levy = Levy::new(start_position, alpha)?;
-    let (times, positions) = levy.simulate(duration, time_step)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    let num_steps = checked_num_steps(duration, time_step)?;
+    checked_finite("start_position", start_position)?;
+    let jump = StableSampler::new(alpha, 0.0, 1.0, 0.0)?;
+    let (times, positions) = levy_path(
+        start_position,
+        alpha,
+        &jump,
+        duration,
+        time_step,
+        num_steps,
+        &mut stream_rng(),
+    );
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
+}
+
+/// 用 `rng` 逐步生成一条 Lévy 轨迹，步长 `dt` 上的增量为 `dt^{1/α} S_α(1, 0, 0)`；
+/// 最后一步取剩余时长，使终点恰为 `duration`
+fn levy_path<R: Rng + ?Sized>(
+    start_position: f64,
+    alpha: f64,
+    jump: &StableSampler,
+    duration: f64,
+    time_step: f64,
+    num_steps: usize,
+    rng: &mut R,
+) -> (Vec<f64>, Vec<f64>) {
+    let last_step = duration - (num_steps - 1) as f64 * time_step;
+    let times: Vec<f64> = (0..num_steps)
+        .map(|k| k as f64 * time_step)
+        .chain(std::iter::once(duration))
+        .collect();
+    let mut positions = Vec::with_capacity(num_steps + 1);
+    let mut x = start_position;
+    positions.push(x);
+    for k in 0..num_steps {
+        let h = if k + 1 < num_steps {
+            time_step
+        } else {
+            last_step
+        };
+        x += h.powf(1.0 / alpha) * jump.sample(rng);
+        positions.push(x);
+    }
+    (times, positions)
+}
+
+/// Simulate Levy process for a batch of `(start_position, alpha, duration, time_step)` entries.
+///
+/// Every entry is validated before any simulation starts; the first invalid one
+/// raises an error naming its index, so no partial results are returned. The paths are
+/// simulated in parallel but equal those of successive `levy_simulate` calls under the
+/// same `set_seed`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn levy_simulate_sweep(
+    py: Python<'_>,
+    params: Vec<(f64, f64, f64, f64)>,
+) -> XPyResult<Vec<PyArrayPair<'_>>> {
+    let at_index = |index: usize, error: XPyError| match error {
+        XPyError::ValueError(msg) => XPyError::ValueError(format!("sweep entry {index}: {msg}")),
+        other => other,
+    };
+    let entries = params
+        .iter()
+        .enumerate()
+        .map(|(index, &(start_position, alpha, duration, time_step))| {
+            let num_steps =
+                checked_num_steps(duration, time_step).map_err(|e| at_index(index, e))?;
+            checked_finite("start_position", start_position).map_err(|e| at_index(index, e))?;
+            let jump = StableSampler::new(alpha, 0.0, 1.0, 0.0).map_err(|e| at_index(index, e))?;
+            Ok((start_position, alpha, jump, duration, time_step, num_steps))
+        })
+        .collect::<XPyResult<Vec<_>>>()?;
+    // 按条目顺序从全局随机流取生成器，与依次调用 `levy_simulate` 得到相同的轨迹
+    let rngs: Vec<_> = entries.iter().map(|_| stream_rng()).collect();
+    let results: Vec<_> = entries
+        .into_par_iter()
+        .zip(rngs)
+        .map(
+            |((start_position, alpha, jump, duration, time_step, num_steps), mut rng)| {
+                levy_path(
+                    start_position,
+                    alpha,
+                    &jump,
+                    duration,
+                    time_step,
+                    num_steps,
+                    &mut rng,
+                )
+            },
+        )
+        .collect();
+    Ok(results
+        .into_iter()
+        .map(|(times, positions)| vec_to_pyarray(py, times, positions))
+        .collect())
 }
 
 /// Get the first passage time of Levy process.
@@ -35,9 +153,8 @@ pub fn levy_fpt(
     domain: (f64, f64),
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let levy = Levy::new(start_position, alpha)?;
-    let result = levy.fpt(domain, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Levy(Levy::new(start_position, alpha)?);
+    kind.fpt(start_position, time_step, domain, max_duration)
 }
 
 /// Get the raw moment of the first passage time of Levy process.
@@ -52,10 +169,15 @@ pub fn levy_fpt_raw_moment(
     max_duration: f64,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
-    let levy = Levy::new(start_position, alpha)?;
-    let fpt = FirstPassageTime::new(&levy, domain)?;
-    let result = fpt.raw_moment(order, particles, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Levy(Levy::new(start_position, alpha)?);
+    kind.fpt_raw_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
 }
 
 /// Get the central moment of the first passage time of Levy process.
@@ -70,10 +192,15 @@ pub fn levy_fpt_central_moment(
     max_duration: f64,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
-    let levy = Levy::new(start_position, alpha)?;
-    let fpt = FirstPassageTime::new(&levy, domain)?;
-    let result = fpt.central_moment(order, particles, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Levy(Levy::new(start_position, alpha)?);
+    kind.fpt_central_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
 }
 
 /// Get the occupation time of Levy process.
@@ -86,9 +213,8 @@ pub fn levy_occupation_time(
     domain: (f64, f64),
     duration: f64,
 ) -> XPyResult<f64> {
-    let levy = Levy::new(start_position, alpha)?;
-    let result = levy.occupation_time(domain, duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Levy(Levy::new(start_position, alpha)?);
+    kind.occupation_time(time_step, domain, duration)
 }
 
 /// Get the raw moment of the occupation time of Levy process.
@@ -103,10 +229,8 @@ pub fn levy_occupation_time_raw_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
-    let levy = Levy::new(start_position, alpha)?;
-    let oc = OccupationTime::new(&levy, domain, duration)?;
-    let result = oc.raw_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Levy(Levy::new(start_position, alpha)?);
+    kind.occupation_time_raw_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the central moment of the occupation time of Levy process.
@@ -121,15 +245,14 @@ pub fn levy_occupation_time_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
-    let levy = Levy::new(start_position, alpha)?;
-    let oc = OccupationTime::new(&levy, domain, duration)?;
-    let result = oc.central_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Levy(Levy::new(start_position, alpha)?);
+    kind.occupation_time_central_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the time-averaged mean squared displacement of Levy process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, alpha, duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn levy_tamsd(
     start_position: f64,
     alpha: f64,
@@ -137,15 +260,25 @@ pub fn levy_tamsd(
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let levy = Levy::new(start_position, alpha)?;
-    let result = levy.tamsd(duration, delta, time_step, quad_order)?;
+    let result = tamsd_with_rule(
+        rule,
+        || levy.tamsd(duration, delta, time_step, quad_order),
+        |t| levy.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the effective time-averaged mean squared displacement of Levy process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, alpha, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn levy_eatamsd(
     start_position: f64,
     alpha: f64,
@@ -154,9 +287,19 @@ pub fn levy_eatamsd(
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let levy = Levy::new(start_position, alpha)?;
-    let result = levy.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || levy.tamsd(duration, delta, time_step, quad_order),
+        |t| levy.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
@@ -170,10 +313,64 @@ pub fn levy_raw_moment(
     time_step: f64,
     order: i32,
     particles: usize,
-) -> XPyResult<f64> {
-    let levy = Levy::new(start_position, alpha)?;
-    let result = levy.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+) -> PyResult<f64> {
+    let kind = Kind::Levy(Levy::new(start_position, alpha)?);
+    kind.raw_moment(duration, time_step, order, particles, false)
+}
+
+/// Get the raw moment of Levy process on a grid of `alphas` and `durations`.
+///
+/// Returns an array of shape `(len(alphas), len(durations))`; every cell is an independent
+/// ensemble of `particles` paths and the cells run in parallel. All parameters are
+/// validated before any simulation starts.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn levy_raw_moment_grid<'py>(
+    py: Python<'py>,
+    start_position: f64,
+    alphas: Vec<f64>,
+    durations: Vec<f64>,
+    time_step: f64,
+    order: i32,
+    particles: usize,
+) -> PyResult<Bound<'py, PyArray<f64, Ix2>>> {
+    if particles == 0 {
+        return Err(XPyError::ValueError("particles must be positive".to_string()).into());
+    }
+    let processes = alphas
+        .iter()
+        .enumerate()
+        .map(|(index, &alpha)| {
+            if !(alpha > 0.0 && alpha <= 2.0) {
+                return Err(XPyError::ValueError(format!(
+                    "alphas[{index}] must be in (0, 2], got {alpha}"
+                )));
+            }
+            Ok(Levy::new(start_position, alpha)?)
+        })
+        .collect::<XPyResult<Vec<_>>>()?;
+    for (index, &duration) in durations.iter().enumerate() {
+        checked_num_steps(duration, time_step).map_err(|e| match e {
+            XPyError::ValueError(msg) => XPyError::ValueError(format!("durations[{index}]: {msg}")),
+            other => other,
+        })?;
+    }
+    // 按行优先顺序遍历网格单元
+    let columns = durations.len();
+    let values = (0..processes.len() * columns)
+        .into_par_iter()
+        .map(|cell| {
+            let (levy, duration) = (&processes[cell / columns], durations[cell % columns]);
+            let endpoints = par_endpoints(particles, || levy.simulate(duration, time_step))?;
+            let values = endpoints.into_iter().map(|x| x.powi(order)).collect();
+            Ok(summation::mean(values, true, false).0)
+        })
+        .collect::<XPyResult<Vec<f64>>>()?;
+    // 警告需要持有 GIL，只在调用线程上对整个网格发出一次
+    if let Some(&value) = values.iter().find(|value| !value.is_finite()) {
+        warn_nonfinite_moment(order, value)?;
+    }
+    Ok(vec_to_pyarray2(py, alphas.len(), durations.len(), values)?)
 }
 
 /// Get the central moment of Levy process.
@@ -187,9 +384,8 @@ pub fn levy_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let levy = Levy::new(start_position, alpha)?;
-    let result = levy.central_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Levy(Levy::new(start_position, alpha)?);
+    kind.central_moment(duration, time_step, order, particles)
 }
 
 /// Get the fractional raw moment of Levy process.
@@ -203,6 +399,7 @@ pub fn levy_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let levy = Levy::new(start_position, alpha)?;
     let result = levy.frac_raw_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -219,6 +416,7 @@ pub fn levy_frac_central_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let levy = Levy::new(start_position, alpha)?;
     let result = levy.frac_central_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -227,6 +425,7 @@ pub fn levy_frac_central_moment(
 /// Simulate AsymmetricLevy process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, alpha, beta, duration, time_step, t0 = 0.0, thin = 1))]
 pub fn asymmetric_levy_simulate(
     py: Python<'_>,
     start_position: f64,
@@ -234,10 +433,19 @@ pub fn asymmetric_levy_simulate(
     beta: f64,
     duration: f64,
     time_step: f64,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
     let levy = AsymmetricLevy::new(start_position, alpha, beta)?;
     let (times, positions) = levy.simulate(duration, time_step)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
 }
 
 /// Get the first passage time of AsymmetricLevy process.
@@ -251,9 +459,8 @@ pub fn asymmetric_levy_fpt(
     domain: (f64, f64),
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let levy = AsymmetricLevy::new(start_position, alpha, beta)?;
-    let result = levy.fpt(domain, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricLevy(AsymmetricLevy::new(start_position, alpha, beta)?);
+    kind.fpt(start_position, time_step, domain, max_duration)
 }
 
 /// Get the raw moment of the first passage time of AsymmetricLevy process.
@@ -269,10 +476,15 @@ pub fn asymmetric_levy_fpt_raw_moment(
     max_duration: f64,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
-    let levy = AsymmetricLevy::new(start_position, alpha, beta)?;
-    let fpt = FirstPassageTime::new(&levy, domain)?;
-    let result = fpt.raw_moment(order, particles, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricLevy(AsymmetricLevy::new(start_position, alpha, beta)?);
+    kind.fpt_raw_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
 }
 
 /// Get the central moment of the first passage time of AsymmetricLevy process.
@@ -288,10 +500,15 @@ pub fn asymmetric_levy_fpt_central_moment(
     max_duration: f64,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
-    let levy = AsymmetricLevy::new(start_position, alpha, beta)?;
-    let fpt = FirstPassageTime::new(&levy, domain)?;
-    let result = fpt.central_moment(order, particles, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricLevy(AsymmetricLevy::new(start_position, alpha, beta)?);
+    kind.fpt_central_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
 }
 
 /// Get the occupation time of AsymmetricLevy process.
@@ -305,9 +522,8 @@ pub fn asymmetric_levy_occupation_time(
     domain: (f64, f64),
     duration: f64,
 ) -> XPyResult<f64> {
-    let levy = AsymmetricLevy::new(start_position, alpha, beta)?;
-    let result = levy.occupation_time(domain, duration, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricLevy(AsymmetricLevy::new(start_position, alpha, beta)?);
+    kind.occupation_time(time_step, domain, duration)
 }
 
 /// Get the raw moment of the occupation time of AsymmetricLevy process.
@@ -323,10 +539,8 @@ pub fn asymmetric_levy_occupation_time_raw_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
-    let levy = AsymmetricLevy::new(start_position, alpha, beta)?;
-    let oc = OccupationTime::new(&levy, domain, duration)?;
-    let result = oc.raw_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricLevy(AsymmetricLevy::new(start_position, alpha, beta)?);
+    kind.occupation_time_raw_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the central moment of the occupation time of AsymmetricLevy process.
@@ -342,15 +556,14 @@ pub fn asymmetric_levy_occupation_time_central_moment(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
-    let levy = AsymmetricLevy::new(start_position, alpha, beta)?;
-    let oc = OccupationTime::new(&levy, domain, duration)?;
-    let result = oc.central_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricLevy(AsymmetricLevy::new(start_position, alpha, beta)?);
+    kind.occupation_time_central_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the time-averaged mean squared displacement of AsymmetricLevy process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, alpha, beta, duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn asymmetric_levy_tamsd(
     start_position: f64,
     alpha: f64,
@@ -359,15 +572,25 @@ pub fn asymmetric_levy_tamsd(
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let levy = AsymmetricLevy::new(start_position, alpha, beta)?;
-    let result = levy.tamsd(duration, delta, time_step, quad_order)?;
+    let result = tamsd_with_rule(
+        rule,
+        || levy.tamsd(duration, delta, time_step, quad_order),
+        |t| levy.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the effective time-averaged mean squared displacement of AsymmetricLevy process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (start_position, alpha, beta, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn asymmetric_levy_eatamsd(
     start_position: f64,
     alpha: f64,
@@ -377,9 +600,19 @@ pub fn asymmetric_levy_eatamsd(
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
     let levy = AsymmetricLevy::new(start_position, alpha, beta)?;
-    let result = levy.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || levy.tamsd(duration, delta, time_step, quad_order),
+        |t| levy.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
@@ -394,10 +627,9 @@ pub fn asymmetric_levy_raw_moment(
     time_step: f64,
     order: i32,
     particles: usize,
-) -> XPyResult<f64> {
-    let levy = AsymmetricLevy::new(start_position, alpha, beta)?;
-    let result = levy.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+) -> PyResult<f64> {
+    let kind = Kind::AsymmetricLevy(AsymmetricLevy::new(start_position, alpha, beta)?);
+    kind.raw_moment(duration, time_step, order, particles, false)
 }
 
 /// Get the central moment of asymmetric Levy process.
@@ -412,9 +644,8 @@ pub fn asymmetric_levy_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let levy = AsymmetricLevy::new(start_position, alpha, beta)?;
-    let result = levy.central_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::AsymmetricLevy(AsymmetricLevy::new(start_position, alpha, beta)?);
+    kind.central_moment(duration, time_step, order, particles)
 }
 
 /// Get the fractional raw moment of asymmetric Levy process.
@@ -429,6 +660,7 @@ pub fn asymmetric_levy_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let levy = AsymmetricLevy::new(start_position, alpha, beta)?;
     let result = levy.frac_raw_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -446,7 +678,134 @@ pub fn asymmetric_levy_frac_central_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let levy = AsymmetricLevy::new(start_position, alpha, beta)?;
     let result = levy.frac_central_moment(duration, order, particles, time_step)?;
     Ok(result)
 }
+
+/// Simulate a normal-inverse Gaussian (NIG) Levy process.
+///
+/// The process is Brownian motion with drift `beta` run on an inverse Gaussian clock: over
+/// a step `h` the clock advances by `Z ~ IG(delta * h / gamma, (delta * h)^2)` with
+/// `gamma = sqrt(alpha^2 - beta^2)`, and the position by `mu * h + beta * Z + sqrt(Z) * N`.
+/// Unlike stable processes, all moments are finite; the mean at time `t` is
+/// `start_position + (mu + delta * beta / gamma) * t`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (alpha, beta, delta, mu, start_position, duration, time_step, t0 = 0.0, thin = 1))]
+pub fn nig_simulate(
+    py: Python<'_>,
+    alpha: f64,
+    beta: f64,
+    delta: f64,
+    mu: f64,
+    start_position: f64,
+    duration: f64,
+    time_step: f64,
+    t0: f64,
+    thin: usize,
+) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    let num_steps = checked_num_steps(duration, time_step)?;
+    let gamma = checked_nig(alpha, beta, delta, mu, start_position)?;
+    let last_step = duration - (num_steps - 1) as f64 * time_step;
+    let inverse_gaussian = |h: f64| InverseGaussian::new(delta * h / gamma, (delta * h).powi(2));
+    let (full, last) = (inverse_gaussian(time_step)?, inverse_gaussian(last_step)?);
+    let mut rng = stream_rng();
+    let times: Vec<f64> = (0..num_steps)
+        .map(|k| k as f64 * time_step)
+        .chain(std::iter::once(duration))
+        .collect();
+    let mut positions = Vec::with_capacity(num_steps + 1);
+    let mut x = start_position;
+    positions.push(x);
+    for k in 0..num_steps {
+        let (h, clock) = if k + 1 < num_steps {
+            (time_step, &full)
+        } else {
+            (last_step, &last)
+        };
+        x += nig_increment(&mut rng, clock, beta, mu, h);
+        positions.push(x);
+    }
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
+}
+
+/// Get the raw moment of a normal-inverse Gaussian (NIG) Levy process.
+///
+/// `X(duration)` is sampled exactly, since the NIG law is closed under convolution, so no
+/// time step is needed. Moments of every order are finite.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn nig_raw_moment(
+    alpha: f64,
+    beta: f64,
+    delta: f64,
+    mu: f64,
+    start_position: f64,
+    duration: f64,
+    order: i32,
+    particles: usize,
+) -> XPyResult<f64> {
+    let gamma = checked_nig(alpha, beta, delta, mu, start_position)?;
+    if !(duration > 0.0 && duration.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "duration must be positive and finite, got {duration}"
+        )));
+    }
+    if let Some(result) = trivial_raw_moment(order, false)? {
+        return Ok(result);
+    }
+    if particles == 0 {
+        return Err(XPyError::ValueError(
+            "particles must be positive".to_string(),
+        ));
+    }
+    let clock = InverseGaussian::new(delta * duration / gamma, (delta * duration).powi(2))?;
+    let values: Vec<f64> = (0..particles)
+        .into_par_iter()
+        .map_init(stream_rng, |rng, _| {
+            (start_position + nig_increment(rng, &clock, beta, mu, duration)).powi(order)
+        })
+        .collect();
+    Ok(summation::sum(values, true) / particles as f64)
+}
+
+/// 校验 NIG 参数 `alpha > |beta|`、`delta > 0`，返回 `gamma = sqrt(alpha^2 - beta^2)`
+fn checked_nig(alpha: f64, beta: f64, delta: f64, mu: f64, start_position: f64) -> XPyResult<f64> {
+    if !(alpha.is_finite() && alpha > beta.abs()) {
+        return Err(XPyError::ValueError(format!(
+            "alpha must be finite and greater than |beta|, got alpha = {alpha}, beta = {beta}"
+        )));
+    }
+    if !(delta > 0.0 && delta.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "delta must be positive and finite, got {delta}"
+        )));
+    }
+    if !(mu.is_finite() && start_position.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "mu and start_position must be finite, got {mu} and {start_position}"
+        )));
+    }
+    Ok((alpha - beta).sqrt() * (alpha + beta).sqrt())
+}
+
+/// NIG 过程在长为 `h` 的一步上的增量：逆高斯时钟 `Z` 上带漂移 `beta` 的布朗运动
+fn nig_increment<R: Rng + ?Sized>(
+    rng: &mut R,
+    clock: &InverseGaussian,
+    beta: f64,
+    mu: f64,
+    h: f64,
+) -> f64 {
+    let z = clock.sample(rng);
+    let n: f64 = StandardNormal.sample(rng);
+    mu * h + beta * z + z.sqrt() * n
+}
@@ -1,6 +1,9 @@
 use crate::{
     XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    simulation::{
+        PyArrayPair, checked_fpt_domain, checked_t0, checked_thin, endpoint_raw_moment,
+        shift_times, thin_path, trivial_central_moment, trivial_raw_moment, vec_to_pyarray,
+    },
 };
 use diffusionx::simulation::{continuous::LevyWalk, prelude::*};
 use pyo3::prelude::*;
@@ -10,16 +13,25 @@ use pyo3_stub_gen::derive::gen_stub_pyfunction;
 /// Simulate Levy walk.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (alpha, velocity, start_position, duration, t0 = 0.0, thin = 1))]
 pub fn levy_walk_simulate(
     py: Python<'_>,
     alpha: f64,
     velocity: f64,
     start_position: f64,
     duration: f64,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
     let levy_walk = LevyWalk::new(alpha, velocity, start_position)?;
     let (times, positions) = levy_walk.simulate_with_duration(duration)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
 }
 
 /// Get the raw moment of Levy walk.
@@ -32,10 +44,14 @@ pub fn levy_walk_raw_moment(
     duration: f64,
     order: i32,
     particles: usize,
-) -> XPyResult<f64> {
+) -> PyResult<f64> {
     let levy_walk = LevyWalk::new(alpha, velocity, start_position)?;
-    let result = levy_walk.raw_moment(duration, order, particles, 0.1)?;
-    Ok(result)
+    if let Some(result) = trivial_raw_moment(order, false)? {
+        return Ok(result);
+    }
+    endpoint_raw_moment(particles, order, || {
+        levy_walk.simulate_with_duration(duration)
+    })
 }
 
 /// Get the central moment of Levy walk.
@@ -50,6 +66,9 @@ pub fn levy_walk_central_moment(
     particles: usize,
 ) -> XPyResult<f64> {
     let levy_walk = LevyWalk::new(alpha, velocity, start_position)?;
+    if let Some(result) = trivial_central_moment(order)? {
+        return Ok(result);
+    }
     let result = levy_walk.central_moment(duration, order, particles, 0.1)?;
     Ok(result)
 }
@@ -96,6 +115,7 @@ pub fn levy_walk_fpt(
     domain: (f64, f64),
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_fpt_domain(start_position, domain)?;
     let levy_walk = LevyWalk::new(alpha, velocity, start_position)?;
     let result = levy_walk.fpt(domain, max_duration, 0.1)?;
     Ok(result)
@@ -1,15 +1,35 @@
 use crate::{
-    XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    XPyError, XPyResult,
+    analysis::cumulative_trapezoid,
+    random::{indexed_rng, sobol_standard_normals},
+    simulation::{
+        Endpoint, Kind, PyArrayPair, PyArrayTriple, checked_finite, checked_num_steps,
+        checked_positive, checked_t0, checked_thin,
+        continuous::{eatamsd_with_rule, tamsd_with_rule},
+        shift_times, simulate_with_endpoint, summation, thin_path, trivial_raw_moment,
+        vec_to_pyarray,
+    },
 };
-use diffusionx::simulation::{continuous::OrnsteinUhlenbeck, prelude::*};
+use diffusionx::{
+    random::normal,
+    simulation::{continuous::OrnsteinUhlenbeck, prelude::*},
+};
+use numpy::{IntoPyArray, Ix1, PyArray};
 use pyo3::prelude::*;
 #[cfg(feature = "stub_gen")]
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
+use rand::distr::Distribution;
+use rand_distr::StandardNormal;
+use rayon::prelude::*;
 
 /// Simulate Ornstein-Uhlenbeck process.
+///
+/// See `bm_simulate` for `endpoint`. A positive `burn_in` first runs the process for that
+/// time and discards it, so the returned path starts from the state reached at `burn_in`
+/// with times shifted back to 0.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (theta, sigma, start_position, duration, time_step, endpoint = "extend", burn_in = 0.0, t0 = 0.0, thin = 1))]
 pub fn ou_simulate(
     py: Python<'_>,
     theta: f64,
@@ -17,15 +37,96 @@ pub fn ou_simulate(
     start_position: f64,
     duration: f64,
     time_step: f64,
+    endpoint: &str,
+    burn_in: f64,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let (times, positions) = ou.simulate(duration, time_step)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    let endpoint = Endpoint::parse(endpoint)?;
+    let start_position = burned_in_start(theta, sigma, start_position, burn_in, time_step)?;
+    let ou = checked_ou(theta, sigma, start_position)?;
+    let (times, positions) = simulate_with_endpoint(
+        |end| ou.simulate(end, time_step),
+        |x, h| OrnsteinUhlenbeck::new(theta, sigma, x)?.simulate(h, h),
+        duration,
+        time_step,
+        endpoint,
+    )?;
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
+}
+
+/// Simulate Ornstein-Uhlenbeck process together with its running time integral.
+///
+/// Returns the times, the path `V` and `X(t) = ∫_0^t V(s) ds` by the trapezoidal rule over
+/// the simulation grid, e.g. the position of a particle with OU velocity `V`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn ou_simulate_integrated(
+    py: Python<'_>,
+    theta: f64,
+    sigma: f64,
+    start_position: f64,
+    duration: f64,
+    time_step: f64,
+) -> XPyResult<PyArrayTriple<'_>> {
+    checked_num_steps(duration, time_step)?;
+    let ou = checked_ou(theta, sigma, start_position)?;
+    let (times, velocity) = ou.simulate(duration, time_step)?;
+    let integral = cumulative_trapezoid(&velocity, times.windows(2).map(|t| t[1] - t[0]));
+    Ok((
+        times.into_pyarray(py),
+        velocity.into_pyarray(py),
+        integral.into_pyarray(py),
+    ))
+}
+
+/// Get the terminal values `X(duration)` of `particles` independent Ornstein-Uhlenbeck processes.
+///
+/// The values are sampled from the exact Gaussian transition
+/// `N(x0 e^{-theta t}, sigma^2 (1 - e^{-2 theta t}) / (2 theta))`; see `bm_terminals` for `qmc`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (theta, sigma, start_position, duration, particles, qmc = false))]
+pub fn ou_terminals(
+    py: Python<'_>,
+    theta: f64,
+    sigma: f64,
+    start_position: f64,
+    duration: f64,
+    particles: usize,
+    qmc: bool,
+) -> XPyResult<Bound<'_, PyArray<f64, Ix1>>> {
+    if !(duration > 0.0 && duration.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "duration must be positive and finite, got {duration}"
+        )));
+    }
+    checked_ou(theta, sigma, start_position)?;
+    let decay = (-theta * duration).exp();
+    let mean = start_position * decay;
+    let scale = sigma * (-(-2.0 * theta * duration).exp_m1() / (2.0 * theta)).sqrt();
+    let mut result = if qmc {
+        sobol_standard_normals(particles)
+    } else {
+        normal::standard_rands(particles)
+    };
+    result.par_iter_mut().for_each(|x| *x = mean + scale * *x);
+    Ok(result.into_pyarray(py))
 }
 
 /// Get the raw moment of Ornstein-Uhlenbeck process.
+///
+/// With `burn_in`, the moment is taken at `burn_in + duration`, after the transient from
+/// `start_position` has decayed.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (theta, sigma, start_position, duration, time_step, order, particles, burn_in = 0.0))]
 pub fn ou_raw_moment(
     theta: f64,
     sigma: f64,
@@ -34,15 +135,111 @@ pub fn ou_raw_moment(
     time_step: f64,
     order: i32,
     particles: usize,
+    burn_in: f64,
+) -> PyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    let kind = Kind::Ou(checked_ou(theta, sigma, start_position)?);
+    let duration = duration + checked_burn_in(burn_in)?;
+    kind.raw_moment(duration, time_step, order, particles, false)
+}
+
+/// Get the raw moment of Ornstein-Uhlenbeck process, Richardson-extrapolated over `time_step`.
+///
+/// Every particle is integrated by Euler-Maruyama at `time_step` and at `time_step / 2`
+/// with coupled noise (the coarse increments are sums of the fine ones), and the result is
+/// `2 M(time_step / 2) - M(time_step)`. Without this coupling the extrapolation would
+/// amplify the statistical error instead of reducing the bias.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn ou_raw_moment_extrapolated(
+    theta: f64,
+    sigma: f64,
+    start_position: f64,
+    duration: f64,
+    time_step: f64,
+    order: i32,
+    particles: usize,
 ) -> XPyResult<f64> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let result = ou.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let steps = checked_num_steps(duration, time_step)?;
+    checked_ou(theta, sigma, start_position)?;
+    if let Some(result) = trivial_raw_moment(order, false)? {
+        return Ok(result);
+    }
+    if particles == 0 {
+        return Err(XPyError::ValueError(
+            "particles must be positive".to_string(),
+        ));
+    }
+    let values: Vec<f64> = (0..particles)
+        .into_par_iter()
+        .map(|_| {
+            let noise = normal::standard_rands(2 * steps);
+            let (mut fine, mut coarse) = (start_position, start_position);
+            for (k, xi) in noise.chunks_exact(2).enumerate() {
+                let h = time_step.min(duration - k as f64 * time_step);
+                let (dw1, dw2) = ((h / 2.0).sqrt() * xi[0], (h / 2.0).sqrt() * xi[1]);
+                fine += -theta * fine * h / 2.0 + sigma * dw1;
+                fine += -theta * fine * h / 2.0 + sigma * dw2;
+                coarse += -theta * coarse * h + sigma * (dw1 + dw2);
+            }
+            2.0 * fine.powi(order) - coarse.powi(order)
+        })
+        .collect();
+    Ok(summation::sum(values, true) / particles as f64)
+}
+
+/// Get the raw moment of Ornstein-Uhlenbeck process with common random numbers.
+///
+/// Particle `i` is integrated by Euler-Maruyama with Gaussian increments drawn from a
+/// generator derived from `(seed, i)` only, so calls that share `seed` and `time_step` use
+/// the same driving noise for any `theta`, `sigma` or `start_position`. The difference of
+/// two such estimates at nearby parameter values is then a low-variance finite-difference
+/// sensitivity.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn ou_raw_moment_crn(
+    theta: f64,
+    sigma: f64,
+    start_position: f64,
+    duration: f64,
+    time_step: f64,
+    order: i32,
+    particles: usize,
+    seed: u64,
+) -> XPyResult<f64> {
+    let steps = checked_num_steps(duration, time_step)?;
+    checked_ou(theta, sigma, start_position)?;
+    if let Some(result) = trivial_raw_moment(order, false)? {
+        return Ok(result);
+    }
+    if particles == 0 {
+        return Err(XPyError::ValueError(
+            "particles must be positive".to_string(),
+        ));
+    }
+    let values: Vec<f64> = (0..particles as u64)
+        .into_par_iter()
+        .map(|particle| {
+            let mut rng = indexed_rng(seed, particle);
+            let mut x = start_position;
+            for k in 0..steps {
+                let h = time_step.min(duration - k as f64 * time_step);
+                let xi: f64 = StandardNormal.sample(&mut rng);
+                x += -theta * x * h + sigma * h.sqrt() * xi;
+            }
+            x.powi(order)
+        })
+        .collect();
+    Ok(summation::sum(values, true) / particles as f64)
 }
 
 /// Get the central moment of Ornstein-Uhlenbeck process.
+///
+/// With `burn_in`, the moment is taken at `burn_in + duration`, after the transient from
+/// `start_position` has decayed.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (theta, sigma, start_position, duration, time_step, order, particles, burn_in = 0.0))]
 pub fn ou_central_moment(
     theta: f64,
     sigma: f64,
@@ -51,15 +248,21 @@ pub fn ou_central_moment(
     time_step: f64,
     order: i32,
     particles: usize,
+    burn_in: f64,
 ) -> XPyResult<f64> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let result = ou.central_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    checked_num_steps(duration, time_step)?;
+    let kind = Kind::Ou(checked_ou(theta, sigma, start_position)?);
+    let duration = duration + checked_burn_in(burn_in)?;
+    kind.central_moment(duration, time_step, order, particles)
 }
 
 /// Get the fractional raw moment of Ornstein-Uhlenbeck process.
+///
+/// With `burn_in`, the moment is taken at `burn_in + duration`, after the transient from
+/// `start_position` has decayed.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (theta, sigma, start_position, duration, time_step, order, particles, burn_in = 0.0))]
 pub fn ou_frac_raw_moment(
     theta: f64,
     sigma: f64,
@@ -68,15 +271,26 @@ pub fn ou_frac_raw_moment(
     time_step: f64,
     order: f64,
     particles: usize,
+    burn_in: f64,
 ) -> XPyResult<f64> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let result = ou.frac_raw_moment(duration, order, particles, time_step)?;
+    checked_num_steps(duration, time_step)?;
+    let ou = checked_ou(theta, sigma, start_position)?;
+    let result = ou.frac_raw_moment(
+        duration + checked_burn_in(burn_in)?,
+        order,
+        particles,
+        time_step,
+    )?;
     Ok(result)
 }
 
 /// Get the fractional central moment of Ornstein-Uhlenbeck process.
+///
+/// With `burn_in`, the moment is taken at `burn_in + duration`, after the transient from
+/// `start_position` has decayed.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (theta, sigma, start_position, duration, time_step, order, particles, burn_in = 0.0))]
 pub fn ou_frac_central_moment(
     theta: f64,
     sigma: f64,
@@ -85,9 +299,16 @@ pub fn ou_frac_central_moment(
     time_step: f64,
     order: f64,
     particles: usize,
+    burn_in: f64,
 ) -> XPyResult<f64> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let result = ou.frac_central_moment(duration, order, particles, time_step)?;
+    checked_num_steps(duration, time_step)?;
+    let ou = checked_ou(theta, sigma, start_position)?;
+    let result = ou.frac_central_moment(
+        duration + checked_burn_in(burn_in)?,
+        order,
+        particles,
+        time_step,
+    )?;
     Ok(result)
 }
 
@@ -102,9 +323,8 @@ pub fn ou_fpt(
     domain: (f64, f64),
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let result = ou.fpt(domain, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Ou(checked_ou(theta, sigma, start_position)?);
+    kind.fpt(start_position, time_step, domain, max_duration)
 }
 
 /// Get the raw moment of the first passage time of Ornstein-Uhlenbeck process.
@@ -120,10 +340,15 @@ pub fn ou_fpt_raw_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let fpt = FirstPassageTime::new(&ou, domain)?;
-    let result = fpt.raw_moment(order, particles, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Ou(checked_ou(theta, sigma, start_position)?);
+    kind.fpt_raw_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
 }
 
 /// Get the central moment of the first passage time of Ornstein-Uhlenbeck process.
@@ -139,10 +364,15 @@ pub fn ou_fpt_central_moment(
     time_step: f64,
     max_duration: f64,
 ) -> XPyResult<Option<f64>> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let fpt = FirstPassageTime::new(&ou, domain)?;
-    let result = fpt.central_moment(order, particles, max_duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Ou(checked_ou(theta, sigma, start_position)?);
+    kind.fpt_central_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
 }
 
 /// Get the occupation time of Ornstein-Uhlenbeck process.
@@ -156,9 +386,8 @@ pub fn ou_occupation_time(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let result = ou.occupation_time(domain, duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Ou(checked_ou(theta, sigma, start_position)?);
+    kind.occupation_time(time_step, domain, duration)
 }
 
 /// Get the raw moment of the occupation time of Ornstein-Uhlenbeck process.
@@ -174,10 +403,8 @@ pub fn ou_occupation_time_raw_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let oc = OccupationTime::new(&ou, domain, duration)?;
-    let result = oc.raw_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Ou(checked_ou(theta, sigma, start_position)?);
+    kind.occupation_time_raw_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the central moment of the occupation time of Ornstein-Uhlenbeck process.
@@ -193,15 +420,14 @@ pub fn ou_occupation_time_central_moment(
     time_step: f64,
     duration: f64,
 ) -> XPyResult<f64> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let oc = OccupationTime::new(&ou, domain, duration)?;
-    let result = oc.central_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Ou(checked_ou(theta, sigma, start_position)?);
+    kind.occupation_time_central_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the time-averaged mean squared displacement of Ornstein-Uhlenbeck process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (theta, sigma, start_position, duration, delta, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn ou_tamsd(
     theta: f64,
     sigma: f64,
@@ -210,15 +436,25 @@ pub fn ou_tamsd(
     delta: f64,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let result = ou.tamsd(duration, delta, time_step, quad_order)?;
+    let ou = checked_ou(theta, sigma, start_position)?;
+    let result = tamsd_with_rule(
+        rule,
+        || ou.tamsd(duration, delta, time_step, quad_order),
+        |t| ou.simulate(t, time_step),
+        duration,
+        delta,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the effective time-averaged mean squared displacement of Ornstein-Uhlenbeck process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (theta, sigma, start_position, duration, delta, particles, time_step, quad_order, rule = "gauss_legendre"))]
 pub fn ou_eatamsd(
     theta: f64,
     sigma: f64,
@@ -228,15 +464,28 @@ pub fn ou_eatamsd(
     particles: usize,
     time_step: f64,
     quad_order: usize,
+    rule: &str,
 ) -> XPyResult<f64> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let result = ou.eatamsd(duration, delta, particles, time_step, quad_order)?;
+    let ou = checked_ou(theta, sigma, start_position)?;
+    let result = eatamsd_with_rule(
+        rule,
+        || ou.tamsd(duration, delta, time_step, quad_order),
+        |t| ou.simulate(t, time_step),
+        duration,
+        delta,
+        particles,
+        time_step,
+        quad_order,
+    )?;
     Ok(result)
 }
 
 /// Get the mean of Ornstein-Uhlenbeck process.
+///
+/// See `ou_raw_moment` for `burn_in`.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (theta, sigma, start_position, duration, particles, time_step, burn_in = 0.0))]
 pub fn ou_mean(
     theta: f64,
     sigma: f64,
@@ -244,9 +493,10 @@ pub fn ou_mean(
     duration: f64,
     particles: usize,
     time_step: f64,
+    burn_in: f64,
 ) -> XPyResult<f64> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
-    let result = ou.mean(duration, particles, time_step)?;
+    let ou = checked_ou(theta, sigma, start_position)?;
+    let result = ou.mean(duration + checked_burn_in(burn_in)?, particles, time_step)?;
     Ok(result)
 }
 
@@ -261,7 +511,46 @@ pub fn ou_msd(
     particles: usize,
     time_step: f64,
 ) -> XPyResult<f64> {
-    let ou = OrnsteinUhlenbeck::new(theta, sigma, start_position)?;
+    let ou = checked_ou(theta, sigma, start_position)?;
     let result = ou.msd(duration, particles, time_step)?;
     Ok(result)
 }
+
+/// 校验预热时间 `burn_in >= 0`
+fn checked_burn_in(burn_in: f64) -> XPyResult<f64> {
+    if !(burn_in >= 0.0 && burn_in.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "burn_in must be non-negative and finite, got {burn_in}"
+        )));
+    }
+    Ok(burn_in)
+}
+
+/// 模拟并丢弃长为 `burn_in` 的预热段，返回其终点作为新的起点
+fn burned_in_start(
+    theta: f64,
+    sigma: f64,
+    start_position: f64,
+    burn_in: f64,
+    time_step: f64,
+) -> XPyResult<f64> {
+    if checked_burn_in(burn_in)? == 0.0 {
+        return Ok(start_position);
+    }
+    let ou = checked_ou(theta, sigma, start_position)?;
+    let (_, positions) = ou.simulate(burn_in, time_step)?;
+    Ok(positions.last().copied().unwrap_or(start_position))
+}
+
+/// 构造 OU 过程 `dX = -theta X dt + sigma dW`，逐项校验 `theta`、`sigma` 为正、
+/// `start_position` 有限
+pub(crate) fn checked_ou(
+    theta: f64,
+    sigma: f64,
+    start_position: f64,
+) -> XPyResult<OrnsteinUhlenbeck> {
+    checked_positive("theta", theta)?;
+    checked_positive("sigma", sigma)?;
+    checked_finite("start_position", start_position)?;
+    Ok(OrnsteinUhlenbeck::new(theta, sigma, start_position)?)
+}
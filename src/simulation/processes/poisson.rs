@@ -1,6 +1,9 @@
 use crate::{
     XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    simulation::{
+        PyArrayPair, checked_positive, endpoint_raw_moment, trivial_central_moment,
+        trivial_raw_moment, vec_to_pyarray,
+    },
 };
 use diffusionx::simulation::{point::Poisson, prelude::*};
 use pyo3::prelude::*;
@@ -15,7 +18,7 @@ pub fn poisson_simulate_duration(
     lambda_: f64,
     duration: f64,
 ) -> XPyResult<PyArrayPair<'_>> {
-    let poisson = Poisson::new(lambda_)?;
+    let poisson = checked_poisson(lambda_)?;
     let (times, positions) = poisson.simulate_with_duration(duration)?;
     Ok(vec_to_pyarray(py, times, positions))
 }
@@ -28,7 +31,7 @@ pub fn poisson_simulate_step(
     lambda_: f64,
     num_step: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
-    let poisson = Poisson::new(lambda_)?;
+    let poisson = checked_poisson(lambda_)?;
     let (times, positions) = poisson.simulate_with_step(num_step)?;
     Ok(vec_to_pyarray(py, times, positions))
 }
@@ -41,10 +44,14 @@ pub fn poisson_raw_moment(
     duration: f64,
     order: i32,
     particles: usize,
-) -> XPyResult<f64> {
-    let poisson: Poisson<f64, f64> = Poisson::new(lambda_)?;
-    let result = poisson.raw_moment(duration, order, particles)?;
-    Ok(result)
+) -> PyResult<f64> {
+    let poisson: Poisson<f64, f64> = checked_poisson(lambda_)?;
+    if let Some(result) = trivial_raw_moment(order, false)? {
+        return Ok(result);
+    }
+    endpoint_raw_moment(particles, order, || {
+        poisson.simulate_with_duration(duration)
+    })
 }
 
 /// Get the central moment of Poisson process.
@@ -56,7 +63,10 @@ pub fn poisson_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let poisson: Poisson<f64, f64> = Poisson::new(lambda_)?;
+    let poisson: Poisson<f64, f64> = checked_poisson(lambda_)?;
+    if let Some(result) = trivial_central_moment(order)? {
+        return Ok(result);
+    }
     let result = poisson.central_moment(duration, order, particles)?;
     Ok(result)
 }
@@ -70,7 +80,7 @@ pub fn poisson_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
-    let poisson: Poisson<f64, f64> = Poisson::new(lambda_)?;
+    let poisson: Poisson<f64, f64> = checked_poisson(lambda_)?;
     let result = poisson.frac_raw_moment(duration, order, particles)?;
     Ok(result)
 }
@@ -84,7 +94,7 @@ pub fn poisson_frac_central_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
-    let poisson: Poisson<f64, f64> = Poisson::new(lambda_)?;
+    let poisson: Poisson<f64, f64> = checked_poisson(lambda_)?;
     let result = poisson.frac_central_moment(duration, order, particles)?;
     Ok(result)
 }
@@ -93,7 +103,7 @@ pub fn poisson_frac_central_moment(
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
 pub fn poisson_fpt(lambda_: f64, domain: (f64, f64), max_duration: f64) -> XPyResult<Option<f64>> {
-    let poisson: Poisson<f64, f64> = Poisson::new(lambda_)?;
+    let poisson: Poisson<f64, f64> = checked_poisson(lambda_)?;
     let result = poisson.fpt(domain, max_duration)?;
     Ok(result)
 }
@@ -108,7 +118,7 @@ pub fn poisson_fpt_raw_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<Option<f64>> {
-    let poisson: Poisson<f64, f64> = Poisson::new(lambda_)?;
+    let poisson: Poisson<f64, f64> = checked_poisson(lambda_)?;
     let fpt = FirstPassageTime::new(&poisson, domain)?;
     let result = fpt.raw_moment_p(order, particles, max_duration)?;
     Ok(result)
@@ -124,7 +134,7 @@ pub fn poisson_fpt_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<Option<f64>> {
-    let poisson: Poisson<f64, f64> = Poisson::new(lambda_)?;
+    let poisson: Poisson<f64, f64> = checked_poisson(lambda_)?;
     let fpt = FirstPassageTime::new(&poisson, domain)?;
     let result = fpt.central_moment_p(order, particles, max_duration)?;
     Ok(result)
@@ -134,7 +144,7 @@ pub fn poisson_fpt_central_moment(
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
 pub fn poisson_occupation_time(lambda_: f64, domain: (f64, f64), duration: f64) -> XPyResult<f64> {
-    let poisson: Poisson<f64, f64> = Poisson::new(lambda_)?;
+    let poisson: Poisson<f64, f64> = checked_poisson(lambda_)?;
     let result = poisson.occupation_time(domain, duration)?;
     Ok(result)
 }
@@ -149,7 +159,7 @@ pub fn poisson_occupation_time_raw_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let poisson: Poisson<f64, f64> = Poisson::new(lambda_)?;
+    let poisson: Poisson<f64, f64> = checked_poisson(lambda_)?;
     let oc = OccupationTime::new(&poisson, domain, duration)?;
     let result = oc.raw_moment_p(order, particles)?;
     Ok(result)
@@ -165,7 +175,7 @@ pub fn poisson_occupation_time_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let poisson: Poisson<f64, f64> = Poisson::new(lambda_)?;
+    let poisson: Poisson<f64, f64> = checked_poisson(lambda_)?;
     let oc = OccupationTime::new(&poisson, domain, duration)?;
     let result = oc.central_moment_p(order, particles)?;
     Ok(result)
@@ -175,7 +185,7 @@ pub fn poisson_occupation_time_central_moment(
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
 pub fn poisson_mean(lambda_: f64, duration: f64, particles: usize) -> XPyResult<f64> {
-    let poisson: Poisson<f64, f64> = Poisson::new(lambda_)?;
+    let poisson: Poisson<f64, f64> = checked_poisson(lambda_)?;
     let result = poisson.mean(duration, particles)?;
     Ok(result)
 }
@@ -184,7 +194,13 @@ pub fn poisson_mean(lambda_: f64, duration: f64, particles: usize) -> XPyResult<
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
 pub fn poisson_msd(lambda_: f64, duration: f64, particles: usize) -> XPyResult<f64> {
-    let poisson: Poisson<f64, f64> = Poisson::new(lambda_)?;
+    let poisson: Poisson<f64, f64> = checked_poisson(lambda_)?;
     let result = poisson.msd(duration, particles)?;
     Ok(result)
 }
+
+/// 构造 Poisson 过程，校验强度 `lambda_` 为正
+pub(crate) fn checked_poisson(lambda_: f64) -> XPyResult<Poisson<f64, f64>> {
+    checked_positive("lambda_", lambda_)?;
+    Ok(Poisson::new(lambda_)?)
+}
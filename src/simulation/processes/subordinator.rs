@@ -1,11 +1,19 @@
 use crate::{
-    XPyResult,
-    simulation::{PyArrayPair, vec_to_pyarray},
+    XPyError, XPyResult,
+    simulation::{
+        Kind, PyArrayPair, checked_domain, checked_num_steps, checked_t0, checked_thin,
+        par_endpoints, shift_times, summation, thin_path, vec_to_pyarray,
+    },
 };
-use diffusionx::simulation::{
-    continuous::{InvSubordinator, Subordinator},
-    prelude::*,
+use diffusionx::{
+    XResult,
+    random::normal,
+    simulation::{
+        continuous::{InvSubordinator, Subordinator},
+        prelude::*,
+    },
 };
+use numpy::{IntoPyArray, Ix1, PyArray};
 use pyo3::prelude::*;
 #[cfg(feature = "stub_gen")]
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
@@ -13,15 +21,25 @@ use pyo3_stub_gen::derive::gen_stub_pyfunction;
 /// Simulate subordinator process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (alpha, duration, time_step, t0 = 0.0, thin = 1))]
 pub fn subordinator_simulate(
     py: Python<'_>,
     alpha: f64,
     duration: f64,
     time_step: f64,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
     let subordinator = Subordinator::new(alpha)?;
     let (times, positions) = subordinator.simulate(duration, time_step)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
 }
 
 /// Get the fractional raw moment of subordinator process.
@@ -34,6 +52,7 @@ pub fn subordinator_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let subordinator = Subordinator::new(alpha)?;
     let result = subordinator.frac_raw_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -48,6 +67,7 @@ pub fn subordinator_fpt(
     max_duration: f64,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let subordinator = Subordinator::new(alpha)?;
     let result = subordinator.fpt(domain, max_duration, time_step)?;
     Ok(result)
@@ -64,6 +84,7 @@ pub fn subordinator_fpt_raw_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let subordinator = Subordinator::new(alpha)?;
     let fpt = FirstPassageTime::new(&subordinator, domain)?;
     let result = fpt.raw_moment(order, particles, max_duration, time_step)?;
@@ -81,6 +102,7 @@ pub fn subordinator_fpt_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let subordinator = Subordinator::new(alpha)?;
     let fpt = FirstPassageTime::new(&subordinator, domain)?;
     let result = fpt.central_moment(order, particles, max_duration, time_step)?;
@@ -96,9 +118,8 @@ pub fn subordinator_occupation_time(
     duration: f64,
     time_step: f64,
 ) -> XPyResult<f64> {
-    let subordinator = Subordinator::new(alpha)?;
-    let result = subordinator.occupation_time(domain, duration, time_step)?;
-    Ok(result)
+    let kind = Kind::Subordinator(Subordinator::new(alpha)?);
+    kind.occupation_time(time_step, domain, duration)
 }
 
 /// Get the raw moment of the occupation time of subordinator process.
@@ -112,10 +133,8 @@ pub fn subordinator_occupation_time_raw_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let subordinator = Subordinator::new(alpha)?;
-    let oc = OccupationTime::new(&subordinator, domain, duration)?;
-    let result = oc.raw_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Subordinator(Subordinator::new(alpha)?);
+    kind.occupation_time_raw_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the central moment of the occupation time of subordinator process.
@@ -129,24 +148,244 @@ pub fn subordinator_occupation_time_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let subordinator = Subordinator::new(alpha)?;
-    let oc = OccupationTime::new(&subordinator, domain, duration)?;
-    let result = oc.central_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::Subordinator(Subordinator::new(alpha)?);
+    kind.occupation_time_central_moment(domain, order, particles, time_step, duration)
 }
 
 /// Simulate inverse subordinator process.
 #[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
 #[pyfunction]
+#[pyo3(signature = (alpha, duration, time_step, t0 = 0.0, thin = 1))]
 pub fn inv_subordinator_simulate(
     py: Python<'_>,
     alpha: f64,
     duration: f64,
     time_step: f64,
+    t0: f64,
+    thin: usize,
 ) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
     let inv_subordinator = InvSubordinator::new(alpha)?;
     let (times, positions) = inv_subordinator.simulate(duration, time_step)?;
-    Ok(vec_to_pyarray(py, times, positions))
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
+}
+
+/// Simulate an alpha-stable subordinator `S(u)` together with its inverse `E(t)`.
+///
+/// `S` is simulated on the operational grid `u_i = i * time_step` until it first exceeds
+/// `duration`, and `E(t) = min{u_i : S(u_i) > t}` is evaluated from the same path on the
+/// physical grid `t_j = j * time_step` up to `duration`. Returns
+/// `(operational_time, subordinator, physical_time, inverse)`.
+///
+/// `t0` shifts both time axes, as in `subordinator_simulate`, and `thin` keeps every
+/// `thin`-th point of each pair; `E` is always evaluated from the full path.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (alpha, duration, time_step, t0 = 0.0, thin = 1))]
+pub fn subordinator_pair_simulate(
+    py: Python<'_>,
+    alpha: f64,
+    duration: f64,
+    time_step: f64,
+    t0: f64,
+    thin: usize,
+) -> XPyResult<(
+    Bound<'_, PyArray<f64, Ix1>>,
+    Bound<'_, PyArray<f64, Ix1>>,
+    Bound<'_, PyArray<f64, Ix1>>,
+    Bound<'_, PyArray<f64, Ix1>>,
+)> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    if !(alpha > 0.0 && alpha < 1.0) {
+        return Err(XPyError::ValueError(format!(
+            "alpha must be in (0, 1), got {alpha}"
+        )));
+    }
+    let steps = checked_num_steps(duration, time_step)?;
+    let subordinator = Subordinator::new(alpha)?;
+    // 增量平稳独立，故可不断续接新模拟的一段，直到 S 超过 duration
+    let chunk = steps as f64 * time_step;
+    let (mut operational, mut values) = (vec![0.0], vec![0.0]);
+    while values[values.len() - 1] <= duration {
+        let (u0, s0) = (operational[operational.len() - 1], values[values.len() - 1]);
+        let (times, positions) = subordinator.simulate(chunk, time_step)?;
+        for (u, s) in times.into_iter().zip(positions).skip(1) {
+            operational.push(u0 + u);
+            values.push(s0 + s);
+            if s0 + s > duration {
+                break;
+            }
+        }
+    }
+    let physical: Vec<f64> = (0..=steps)
+        .map(|j| (j as f64 * time_step).min(duration))
+        .collect();
+    // 物理时间单调，游标只需前移；末点 S > duration 保证下标不越界
+    let mut index = 0;
+    let inverse: Vec<f64> = physical
+        .iter()
+        .map(|&t| {
+            index += values[index..].partition_point(|&s| s <= t);
+            operational[index]
+        })
+        .collect();
+    Ok((
+        shift_times(thin_path(operational, thin), t0).into_pyarray(py),
+        thin_path(values, thin).into_pyarray(py),
+        shift_times(thin_path(physical, thin), t0).into_pyarray(py),
+        thin_path(inverse, thin).into_pyarray(py),
+    ))
+}
+
+/// Simulate fractional-time diffusion, i.e. Brownian motion evaluated at the inverse
+/// alpha-stable subordinator `E(t)`.
+///
+/// Its mean squared displacement is `2 D t^alpha / Gamma(1 + alpha)`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (alpha, diffusion_coefficient, start_position, duration, time_step, t0 = 0.0, thin = 1))]
+pub fn fractional_diffusion_simulate(
+    py: Python<'_>,
+    alpha: f64,
+    diffusion_coefficient: f64,
+    start_position: f64,
+    duration: f64,
+    time_step: f64,
+    t0: f64,
+    thin: usize,
+) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    checked_num_steps(duration, time_step)?;
+    validate_fractional_diffusion(alpha, diffusion_coefficient)?;
+    let (times, positions) = fractional_diffusion_path(
+        alpha,
+        diffusion_coefficient,
+        start_position,
+        duration,
+        time_step,
+    )?;
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(positions, thin),
+    ))
+}
+
+/// Get the mean squared displacement of fractional-time diffusion.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn fractional_diffusion_msd(
+    alpha: f64,
+    diffusion_coefficient: f64,
+    duration: f64,
+    time_step: f64,
+    particles: usize,
+) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
+    validate_fractional_diffusion(alpha, diffusion_coefficient)?;
+    let endpoints = par_endpoints(particles, || {
+        fractional_diffusion_path(alpha, diffusion_coefficient, 0.0, duration, time_step)
+    })?;
+    let squares = endpoints.into_iter().map(|x| x * x).collect();
+    Ok(summation::mean(squares, true, false).0)
+}
+
+fn validate_fractional_diffusion(alpha: f64, diffusion_coefficient: f64) -> XPyResult<()> {
+    if !(alpha > 0.0 && alpha < 1.0) {
+        return Err(XPyError::ValueError(format!(
+            "alpha must be in (0, 1), got {alpha}"
+        )));
+    }
+    if !(diffusion_coefficient > 0.0 && diffusion_coefficient.is_finite()) {
+        return Err(XPyError::ValueError(format!(
+            "diffusion_coefficient must be positive and finite, got {diffusion_coefficient}"
+        )));
+    }
+    Ok(())
+}
+
+/// 以逆从属过程 E(t) 为操作时间的布朗运动：X(t_i) = x0 + sum sqrt(2D dE_j) xi_j
+fn fractional_diffusion_path(
+    alpha: f64,
+    diffusion_coefficient: f64,
+    start_position: f64,
+    duration: f64,
+    time_step: f64,
+) -> XResult<(Vec<f64>, Vec<f64>)> {
+    let inv_subordinator = InvSubordinator::new(alpha)?;
+    let (times, operational) = inv_subordinator.simulate(duration, time_step)?;
+    let noise = normal::standard_rands(operational.len().saturating_sub(1));
+    let positions = std::iter::once(start_position)
+        .chain(
+            operational
+                .windows(2)
+                .zip(noise)
+                .scan(start_position, |x, (e, xi)| {
+                    *x += (2.0 * diffusion_coefficient * (e[1] - e[0])).sqrt() * xi;
+                    Some(*x)
+                }),
+        )
+        .collect();
+    Ok((times, positions))
+}
+
+/// Simulate the integrated CIR time change `T(t) = int_0^t v(s) ds`, where
+/// `dv = kappa (theta - v) dt + xi sqrt(v) dW` with `v(0) = v0`.
+///
+/// The variance is integrated with the full-truncation Euler scheme and the trapezoidal
+/// rule on `max(v, 0)`, so `T` is nondecreasing. At stationarity `T` grows at rate `theta`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (kappa, theta, xi, v0, duration, time_step, t0 = 0.0, thin = 1))]
+pub fn integrated_cir_subordinator_simulate(
+    py: Python<'_>,
+    kappa: f64,
+    theta: f64,
+    xi: f64,
+    v0: f64,
+    duration: f64,
+    time_step: f64,
+    t0: f64,
+    thin: usize,
+) -> XPyResult<PyArrayPair<'_>> {
+    checked_t0(t0)?;
+    checked_thin(thin)?;
+    let num_steps = checked_num_steps(duration, time_step)?;
+    for (name, value) in [("kappa", kappa), ("theta", theta), ("xi", xi), ("v0", v0)] {
+        if !(value >= 0.0 && value.is_finite()) {
+            return Err(XPyError::ValueError(format!(
+                "{name} must be non-negative and finite, got {value}"
+            )));
+        }
+    }
+    let mut times = Vec::with_capacity(num_steps + 1);
+    let mut clock = Vec::with_capacity(num_steps + 1);
+    times.push(0.0);
+    clock.push(0.0);
+    let (mut t, mut v, mut total) = (0.0, v0, 0.0);
+    for (i, xi_i) in (1..=num_steps).zip(normal::standard_rands(num_steps)) {
+        let next = (i as f64 * time_step).min(duration);
+        let dt = next - t;
+        let v_plus = v.max(0.0);
+        let v_next = v + kappa * (theta - v_plus) * dt + xi * (v_plus * dt).sqrt() * xi_i;
+        total += 0.5 * (v_plus + v_next.max(0.0)) * dt;
+        times.push(next);
+        clock.push(total);
+        (t, v) = (next, v_next);
+    }
+    Ok(vec_to_pyarray(
+        py,
+        shift_times(thin_path(times, thin), t0),
+        thin_path(clock, thin),
+    ))
 }
 
 /// Get the raw moment of inverse subordinator process.
@@ -158,10 +397,9 @@ pub fn inv_subordinator_raw_moment(
     time_step: f64,
     order: i32,
     particles: usize,
-) -> XPyResult<f64> {
-    let inv_subordinator = InvSubordinator::new(alpha)?;
-    let result = inv_subordinator.raw_moment(duration, order, particles, time_step)?;
-    Ok(result)
+) -> PyResult<f64> {
+    let kind = Kind::InvSubordinator(InvSubordinator::new(alpha)?);
+    kind.raw_moment(duration, time_step, order, particles, false)
 }
 
 /// Get the central moment of inverse subordinator process.
@@ -174,9 +412,8 @@ pub fn inv_subordinator_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let inv_subordinator = InvSubordinator::new(alpha)?;
-    let result = inv_subordinator.central_moment(duration, order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::InvSubordinator(InvSubordinator::new(alpha)?);
+    kind.central_moment(duration, time_step, order, particles)
 }
 
 /// Get the fractional raw moment of inverse subordinator process.
@@ -189,6 +426,7 @@ pub fn inv_subordinator_frac_raw_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let inv_subordinator = InvSubordinator::new(alpha)?;
     let result = inv_subordinator.frac_raw_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -204,6 +442,7 @@ pub fn inv_subordinator_frac_central_moment(
     order: f64,
     particles: usize,
 ) -> XPyResult<f64> {
+    checked_num_steps(duration, time_step)?;
     let inv_subordinator = InvSubordinator::new(alpha)?;
     let result = inv_subordinator.frac_central_moment(duration, order, particles, time_step)?;
     Ok(result)
@@ -218,6 +457,7 @@ pub fn inv_subordinator_fpt(
     max_duration: f64,
     time_step: f64,
 ) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let inv_subordinator = InvSubordinator::new(alpha)?;
     let result = inv_subordinator.fpt(domain, max_duration, time_step)?;
     Ok(result)
@@ -234,6 +474,7 @@ pub fn inv_subordinator_fpt_raw_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let inv_subordinator = InvSubordinator::new(alpha)?;
     let fpt = FirstPassageTime::new(&inv_subordinator, domain)?;
     let result = fpt.raw_moment(order, particles, max_duration, time_step)?;
@@ -251,6 +492,7 @@ pub fn inv_subordinator_fpt_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<Option<f64>> {
+    checked_domain(domain)?;
     let inv_subordinator = InvSubordinator::new(alpha)?;
     let fpt = FirstPassageTime::new(&inv_subordinator, domain)?;
     let result = fpt.central_moment(order, particles, max_duration, time_step)?;
@@ -266,9 +508,8 @@ pub fn inv_subordinator_occupation_time(
     duration: f64,
     time_step: f64,
 ) -> XPyResult<f64> {
-    let inv_subordinator = InvSubordinator::new(alpha)?;
-    let result = inv_subordinator.occupation_time(domain, duration, time_step)?;
-    Ok(result)
+    let kind = Kind::InvSubordinator(InvSubordinator::new(alpha)?);
+    kind.occupation_time(time_step, domain, duration)
 }
 
 /// Get the raw moment of the occupation time of inverse subordinator process.
@@ -282,10 +523,8 @@ pub fn inv_subordinator_occupation_time_raw_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let inv_subordinator = InvSubordinator::new(alpha)?;
-    let oc = OccupationTime::new(&inv_subordinator, domain, duration)?;
-    let result = oc.raw_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::InvSubordinator(InvSubordinator::new(alpha)?);
+    kind.occupation_time_raw_moment(domain, order, particles, time_step, duration)
 }
 
 /// Get the central moment of the occupation time of inverse subordinator process.
@@ -299,8 +538,6 @@ pub fn inv_subordinator_occupation_time_central_moment(
     order: i32,
     particles: usize,
 ) -> XPyResult<f64> {
-    let inv_subordinator = InvSubordinator::new(alpha)?;
-    let oc = OccupationTime::new(&inv_subordinator, domain, duration)?;
-    let result = oc.central_moment(order, particles, time_step)?;
-    Ok(result)
+    let kind = Kind::InvSubordinator(InvSubordinator::new(alpha)?);
+    kind.occupation_time_central_moment(domain, order, particles, time_step, duration)
 }
@@ -0,0 +1,647 @@
+use crate::{
+    XPyError, XPyResult,
+    simulation::{
+        PyArrayPair, checked_bm, checked_domain, checked_fpt_domain, checked_gamma, checked_gb,
+        checked_num_steps, checked_ou, endpoint_raw_moment, trivial_central_moment,
+        trivial_raw_moment, vec_to_pyarray,
+    },
+};
+use diffusionx::{
+    XResult,
+    simulation::{
+        continuous::{
+            AsymmetricCauchy, AsymmetricLevy, Bm, BrownianBridge, BrownianExcursion,
+            BrownianMeander, Cauchy, FBm, Gamma, GeometricBm, InvSubordinator, Levy,
+            OrnsteinUhlenbeck, Subordinator,
+        },
+        prelude::*,
+    },
+};
+use pyo3::prelude::*;
+#[cfg(feature = "stub_gen")]
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pyfunction, gen_stub_pymethods};
+use std::collections::HashMap;
+
+/// 各过程名及其参数；`None` 表示必填，`Some(v)` 为默认值
+const SPECS: &[(&str, &[(&str, Option<f64>)])] = &[
+    (
+        "bm",
+        &[
+            ("start_position", Some(0.0)),
+            ("diffusion_coefficient", Some(1.0)),
+        ],
+    ),
+    (
+        "fbm",
+        &[("start_position", Some(0.0)), ("hurst_exponent", None)],
+    ),
+    (
+        "ou",
+        &[
+            ("theta", None),
+            ("sigma", None),
+            ("start_position", Some(0.0)),
+        ],
+    ),
+    ("levy", &[("start_position", Some(0.0)), ("alpha", None)]),
+    (
+        "asymmetric_levy",
+        &[
+            ("start_position", Some(0.0)),
+            ("alpha", None),
+            ("beta", None),
+        ],
+    ),
+    ("cauchy", &[("start_position", Some(0.0))]),
+    (
+        "asymmetric_cauchy",
+        &[("start_position", Some(0.0)), ("beta", None)],
+    ),
+    ("gamma", &[("shape", None), ("rate", None)]),
+    (
+        "geometric_bm",
+        &[("start_position", Some(1.0)), ("mu", None), ("sigma", None)],
+    ),
+    ("brownian_bridge", &[]),
+    ("brownian_excursion", &[]),
+    ("brownian_meander", &[]),
+    ("subordinator", &[("alpha", None)]),
+    ("inv_subordinator", &[("alpha", None)]),
+];
+
+/// 内置过程；命名的 `*_raw_moment`、`*_fpt` 等函数构造对应变体后调用其估计方法
+pub(crate) enum Kind {
+    Bm(Bm),
+    FBm(FBm),
+    Ou(OrnsteinUhlenbeck),
+    Levy(Levy),
+    AsymmetricLevy(AsymmetricLevy),
+    Cauchy(Cauchy),
+    AsymmetricCauchy(AsymmetricCauchy),
+    Gamma(Gamma),
+    GeometricBm(GeometricBm),
+    BrownianBridge(BrownianBridge),
+    BrownianExcursion(BrownianExcursion),
+    BrownianMeander(BrownianMeander),
+    Subordinator(Subordinator),
+    InvSubordinator(InvSubordinator),
+}
+
+/// A built-in process created by `make_process`.
+///
+/// It exposes `simulate(duration, time_step)`, so it can be passed to the generic
+/// estimators `moment`, `mean`, `msd`, `tamsd` and `eatamsd`. The `process_*` functions
+/// compute its moments, first passage times and occupation times.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyclass)]
+#[pyclass(frozen, module = "diffusionx._core")]
+pub struct Process {
+    name: String,
+    params: Vec<(&'static str, f64)>,
+    kind: Kind,
+}
+
+#[cfg_attr(feature = "stub_gen", gen_stub_pymethods)]
+#[pymethods]
+impl Process {
+    /// Simulate the process.
+    fn simulate<'py>(
+        &self,
+        py: Python<'py>,
+        duration: f64,
+        time_step: f64,
+    ) -> XPyResult<PyArrayPair<'py>> {
+        checked_num_steps(duration, time_step)?;
+        let (times, positions) = self.simulate_path(duration, time_step)?;
+        Ok(vec_to_pyarray(py, times, positions))
+    }
+
+    /// Name of the process.
+    #[getter]
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Parameters of the process, including defaults.
+    #[getter]
+    fn params(&self) -> HashMap<String, f64> {
+        self.params
+            .iter()
+            .map(|&(key, value)| (key.to_string(), value))
+            .collect()
+    }
+
+    fn __repr__(&self) -> String {
+        let params = self
+            .params
+            .iter()
+            .map(|(key, value)| format!("'{key}': {value}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("make_process('{}', {{{params}}})", self.name)
+    }
+}
+
+/// 对 `Kind` 的每个变体以 `$process` 绑定具体过程并求值 `$body`；
+/// 给出 `unit:` 时，定义在 `[0, 1]` 上的布朗游走与布朗曲折改为求值 `$unit`
+macro_rules! dispatch {
+    ($kind:expr, $process:ident => $body:expr) => {
+        dispatch!($kind, $process => $body, unit: $body)
+    };
+    ($kind:expr, $process:ident => $body:expr, unit: $unit:expr) => {
+        match $kind {
+            Kind::Bm($process) => $body,
+            Kind::FBm($process) => $body,
+            Kind::Ou($process) => $body,
+            Kind::Levy($process) => $body,
+            Kind::AsymmetricLevy($process) => $body,
+            Kind::Cauchy($process) => $body,
+            Kind::AsymmetricCauchy($process) => $body,
+            Kind::Gamma($process) => $body,
+            Kind::GeometricBm($process) => $body,
+            Kind::BrownianBridge($process) => $body,
+            Kind::BrownianExcursion($process) => $unit,
+            Kind::BrownianMeander($process) => $unit,
+            Kind::Subordinator($process) => $body,
+            Kind::InvSubordinator($process) => $body,
+        }
+    };
+}
+
+impl Kind {
+    pub(crate) fn simulate(&self, duration: f64, time_step: f64) -> XResult<(Vec<f64>, Vec<f64>)> {
+        dispatch!(self, process => process.simulate(duration, time_step))
+    }
+
+    /// 终点的 `order` 阶原点矩；`positive` 表示过程严格为正，此时允许负阶
+    pub(crate) fn raw_moment(
+        &self,
+        duration: f64,
+        time_step: f64,
+        order: i32,
+        particles: usize,
+        positive: bool,
+    ) -> PyResult<f64> {
+        checked_num_steps(duration, time_step)?;
+        if let Some(result) = trivial_raw_moment(order, positive)? {
+            return Ok(result);
+        }
+        endpoint_raw_moment(particles, order, || self.simulate(duration, time_step))
+    }
+
+    pub(crate) fn central_moment(
+        &self,
+        duration: f64,
+        time_step: f64,
+        order: i32,
+        particles: usize,
+    ) -> XPyResult<f64> {
+        checked_num_steps(duration, time_step)?;
+        if let Some(result) = trivial_central_moment(order)? {
+            return Ok(result);
+        }
+        let result = dispatch!(self, process => {
+            process.central_moment(duration, order, particles, time_step)?
+        });
+        Ok(result)
+    }
+
+    /// 从 `start_position` 出发首次离开 `domain` 的时间；布朗游走与布朗曲折的时长固定为 1，
+    /// 不使用 `max_duration`
+    pub(crate) fn fpt(
+        &self,
+        start_position: f64,
+        time_step: f64,
+        domain: (f64, f64),
+        max_duration: f64,
+    ) -> XPyResult<Option<f64>> {
+        checked_num_steps(max_duration, time_step)?;
+        checked_fpt_domain(start_position, domain)?;
+        let result = dispatch!(
+            self,
+            process => process.fpt(domain, max_duration, time_step)?,
+            unit: process.fpt(domain, time_step)?
+        );
+        Ok(result)
+    }
+
+    pub(crate) fn fpt_raw_moment(
+        &self,
+        start_position: f64,
+        domain: (f64, f64),
+        order: i32,
+        particles: usize,
+        time_step: f64,
+        max_duration: f64,
+    ) -> XPyResult<Option<f64>> {
+        checked_num_steps(max_duration, time_step)?;
+        checked_fpt_domain(start_position, domain)?;
+        let result = dispatch!(self, process => {
+            FirstPassageTime::new(process, domain)?
+                .raw_moment(order, particles, max_duration, time_step)?
+        });
+        Ok(result)
+    }
+
+    pub(crate) fn fpt_central_moment(
+        &self,
+        start_position: f64,
+        domain: (f64, f64),
+        order: i32,
+        particles: usize,
+        time_step: f64,
+        max_duration: f64,
+    ) -> XPyResult<Option<f64>> {
+        checked_num_steps(max_duration, time_step)?;
+        checked_fpt_domain(start_position, domain)?;
+        let result = dispatch!(self, process => {
+            FirstPassageTime::new(process, domain)?
+                .central_moment(order, particles, max_duration, time_step)?
+        });
+        Ok(result)
+    }
+
+    pub(crate) fn occupation_time(
+        &self,
+        time_step: f64,
+        domain: (f64, f64),
+        duration: f64,
+    ) -> XPyResult<f64> {
+        checked_num_steps(duration, time_step)?;
+        checked_domain(domain)?;
+        let result = dispatch!(self, process => {
+            process.occupation_time(domain, duration, time_step)?
+        });
+        Ok(result)
+    }
+
+    pub(crate) fn occupation_time_raw_moment(
+        &self,
+        domain: (f64, f64),
+        order: i32,
+        particles: usize,
+        time_step: f64,
+        duration: f64,
+    ) -> XPyResult<f64> {
+        checked_num_steps(duration, time_step)?;
+        checked_domain(domain)?;
+        let result = dispatch!(self, process => {
+            OccupationTime::new(process, domain, duration)?.raw_moment(order, particles, time_step)?
+        });
+        Ok(result)
+    }
+
+    pub(crate) fn occupation_time_central_moment(
+        &self,
+        domain: (f64, f64),
+        order: i32,
+        particles: usize,
+        time_step: f64,
+        duration: f64,
+    ) -> XPyResult<f64> {
+        checked_num_steps(duration, time_step)?;
+        checked_domain(domain)?;
+        let result = dispatch!(self, process => {
+            OccupationTime::new(process, domain, duration)?
+                .central_moment(order, particles, time_step)?
+        });
+        Ok(result)
+    }
+}
+
+impl Process {
+    pub(crate) fn simulate_path(
+        &self,
+        duration: f64,
+        time_step: f64,
+    ) -> XResult<(Vec<f64>, Vec<f64>)> {
+        self.kind.simulate(duration, time_step)
+    }
+
+    /// 过程在 `t > 0` 时是否严格为正，即是否允许负阶原点矩
+    fn is_positive(&self) -> bool {
+        match &self.kind {
+            Kind::Gamma(_) => true,
+            Kind::GeometricBm(_) => self.start_position() > 0.0,
+            _ => false,
+        }
+    }
+
+    /// 过程的起点；没有 `start_position` 参数的过程都从 0 出发
+    fn start_position(&self) -> f64 {
+        self.params
+            .iter()
+            .find(|(key, _)| *key == "start_position")
+            .map_or(0.0, |&(_, value)| value)
+    }
+}
+
+/// Create a built-in process from its name and a dictionary of parameters.
+///
+/// Parameter names match the corresponding `*_simulate` functions; `start_position`
+/// and `diffusion_coefficient` may be omitted where they have a natural default.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+#[pyo3(signature = (name, params = None))]
+pub fn make_process(name: &str, params: Option<HashMap<String, f64>>) -> XPyResult<Process> {
+    let mut given = params.unwrap_or_default();
+    let (name, spec) = SPECS.iter().find(|(key, _)| *key == name).ok_or_else(|| {
+        let names = SPECS.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+        XPyError::ValueError(format!(
+            "unknown process '{name}', expected one of {}",
+            names.join(", ")
+        ))
+    })?;
+    let params = spec
+        .iter()
+        .map(|&(key, default)| {
+            given
+                .remove(key)
+                .or(default)
+                .map(|value| (key, value))
+                .ok_or_else(|| {
+                    XPyError::ValueError(format!("missing parameter '{key}' for process '{name}'"))
+                })
+        })
+        .collect::<XPyResult<Vec<_>>>()?;
+    if let Some(key) = given.keys().next() {
+        return Err(XPyError::ValueError(format!(
+            "unknown parameter '{key}' for process '{name}'"
+        )));
+    }
+    let p = |index: usize| params[index].1;
+    let kind = match *name {
+        "bm" => Kind::Bm(checked_bm(p(0), p(1))?),
+        "fbm" => Kind::FBm(FBm::new(p(0), p(1))?),
+        "ou" => Kind::Ou(checked_ou(p(0), p(1), p(2))?),
+        "levy" => Kind::Levy(Levy::new(p(0), p(1))?),
+        "asymmetric_levy" => Kind::AsymmetricLevy(AsymmetricLevy::new(p(0), p(1), p(2))?),
+        "cauchy" => Kind::Cauchy(Cauchy::new(p(0))),
+        "asymmetric_cauchy" => Kind::AsymmetricCauchy(AsymmetricCauchy::new(p(0), p(1))?),
+        "gamma" => Kind::Gamma(checked_gamma(p(0), p(1))?),
+        "geometric_bm" => Kind::GeometricBm(checked_gb(p(0), p(1), p(2))?),
+        "brownian_bridge" => Kind::BrownianBridge(BrownianBridge::new()),
+        "brownian_excursion" => Kind::BrownianExcursion(BrownianExcursion::new()),
+        "brownian_meander" => Kind::BrownianMeander(BrownianMeander::new()),
+        "subordinator" => Kind::Subordinator(Subordinator::new(p(0))?),
+        "inv_subordinator" => Kind::InvSubordinator(InvSubordinator::new(p(0))?),
+        _ => unreachable!("every name in SPECS has a constructor"),
+    };
+    Ok(Process {
+        name: name.to_string(),
+        params,
+        kind,
+    })
+}
+
+/// Get the raw moment of a process created by `make_process`.
+///
+/// Negative orders are only supported for `gamma` and for `geometric_bm` with a positive
+/// `start_position`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn process_raw_moment(
+    process: PyRef<'_, Process>,
+    duration: f64,
+    time_step: f64,
+    order: i32,
+    particles: usize,
+) -> PyResult<f64> {
+    let positive = process.is_positive();
+    process
+        .kind
+        .raw_moment(duration, time_step, order, particles, positive)
+}
+
+/// Get the central moment of a process created by `make_process`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn process_central_moment(
+    process: PyRef<'_, Process>,
+    duration: f64,
+    time_step: f64,
+    order: i32,
+    particles: usize,
+) -> XPyResult<f64> {
+    process
+        .kind
+        .central_moment(duration, time_step, order, particles)
+}
+
+/// Get the first passage time of a process created by `make_process`.
+///
+/// The start of the process must lie inside `domain`; processes without a
+/// `start_position` parameter start at 0.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn process_fpt(
+    process: PyRef<'_, Process>,
+    time_step: f64,
+    domain: (f64, f64),
+    max_duration: f64,
+) -> XPyResult<Option<f64>> {
+    let start_position = process.start_position();
+    process
+        .kind
+        .fpt(start_position, time_step, domain, max_duration)
+}
+
+/// Get the raw moment of the first passage time of a process created by `make_process`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn process_fpt_raw_moment(
+    process: PyRef<'_, Process>,
+    domain: (f64, f64),
+    order: i32,
+    particles: usize,
+    time_step: f64,
+    max_duration: f64,
+) -> XPyResult<Option<f64>> {
+    let start_position = process.start_position();
+    process.kind.fpt_raw_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
+}
+
+/// Get the central moment of the first passage time of a process created by `make_process`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn process_fpt_central_moment(
+    process: PyRef<'_, Process>,
+    domain: (f64, f64),
+    order: i32,
+    particles: usize,
+    time_step: f64,
+    max_duration: f64,
+) -> XPyResult<Option<f64>> {
+    let start_position = process.start_position();
+    process.kind.fpt_central_moment(
+        start_position,
+        domain,
+        order,
+        particles,
+        time_step,
+        max_duration,
+    )
+}
+
+/// Get the occupation time of a process created by `make_process`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn process_occupation_time(
+    process: PyRef<'_, Process>,
+    time_step: f64,
+    domain: (f64, f64),
+    duration: f64,
+) -> XPyResult<f64> {
+    process.kind.occupation_time(time_step, domain, duration)
+}
+
+/// Get the raw moment of the occupation time of a process created by `make_process`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn process_occupation_time_raw_moment(
+    process: PyRef<'_, Process>,
+    domain: (f64, f64),
+    order: i32,
+    particles: usize,
+    time_step: f64,
+    duration: f64,
+) -> XPyResult<f64> {
+    process
+        .kind
+        .occupation_time_raw_moment(domain, order, particles, time_step, duration)
+}
+
+/// Get the central moment of the occupation time of a process created by `make_process`.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyfunction)]
+#[pyfunction]
+pub fn process_occupation_time_central_moment(
+    process: PyRef<'_, Process>,
+    domain: (f64, f64),
+    order: i32,
+    particles: usize,
+    time_step: f64,
+    duration: f64,
+) -> XPyResult<f64> {
+    process
+        .kind
+        .occupation_time_central_moment(domain, order, particles, time_step, duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        bm_central_moment, bm_fpt, bm_occupation_time_raw_moment, bm_raw_moment,
+        levy_central_moment, levy_fpt, levy_raw_moment, ou_central_moment, ou_fpt, ou_raw_moment,
+    };
+
+    fn kind(name: &str, params: &[(&str, f64)]) -> Kind {
+        let params = params
+            .iter()
+            .map(|&(key, value)| (key.to_string(), value))
+            .collect();
+        make_process(name, Some(params)).unwrap().kind
+    }
+
+    #[test]
+    fn dispatch_matches_named_trivial_moments() {
+        let bm = kind(
+            "bm",
+            &[("start_position", 1.0), ("diffusion_coefficient", 0.5)],
+        );
+        let ou = kind(
+            "ou",
+            &[("theta", 1.0), ("sigma", 1.0), ("start_position", 1.0)],
+        );
+        let levy = kind("levy", &[("start_position", 1.0), ("alpha", 1.5)]);
+
+        assert_eq!(bm_raw_moment(1.0, 0.5, 1.0, 0.01, 0, 10).unwrap(), 1.0);
+        assert_eq!(bm.raw_moment(1.0, 0.01, 0, 10, false).unwrap(), 1.0);
+        assert_eq!(
+            ou_raw_moment(1.0, 1.0, 1.0, 1.0, 0.01, 0, 10, 0.0).unwrap(),
+            1.0
+        );
+        assert_eq!(ou.raw_moment(1.0, 0.01, 0, 10, false).unwrap(), 1.0);
+        assert_eq!(levy_raw_moment(1.0, 1.5, 1.0, 0.01, 0, 10).unwrap(), 1.0);
+        assert_eq!(levy.raw_moment(1.0, 0.01, 0, 10, false).unwrap(), 1.0);
+
+        assert_eq!(
+            bm_central_moment(1.0, 0.5, 1.0, 0.01, 1, 10).unwrap(),
+            bm.central_moment(1.0, 0.01, 1, 10).unwrap()
+        );
+        assert_eq!(
+            ou_central_moment(1.0, 1.0, 1.0, 1.0, 0.01, 1, 10, 0.0).unwrap(),
+            ou.central_moment(1.0, 0.01, 1, 10).unwrap()
+        );
+        assert_eq!(
+            levy_central_moment(1.0, 1.5, 1.0, 0.01, 1, 10).unwrap(),
+            levy.central_moment(1.0, 0.01, 1, 10).unwrap()
+        );
+    }
+
+    #[test]
+    fn dispatch_matches_named_errors() {
+        let bm = kind(
+            "bm",
+            &[("start_position", 1.0), ("diffusion_coefficient", 0.5)],
+        );
+        let ou = kind(
+            "ou",
+            &[("theta", 1.0), ("sigma", 1.0), ("start_position", 1.0)],
+        );
+        let levy = kind("levy", &[("start_position", 1.0), ("alpha", 1.5)]);
+        let message = |result: XPyResult<Option<f64>>| result.unwrap_err().to_string();
+
+        // 起点不在区域内
+        let domain = (2.0, 3.0);
+        assert_eq!(
+            message(bm_fpt(1.0, 0.5, 0.01, domain, 1.0)),
+            message(bm.fpt(1.0, 0.01, domain, 1.0))
+        );
+        assert_eq!(
+            message(ou_fpt(1.0, 1.0, 1.0, 0.01, domain, 1.0)),
+            message(ou.fpt(1.0, 0.01, domain, 1.0))
+        );
+        assert_eq!(
+            message(levy_fpt(1.0, 1.5, 0.01, domain, 1.0)),
+            message(levy.fpt(1.0, 0.01, domain, 1.0))
+        );
+
+        // 步长非法
+        let domain = (0.0, 2.0);
+        assert_eq!(
+            message(bm_fpt(1.0, 0.5, -0.01, domain, 1.0)),
+            message(bm.fpt(1.0, -0.01, domain, 1.0))
+        );
+        assert_eq!(
+            bm_occupation_time_raw_moment(1.0, 0.5, domain, 1, 10, 0.0, 1.0)
+                .unwrap_err()
+                .to_string(),
+            bm.occupation_time_raw_moment(domain, 1, 10, 0.0, 1.0)
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(bm_raw_moment(1.0, 0.5, 1.0, 0.0, 2, 10).is_err());
+        assert!(bm.raw_moment(1.0, 0.0, 2, 10, false).is_err());
+    }
+
+    #[test]
+    fn dispatch_matches_named_bm_second_moment() {
+        let bm = kind(
+            "bm",
+            &[("start_position", 0.0), ("diffusion_coefficient", 0.5)],
+        );
+        let named = bm_raw_moment(0.0, 0.5, 1.0, 0.01, 2, 20_000).unwrap();
+        let dispatched = bm.raw_moment(1.0, 0.01, 2, 20_000, false).unwrap();
+        assert!(
+            (named - dispatched).abs() < 0.1 * named,
+            "{named} vs {dispatched}"
+        );
+    }
+}
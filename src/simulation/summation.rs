@@ -0,0 +1,222 @@
+/// Neumaier's variant of Kahan compensated summation.
+///
+/// Unlike plain Kahan summation, this also stays accurate when an incoming term is
+/// larger in magnitude than the running sum, which is the common case for high-order
+/// moments of heavy-tailed processes.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct NeumaierSum {
+    sum: f64,
+    compensation: f64,
+    positive_overflow: bool,
+    negative_overflow: bool,
+}
+
+impl NeumaierSum {
+    pub(crate) fn add(&mut self, value: f64) {
+        if value.is_infinite() {
+            if value > 0.0 {
+                self.positive_overflow = true;
+            } else {
+                self.negative_overflow = true;
+            }
+            return;
+        }
+        let total = self.sum + value;
+        if total.is_infinite() {
+            if total > 0.0 {
+                self.positive_overflow = true;
+            } else {
+                self.negative_overflow = true;
+            }
+            return;
+        }
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - total) + value;
+        } else {
+            self.compensation += (value - total) + self.sum;
+        }
+        self.sum = total;
+    }
+
+    /// The compensated total. Overflow is clamped to a signed infinity; if terms
+    /// overflowed in both directions the result is undefined and `NaN` is returned.
+    pub(crate) fn total(&self) -> f64 {
+        match (self.positive_overflow, self.negative_overflow) {
+            (true, true) => f64::NAN,
+            (true, false) => f64::INFINITY,
+            (false, true) => f64::NEG_INFINITY,
+            (false, false) => self.sum + self.compensation,
+        }
+    }
+}
+
+impl FromIterator<f64> for NeumaierSum {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        let mut acc = NeumaierSum::default();
+        for value in iter {
+            acc.add(value);
+        }
+        acc
+    }
+}
+
+/// Sum `values`, either with compensation or naively.
+pub(crate) fn sum(values: Vec<f64>, compensated: bool) -> f64 {
+    if compensated {
+        values.into_iter().collect::<NeumaierSum>().total()
+    } else {
+        values.into_iter().sum::<f64>()
+    }
+}
+
+/// Average `values`, also reporting how many of them are non-finite.
+///
+/// With `skip_nonfinite` the non-finite values are left out of the average; if none are
+/// finite the result is `NaN`.
+pub(crate) fn mean(values: Vec<f64>, compensated: bool, skip_nonfinite: bool) -> (f64, usize) {
+    let total = values.len();
+    let nonfinite = values.iter().filter(|value| !value.is_finite()).count();
+    if skip_nonfinite {
+        let finite: Vec<f64> = values
+            .into_iter()
+            .filter(|value| value.is_finite())
+            .collect();
+        let count = total - nonfinite;
+        let mean = if count == 0 {
+            f64::NAN
+        } else {
+            sum(finite, compensated) / count as f64
+        };
+        (mean, nonfinite)
+    } else {
+        (sum(values, compensated) / total as f64, nonfinite)
+    }
+}
+
+/// Central moments of orders `2..=order` accumulated in a single pass.
+///
+/// Values are added one at a time and partial accumulators are merged with the pairwise
+/// update formulas of Pébay (2008), which generalize Welford's and Chan et al.'s variance
+/// updates to higher orders, so each thread of a parallel reduction keeps its own.
+#[derive(Debug, Clone)]
+pub(crate) struct CentralMoments {
+    count: f64,
+    mean: f64,
+    /// `sums[p - 2]` 为 `Σ (x - mean)^p`，`p = 2..=order`
+    sums: Vec<f64>,
+}
+
+impl CentralMoments {
+    pub(crate) fn new(order: usize) -> Self {
+        Self {
+            count: 0.0,
+            mean: 0.0,
+            sums: vec![0.0; order.saturating_sub(1)],
+        }
+    }
+
+    pub(crate) fn add(&mut self, value: f64) {
+        self.combine(1.0, value, &[]);
+    }
+
+    pub(crate) fn merge(mut self, other: Self) -> Self {
+        self.combine(other.count, other.mean, &other.sums);
+        self
+    }
+
+    /// The sample mean; `0` if empty.
+    pub(crate) fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// The central moment of order `order`, at most the order given to `new`; `NaN` if empty.
+    pub(crate) fn central_moment(&self, order: usize) -> f64 {
+        match order {
+            0 => 1.0,
+            1 => 0.0,
+            _ => self.sums[order - 2] / self.count,
+        }
+    }
+
+    /// 并入另一组样本的个数、均值与中心和；`sums_b` 为空表示单个样本（中心和全为 0）
+    fn combine(&mut self, count_b: f64, mean_b: f64, sums_b: &[f64]) {
+        if count_b == 0.0 {
+            return;
+        }
+        let count_a = self.count;
+        let count = count_a + count_b;
+        let delta = mean_b - self.mean;
+        let sum_b = |q: usize| sums_b.get(q - 2).copied().unwrap_or(0.0);
+        // M_p 的更新用到低阶的旧值，故从高阶往低阶原地更新
+        for p in (2..self.sums.len() + 2).rev() {
+            let mut total = self.sums[p - 2] + sum_b(p);
+            let mut binomial = 1.0;
+            for k in 1..=p - 2 {
+                binomial *= (p - k + 1) as f64 / k as f64;
+                let q = p - k;
+                let k = k as i32;
+                total += binomial
+                    * delta.powi(k)
+                    * ((-count_b / count).powi(k) * self.sums[q - 2]
+                        + (count_a / count).powi(k) * sum_b(q));
+            }
+            if count_a > 0.0 {
+                let p = p as i32;
+                total += (count_a * count_b / count * delta).powi(p)
+                    * (count_b.powi(1 - p) - (-1.0 / count_a).powi(p - 1));
+            }
+            self.sums[p - 2] = total;
+        }
+        self.mean += delta * count_b / count;
+        self.count = count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neumaier_sum_recovers_cancelled_terms() {
+        let values = vec![1.0, 1e100, 1.0, -1e100];
+        assert_eq!(sum(values.clone(), false), 0.0);
+        assert_eq!(sum(values, true), 2.0);
+    }
+
+    #[test]
+    fn neumaier_sum_clamps_overflow() {
+        assert_eq!(sum(vec![f64::MAX, f64::MAX], true), f64::INFINITY);
+        assert_eq!(sum(vec![f64::NEG_INFINITY, 1.0], true), f64::NEG_INFINITY);
+        assert!(sum(vec![f64::INFINITY, f64::NEG_INFINITY], true).is_nan());
+    }
+
+    #[test]
+    fn central_moments_merge_matches_two_pass() {
+        let values: Vec<f64> = (0..200)
+            .map(|i| ((i * 37) % 101) as f64 / 7.0 - 3.0)
+            .collect();
+        let accumulate = |values: &[f64]| {
+            let mut moments = CentralMoments::new(4);
+            values.iter().for_each(|&value| moments.add(value));
+            moments
+        };
+        let merged = accumulate(&values[..73]).merge(accumulate(&values[73..]));
+        let sequential = accumulate(&values);
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        for moments in [&merged, &sequential] {
+            assert!((moments.mean() - mean).abs() < 1e-12);
+            for order in 2..=4 {
+                let expected = values
+                    .iter()
+                    .map(|value| (value - mean).powi(order as i32))
+                    .sum::<f64>()
+                    / values.len() as f64;
+                let actual = moments.central_moment(order);
+                assert!(
+                    (actual - expected).abs() < 1e-10 * expected.abs().max(1.0),
+                    "{order}: {actual}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{XPyError, XPyResult};
+use numpy::{Ix1, PyArray, PyArrayMethods};
+use pyo3::prelude::*;
+#[cfg(feature = "stub_gen")]
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+
+/// 扩散过程的时间与位置单位，由扩散系数的量纲 `L^2/T` 推出：时间为 `T`，位置为 `L`
+pub(crate) struct DiffusiveUnits {
+    pub(crate) time: String,
+    pub(crate) length: String,
+}
+
+impl DiffusiveUnits {
+    /// 解析形如 `"m^2/s"`、`"um^2/ms"` 的扩散系数单位
+    pub(crate) fn parse(diffusion_unit: &str) -> XPyResult<Self> {
+        let parsed = diffusion_unit.split_once('/').and_then(|(area, time)| {
+            let length = area.trim().strip_suffix("^2")?.trim();
+            let time = time.trim();
+            (!length.is_empty() && !time.is_empty()).then(|| Self {
+                time: time.to_string(),
+                length: length.to_string(),
+            })
+        });
+        parsed.ok_or_else(|| {
+            XPyError::ValueError(format!(
+                "diffusion_unit must have the form 'L^2/T', e.g. 'm^2/s', got '{diffusion_unit}'"
+            ))
+        })
+    }
+}
+
+/// A NumPy array tagged with a unit string.
+#[cfg_attr(feature = "stub_gen", gen_stub_pyclass)]
+#[pyclass(frozen, module = "diffusionx._core")]
+pub struct UnitArray {
+    values: Py<PyArray<f64, Ix1>>,
+    units: String,
+}
+
+impl UnitArray {
+    pub(crate) fn new(values: Bound<'_, PyArray<f64, Ix1>>, units: &str) -> Self {
+        Self {
+            values: values.unbind(),
+            units: units.to_string(),
+        }
+    }
+}
+
+#[cfg_attr(feature = "stub_gen", gen_stub_pymethods)]
+#[pymethods]
+impl UnitArray {
+    /// The underlying array.
+    #[getter]
+    fn values<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray<f64, Ix1>> {
+        self.values.bind(py).clone()
+    }
+
+    /// The unit of the values.
+    #[getter]
+    fn units(&self) -> &str {
+        &self.units
+    }
+
+    fn __len__(&self, py: Python<'_>) -> usize {
+        self.values.bind(py).len()
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let values = self.values.bind(py).repr()?;
+        Ok(format!("UnitArray({values}, units='{}')", self.units))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffusive_units_follow_the_diffusion_unit() {
+        let units = DiffusiveUnits::parse("um^2/ms").unwrap();
+        assert_eq!((units.time.as_str(), units.length.as_str()), ("ms", "um"));
+        assert!(DiffusiveUnits::parse("m/s").is_err());
+        assert!(DiffusiveUnits::parse("^2/s").is_err());
+    }
+}